@@ -949,9 +949,9 @@ class NatsPlaygroundPageState extends State<NatsPlaygroundPage> {
           cubit.updatePublishStatus("Message published successfully at ${_formatTimestamp()}");
         });
       },
-      onFailure: (errorMessage) {
+      onFailure: (error) {
         setState(() {
-          cubit.updatePublishStatus("Publish error: $errorMessage");
+          cubit.updatePublishStatus("Publish error: ${error.message}");
         });
       },
     );
@@ -971,9 +971,9 @@ class NatsPlaygroundPageState extends State<NatsPlaygroundPage> {
           cubit.updateLoadingStatus(false);
         });
       },
-      onFailure: (errorMessage) {
+      onFailure: (error) {
         setState(() {
-          cubit.updateRequestResponse("Request error: $errorMessage");
+          cubit.updateRequestResponse("Request error: ${error.message}");
           cubit.updateRequestResponseTime(_formatTimestamp());
           cubit.updateLoadingStatus(false);
         });
@@ -1042,9 +1042,9 @@ class NatsPlaygroundPageState extends State<NatsPlaygroundPage> {
           cubit.updateSubscriberActive(true);
         });
       },
-      onError: (errorMessage) {
+      onError: (error) {
         setState(() {
-          cubit.updateSubscriberStatus("Subscriber error: $errorMessage");
+          cubit.updateSubscriberStatus("Subscriber error: ${error.message}");
           cubit.updateSubscriberActive(false);
         });
       },
@@ -1088,9 +1088,9 @@ class NatsPlaygroundPageState extends State<NatsPlaygroundPage> {
           cubit.updateLoadingStatus(false);
         });
       },
-      onFailure: (errorMessage) {
+      onFailure: (error) {
         setState(() {
-          cubit.updateKvStatus("Put error: $errorMessage");
+          cubit.updateKvStatus("Put error: ${error.message}");
           cubit.updateKvLastOperationTime(_formatTimestamp());
           cubit.updateLoadingStatus(false);
         });
@@ -1111,9 +1111,9 @@ class NatsPlaygroundPageState extends State<NatsPlaygroundPage> {
           cubit.updateLoadingStatus(false);
         });
       },
-      onFailure: (errorMessage) {
+      onFailure: (error) {
         setState(() {
-          cubit.updateKvStatus("Get error: $errorMessage");
+          cubit.updateKvStatus("Get error: ${error.message}");
           cubit.updateKvLastOperationTime(_formatTimestamp());
           cubit.updateLoadingStatus(false);
         });
@@ -1133,9 +1133,9 @@ class NatsPlaygroundPageState extends State<NatsPlaygroundPage> {
           cubit.updateLoadingStatus(false);
         });
       },
-      onFailure: (errorMessage) {
+      onFailure: (error) {
         setState(() {
-          cubit.updateKvStatus("Delete error: $errorMessage");
+          cubit.updateKvStatus("Delete error: ${error.message}");
           cubit.updateKvLastOperationTime(_formatTimestamp());
           cubit.updateLoadingStatus(false);
         });
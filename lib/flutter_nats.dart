@@ -2,4 +2,17 @@ library;
 
 export 'src/flutter/nats_controller.dart';
 export 'src/rust/frb_generated.dart' show RustLib;
-export 'src/rust/api/nats.dart' show NatsConfig, ReconnectionConfig;
+export 'src/rust/api/codec.dart';
+export 'src/rust/api/compression.dart';
+export 'src/rust/api/consumer.dart';
+export 'src/rust/api/credentials.dart';
+export 'src/rust/api/encryption.dart';
+export 'src/rust/api/error.dart';
+export 'src/rust/api/jetstream.dart';
+export 'src/rust/api/kv.dart';
+export 'src/rust/api/kv_cache.dart';
+export 'src/rust/api/nats.dart';
+export 'src/rust/api/object_store.dart';
+export 'src/rust/api/outbox.dart';
+export 'src/rust/api/service.dart';
+export 'src/rust/api/subject.dart';
@@ -1,6 +1,7 @@
 import 'package:flutter/cupertino.dart';
 import 'package:flutter/foundation.dart';
 import 'package:uuid/uuid.dart';
+import 'package:flutter_nats/src/rust/api/error.dart';
 import 'package:flutter_nats/src/rust/api/nats.dart' as nats_lib;
 
 /// A controller for managing NATS connections and operations
@@ -107,7 +108,7 @@ class NatsController extends ChangeNotifier {
     required String payload,
     int timeoutMs = 5000,
     required ValueChanged<String> onSuccess,
-    required ValueChanged<String> onFailure,
+    required ValueChanged<NatsError> onFailure,
   }) {
     nats_lib.sendRequestWithCallbacks(
       clientId: _clientId,
@@ -129,7 +130,7 @@ class NatsController extends ChangeNotifier {
     required String subject,
     required String payload,
     ValueChanged<bool>? onSuccess,
-    ValueChanged<String>? onFailure,
+    ValueChanged<NatsError>? onFailure,
   }) {
     nats_lib.publish(
       clientId: _clientId,
@@ -180,7 +181,7 @@ class NatsController extends ChangeNotifier {
     int maxMessages = 0,
     required void Function(String subject, String message) onMessage,
     ValueChanged<bool>? onSuccess,
-    ValueChanged<String>? onError,
+    ValueChanged<NatsError>? onError,
     VoidCallback? onDone,
   }) {
     final id = subscriptionId ?? const Uuid().v4();
@@ -231,7 +232,7 @@ class NatsController extends ChangeNotifier {
     required String key,
     required String value,
     ValueChanged<bool>? onSuccess,
-    ValueChanged<String>? onFailure,
+    ValueChanged<NatsError>? onFailure,
   }) {
     nats_lib.kvPut(
       clientId: _clientId,
@@ -248,18 +249,23 @@ class NatsController extends ChangeNotifier {
   /// [bucketName] - The name of the KV bucket
   /// [key] - The key to retrieve
   /// [onSuccess] - Callback for successful retrieval with the value
+  /// [onStale] - Optional callback telling whether the value came from the offline cache
   /// [onFailure] - Optional callback for retrieval failure
   void kvGet({
     required String bucketName,
     required String key,
     required ValueChanged<String> onSuccess,
-    ValueChanged<String>? onFailure,
+    ValueChanged<bool>? onStale,
+    ValueChanged<NatsError>? onFailure,
   }) {
     nats_lib.kvGet(
       clientId: _clientId,
       bucketName: bucketName,
       key: key,
-      onSuccess: onSuccess,
+      onSuccess: (value, stale) {
+        if (onStale != null) onStale(stale);
+        onSuccess(value);
+      },
       onFailure: onFailure ?? (_) {},
     );
   }
@@ -274,7 +280,7 @@ class NatsController extends ChangeNotifier {
     required String bucketName,
     required String key,
     ValueChanged<bool>? onSuccess,
-    ValueChanged<String>? onFailure,
+    ValueChanged<NatsError>? onFailure,
   }) {
     nats_lib.kvDelete(
       clientId: _clientId,
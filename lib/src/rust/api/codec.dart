@@ -0,0 +1,107 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.8.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `content_type`, `decode_message`, `decode_payload`, `encode_for_client`, `encode_payload`, `from_content_type`, `header_codec`, `parse_json`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`
+
+
+            /// Publishes a payload encoded with `codec`, setting its `Content-Type` header.
+Future<void>  publishEncoded({required String clientId , required String subject , required List<int> payload , required PayloadCodec codec }) => RustLib.instance.api.crateApiCodecPublishEncoded(clientId: clientId, subject: subject, payload: payload, codec: codec);
+
+/// Sends a request encoded with `codec` and decodes the response by its `Content-Type`,
+/// falling back to the request's codec.
+Future<DecodedMessage>  requestEncoded({required String clientId , required String subject , required List<int> payload , required PayloadCodec codec , required BigInt timeoutMs }) => RustLib.instance.api.crateApiCodecRequestEncoded(clientId: clientId, subject: subject, payload: payload, codec: codec, timeoutMs: timeoutMs);
+
+/// Streams messages decoded by their `Content-Type` to Dart until stopped with `unsubscribe`.
+///
+/// Messages without a recognised `Content-Type` are decoded with `default_codec`.
+Stream<DecodedMessage>  subscribeDecoded({required String clientId , required String subject , required PayloadCodec defaultCodec , required String subscriptionId }) => RustLib.instance.api.crateApiCodecSubscribeDecoded(clientId: clientId, subject: subject, defaultCodec: defaultCodec, subscriptionId: subscriptionId);
+
+/// Puts a value encoded with `codec` in a KV bucket, setting its `Content-Type` header, and
+/// returns its revision.
+///
+/// The value is compressed and encrypted like other KV values. Buckets reached through a
+/// JetStream domain store no headers, so their values are read with the codec passed to
+/// `kv_get_decoded`.
+Future<BigInt>  kvPutEncoded({required String clientId , required String bucket , required String key , required List<int> payload , required PayloadCodec codec }) => RustLib.instance.api.crateApiCodecKvPutEncoded(clientId: clientId, bucket: bucket, key: key, payload: payload, codec: codec);
+
+/// Gets a value from a KV bucket decoded by its `Content-Type`, falling back to `codec`, or
+/// `None` if the key has no value.
+Future<DecodedPayload?>  kvGetDecoded({required String clientId , required String bucket , required String key , required PayloadCodec codec }) => RustLib.instance.api.crateApiCodecKvGetDecoded(clientId: clientId, bucket: bucket, key: key, codec: codec);
+
+            /// A message whose payload was decoded using its `Content-Type` header.
+class DecodedMessage  {
+                final String subject;
+final String? reply;
+final Map<String, List<String>> headers;
+final DecodedPayload payload;
+
+                const DecodedMessage({required this.subject ,this.reply ,required this.headers ,required this.payload ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => subject.hashCode^reply.hashCode^headers.hashCode^payload.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DecodedMessage &&
+                runtimeType == other.runtimeType
+                && subject == other.subject&& reply == other.reply&& headers == other.headers&& payload == other.payload;
+        
+            }
+
+/// A payload decoded according to its codec.
+///
+/// `json` holds the JSON text of structured payloads, and `bytes` the payload as received.
+class DecodedPayload  {
+                final PayloadCodec codec;
+final String? json;
+final Uint8List bytes;
+
+                const DecodedPayload({required this.codec ,this.json ,required this.bytes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => codec.hashCode^json.hashCode^bytes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DecodedPayload &&
+                runtimeType == other.runtimeType
+                && codec == other.codec&& json == other.json&& bytes == other.bytes;
+        
+            }
+
+/// How a payload is encoded on the wire.
+///
+/// `Json`, `MessagePack` and `Cbor` take and return JSON text, transcoding it as needed.
+/// `Text` and `Protobuf` pass the payload bytes through unchanged.
+enum PayloadCodec {
+                    text,
+json,
+messagePack,
+cbor,
+protobuf,
+                    ;
+                    
+                }
+            
\ No newline at end of file
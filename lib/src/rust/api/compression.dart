@@ -0,0 +1,60 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.8.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `cleanup_client_compression`, `compress_for_client`, `compress`, `content_encoding`, `decompress_payload`, `decompress`, `from_content_encoding`, `read_limited`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+
+            /// Enables compression for a client, or disables it when `config` is `None`.
+///
+/// Received payloads carrying a `Content-Encoding` header are always decompressed.
+Future<void>  setCompression({required String clientId , CompressionConfig? config }) => RustLib.instance.api.crateApiCompressionSetCompression(clientId: clientId, config: config);
+
+/// Sets the largest size in bytes a received payload may decompress to, or restores the
+/// default of 64 MiB when `limit` is `None`.
+///
+/// Larger payloads are rejected instead of being decompressed, so a small compressed payload
+/// cannot exhaust the app's memory.
+Future<void>  setDecompressionLimit({required String clientId , BigInt? limit }) => RustLib.instance.api.crateApiCompressionSetDecompressionLimit(clientId: clientId, limit: limit);
+
+            enum CompressionAlgorithm {
+                    gzip,
+zstd,
+lz4,
+                    ;
+                    
+                }
+
+/// Compression applied to the payloads a client publishes, requests and puts in KV buckets.
+///
+/// Payloads smaller than `min_size` bytes are sent uncompressed.
+class CompressionConfig  {
+                final CompressionAlgorithm algorithm;
+final BigInt minSize;
+
+                const CompressionConfig({required this.algorithm ,required this.minSize ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => algorithm.hashCode^minSize.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CompressionConfig &&
+                runtimeType == other.runtimeType
+                && algorithm == other.algorithm&& minSize == other.minSize;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,231 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.8.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `forward_messages`, `get_acker`, `get_pull_consumer`, `overlay_consumer_config`, `process_consumer_messages`, `request_pause`, `to_deliver_policy`, `to_js_message`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `PauseResponse`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `from`
+
+
+            /// Creates a pull consumer on a stream, failing if one exists with a different configuration.
+Future<ConsumerInfo>  createConsumer({required String clientId , required String stream , required ConsumerConfig config }) => RustLib.instance.api.crateApiConsumerCreateConsumer(clientId: clientId, stream: stream, config: config);
+
+/// Returns the configuration and delivery state of a consumer.
+Future<ConsumerInfo>  consumerInfo({required String clientId , required String stream , required String consumer }) => RustLib.instance.api.crateApiConsumerConsumerInfo(clientId: clientId, stream: stream, consumer: consumer);
+
+/// Updates the configuration of an existing durable consumer and returns its info.
+///
+/// Options left as `None` keep their current value, as do settings `ConsumerConfig` does not
+/// expose, such as the deliver policy.
+Future<ConsumerInfo>  updateConsumer({required String clientId , required String stream , required ConsumerConfig config }) => RustLib.instance.api.crateApiConsumerUpdateConsumer(clientId: clientId, stream: stream, config: config);
+
+/// Deletes a consumer from a stream.
+Future<bool>  deleteConsumer({required String clientId , required String stream , required String consumer }) => RustLib.instance.api.crateApiConsumerDeleteConsumer(clientId: clientId, stream: stream, consumer: consumer);
+
+/// Returns the info of every consumer on a stream.
+Future<List<ConsumerInfo>>  listConsumers({required String clientId , required String stream }) => RustLib.instance.api.crateApiConsumerListConsumers(clientId: clientId, stream: stream);
+
+/// Pauses delivery of a consumer until the given time. Requires nats-server 2.11 or newer.
+Future<ConsumerPauseState>  pauseConsumer({required String clientId , required String stream , required String consumer , required PlatformInt64 pauseUntilMs }) => RustLib.instance.api.crateApiConsumerPauseConsumer(clientId: clientId, stream: stream, consumer: consumer, pauseUntilMs: pauseUntilMs);
+
+/// Resumes delivery of a paused consumer. Requires nats-server 2.11 or newer.
+Future<ConsumerPauseState>  resumeConsumer({required String clientId , required String stream , required String consumer }) => RustLib.instance.api.crateApiConsumerResumeConsumer(clientId: clientId, stream: stream, consumer: consumer);
+
+/// Fetches a batch of up to `batch` messages, waiting at most `expires_ms` for them to arrive.
+Future<List<JsMessage>>  consumerFetch({required String clientId , required String stream , required String consumer , required int batch , required BigInt expiresMs }) => RustLib.instance.api.crateApiConsumerConsumerFetch(clientId: clientId, stream: stream, consumer: consumer, batch: batch, expiresMs: expiresMs);
+
+/// Returns the next message of a consumer, or `None` if none arrives within `timeout_ms`.
+Future<JsMessage?>  consumerNext({required String clientId , required String stream , required String consumer , required BigInt timeoutMs }) => RustLib.instance.api.crateApiConsumerConsumerNext(clientId: clientId, stream: stream, consumer: consumer, timeoutMs: timeoutMs);
+
+/// Continuously delivers the messages of a consumer until it is stopped with `unsubscribe`.
+Stream<JsMessage>  consumerMessages({required String clientId , required String stream , required String consumer , required String subscriptionId }) => RustLib.instance.api.crateApiConsumerConsumerMessages(clientId: clientId, stream: stream, consumer: consumer, subscriptionId: subscriptionId);
+
+/// Delivers the messages of a consumer to a Dart handler until it is stopped with `unsubscribe`.
+///
+/// With `auto_ack` each message is acked once the handler returns `true`, and naked for
+/// prompt redelivery when it returns `false`.
+Future<void>  consumerSubscribe({required String clientId , required String stream , required String consumer , required String subscriptionId , required bool autoAck , required FutureOr<bool> Function(JsMessage) onMessage , required FutureOr<void> Function(bool) onSuccess , required FutureOr<void> Function(String) onError , required FutureOr<void> Function() onDone }) => RustLib.instance.api.crateApiConsumerConsumerSubscribe(clientId: clientId, stream: stream, consumer: consumer, subscriptionId: subscriptionId, autoAck: autoAck, onMessage: onMessage, onSuccess: onSuccess, onError: onError, onDone: onDone);
+
+/// Acknowledges a delivered message as processed.
+Future<void>  jsAck({required String clientId , required String handle }) => RustLib.instance.api.crateApiConsumerJsAck(clientId: clientId, handle: handle);
+
+/// Acknowledges a delivered message and waits for the server to confirm the ack.
+Future<void>  jsDoubleAck({required String clientId , required String handle }) => RustLib.instance.api.crateApiConsumerJsDoubleAck(clientId: clientId, handle: handle);
+
+/// Negatively acknowledges a delivered message so it is redelivered, optionally after a delay.
+Future<void>  jsNak({required String clientId , required String handle , BigInt? delayMs }) => RustLib.instance.api.crateApiConsumerJsNak(clientId: clientId, handle: handle, delayMs: delayMs);
+
+/// Signals that a delivered message is still being processed, resetting its ack wait.
+Future<void>  jsInProgress({required String clientId , required String handle }) => RustLib.instance.api.crateApiConsumerJsInProgress(clientId: clientId, handle: handle);
+
+/// Stops redelivery of a message without acknowledging it as processed.
+Future<void>  jsTerm({required String clientId , required String handle }) => RustLib.instance.api.crateApiConsumerJsTerm(clientId: clientId, handle: handle);
+
+/// Replays the history of a stream through an ordered consumer until stopped with `unsubscribe`.
+///
+/// Messages are delivered strictly in stream order; when a gap is detected the consumer is
+/// recreated from the last delivered sequence, so no message is skipped or repeated.
+Stream<JsMessage>  orderedConsume({required String clientId , required String stream , required List<String> filterSubjects , required DeliverPolicy deliverPolicy , BigInt? startSequence , PlatformInt64? startTimeMs , required String subscriptionId }) => RustLib.instance.api.crateApiConsumerOrderedConsume(clientId: clientId, stream: stream, filterSubjects: filterSubjects, deliverPolicy: deliverPolicy, startSequence: startSequence, startTimeMs: startTimeMs, subscriptionId: subscriptionId);
+
+            enum AckPolicy {
+                    explicit,
+none,
+all,
+                    ;
+                    
+                }
+
+/// Configuration of a pull consumer.
+///
+/// Consumers without a `durable_name` are ephemeral and removed by the server once idle.
+/// Options left as `None` use the server defaults.
+class ConsumerConfig  {
+                final String? durableName;
+final String? description;
+final List<String> filterSubjects;
+final AckPolicy ackPolicy;
+final BigInt? ackWaitMs;
+final PlatformInt64? maxDeliver;
+final Uint64List backoffMs;
+final PlatformInt64? maxAckPending;
+final BigInt? inactiveThresholdMs;
+
+                const ConsumerConfig({this.durableName ,this.description ,required this.filterSubjects ,required this.ackPolicy ,this.ackWaitMs ,this.maxDeliver ,required this.backoffMs ,this.maxAckPending ,this.inactiveThresholdMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => durableName.hashCode^description.hashCode^filterSubjects.hashCode^ackPolicy.hashCode^ackWaitMs.hashCode^maxDeliver.hashCode^backoffMs.hashCode^maxAckPending.hashCode^inactiveThresholdMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ConsumerConfig &&
+                runtimeType == other.runtimeType
+                && durableName == other.durableName&& description == other.description&& filterSubjects == other.filterSubjects&& ackPolicy == other.ackPolicy&& ackWaitMs == other.ackWaitMs&& maxDeliver == other.maxDeliver&& backoffMs == other.backoffMs&& maxAckPending == other.maxAckPending&& inactiveThresholdMs == other.inactiveThresholdMs;
+        
+            }
+
+class ConsumerInfo  {
+                final String stream;
+final String name;
+final PlatformInt64 createdMs;
+final ConsumerConfig config;
+final BigInt deliveredStreamSequence;
+final BigInt deliveredConsumerSequence;
+final BigInt ackFloorStreamSequence;
+final BigInt ackFloorConsumerSequence;
+final BigInt numAckPending;
+final BigInt numRedelivered;
+final BigInt numWaiting;
+final BigInt numPending;
+
+                const ConsumerInfo({required this.stream ,required this.name ,required this.createdMs ,required this.config ,required this.deliveredStreamSequence ,required this.deliveredConsumerSequence ,required this.ackFloorStreamSequence ,required this.ackFloorConsumerSequence ,required this.numAckPending ,required this.numRedelivered ,required this.numWaiting ,required this.numPending ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => stream.hashCode^name.hashCode^createdMs.hashCode^config.hashCode^deliveredStreamSequence.hashCode^deliveredConsumerSequence.hashCode^ackFloorStreamSequence.hashCode^ackFloorConsumerSequence.hashCode^numAckPending.hashCode^numRedelivered.hashCode^numWaiting.hashCode^numPending.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ConsumerInfo &&
+                runtimeType == other.runtimeType
+                && stream == other.stream&& name == other.name&& createdMs == other.createdMs&& config == other.config&& deliveredStreamSequence == other.deliveredStreamSequence&& deliveredConsumerSequence == other.deliveredConsumerSequence&& ackFloorStreamSequence == other.ackFloorStreamSequence&& ackFloorConsumerSequence == other.ackFloorConsumerSequence&& numAckPending == other.numAckPending&& numRedelivered == other.numRedelivered&& numWaiting == other.numWaiting&& numPending == other.numPending;
+        
+            }
+
+/// The pause state of a consumer as reported by the server.
+class ConsumerPauseState  {
+                final bool paused;
+final PlatformInt64? pauseUntilMs;
+final BigInt? pauseRemainingMs;
+
+                const ConsumerPauseState({required this.paused ,this.pauseUntilMs ,this.pauseRemainingMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => paused.hashCode^pauseUntilMs.hashCode^pauseRemainingMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ConsumerPauseState &&
+                runtimeType == other.runtimeType
+                && paused == other.paused&& pauseUntilMs == other.pauseUntilMs&& pauseRemainingMs == other.pauseRemainingMs;
+        
+            }
+
+/// Where a consumer starts delivering messages from.
+///
+/// `ByStartSequence` and `ByStartTime` need the matching start sequence or start time.
+enum DeliverPolicy {
+                    all,
+last,
+new_,
+lastPerSubject,
+byStartSequence,
+byStartTime,
+                    ;
+                    
+                }
+
+/// A message delivered by a JetStream consumer together with its delivery metadata.
+///
+/// The `handle` identifies this delivery when acknowledging it with `js_ack` and friends.
+/// `payload` is the text of `bytes`, with invalid UTF-8 sequences replaced. Payloads are
+/// decrypted and decompressed according to their headers; if that fails, `decode_error` says
+/// why and `bytes` holds the payload as received.
+class JsMessage  {
+                final String handle;
+final String subject;
+final String payload;
+final Uint8List bytes;
+final String? decodeError;
+final String stream;
+final String consumer;
+final BigInt streamSequence;
+final BigInt consumerSequence;
+final BigInt delivered;
+final BigInt pending;
+final PlatformInt64 timestampMs;
+
+                const JsMessage({required this.handle ,required this.subject ,required this.payload ,required this.bytes ,this.decodeError ,required this.stream ,required this.consumer ,required this.streamSequence ,required this.consumerSequence ,required this.delivered ,required this.pending ,required this.timestampMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => handle.hashCode^subject.hashCode^payload.hashCode^bytes.hashCode^decodeError.hashCode^stream.hashCode^consumer.hashCode^streamSequence.hashCode^consumerSequence.hashCode^delivered.hashCode^pending.hashCode^timestampMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is JsMessage &&
+                runtimeType == other.runtimeType
+                && handle == other.handle&& subject == other.subject&& payload == other.payload&& bytes == other.bytes&& decodeError == other.decodeError&& stream == other.stream&& consumer == other.consumer&& streamSequence == other.streamSequence&& consumerSequence == other.consumerSequence&& delivered == other.delivered&& pending == other.pending&& timestampMs == other.timestampMs;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,154 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.8.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `creds_blocks`, `key_pair_from_seed`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `RawClaims`, `RawNatsClaims`, `RawPermission`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`, `default`, `default`
+
+
+            /// Creates a new nkey pair of the given kind.
+Future<NkeyPair>  nkeyCreate({required NkeyKind kind }) => RustLib.instance.api.crateApiCredentialsNkeyCreate(kind: kind);
+
+/// Derives the public key of an nkey seed.
+Future<String>  nkeyPublicKey({required String seed }) => RustLib.instance.api.crateApiCredentialsNkeyPublicKey(seed: seed);
+
+/// Signs a nonce with an nkey seed, returning the signature encoded as the server expects it.
+Future<String>  nkeySign({required String seed , required List<int> nonce }) => RustLib.instance.api.crateApiCredentialsNkeySign(seed: seed, nonce: nonce);
+
+/// Checks a signature returned by `nkey_sign` against the public key of the signer.
+Future<bool>  nkeyVerify({required String publicKey , required List<int> nonce , required String signature }) => RustLib.instance.api.crateApiCredentialsNkeyVerify(publicKey: publicKey, nonce: nonce, signature: signature);
+
+/// Decodes the claims of a NATS JWT. The signature of the JWT is not checked.
+Future<JwtClaims>  decodeJwt({required String jwt }) => RustLib.instance.api.crateApiCredentialsDecodeJwt(jwt: jwt);
+
+/// Parses the contents of a `.creds` file.
+Future<Credentials>  parseCreds({required String contents }) => RustLib.instance.api.crateApiCredentialsParseCreds(contents: contents);
+
+/// Reads and parses a `.creds` file.
+Future<Credentials>  parseCredsFile({required String path }) => RustLib.instance.api.crateApiCredentialsParseCredsFile(path: path);
+
+            /// The contents of a `.creds` file. The seed is not returned, only its public key.
+class Credentials  {
+                final String jwt;
+final String publicKey;
+final JwtClaims claims;
+
+                const Credentials({required this.jwt ,required this.publicKey ,required this.claims ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => jwt.hashCode^publicKey.hashCode^claims.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Credentials &&
+                runtimeType == other.runtimeType
+                && jwt == other.jwt&& publicKey == other.publicKey&& claims == other.claims;
+        
+            }
+
+/// The claims of a NATS JWT.
+///
+/// Times are in milliseconds since the Unix epoch. A JWT without `expires_ms` never expires.
+class JwtClaims  {
+                final String subject;
+final String? name;
+final String issuer;
+final String? issuerAccount;
+final PlatformInt64 issuedAtMs;
+final PlatformInt64? expiresMs;
+final SubjectPermissions publish;
+final SubjectPermissions subscribe;
+final bool bearerToken;
+
+                const JwtClaims({required this.subject ,this.name ,required this.issuer ,this.issuerAccount ,required this.issuedAtMs ,this.expiresMs ,required this.publish ,required this.subscribe ,required this.bearerToken ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => subject.hashCode^name.hashCode^issuer.hashCode^issuerAccount.hashCode^issuedAtMs.hashCode^expiresMs.hashCode^publish.hashCode^subscribe.hashCode^bearerToken.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is JwtClaims &&
+                runtimeType == other.runtimeType
+                && subject == other.subject&& name == other.name&& issuer == other.issuer&& issuerAccount == other.issuerAccount&& issuedAtMs == other.issuedAtMs&& expiresMs == other.expiresMs&& publish == other.publish&& subscribe == other.subscribe&& bearerToken == other.bearerToken;
+        
+            }
+
+enum NkeyKind {
+                    user,
+account,
+operator_,
+                    ;
+                    
+                }
+
+/// An nkey pair. The `seed` is the private part and must be kept secret.
+class NkeyPair  {
+                final String publicKey;
+final String seed;
+
+                const NkeyPair({required this.publicKey ,required this.seed ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => publicKey.hashCode^seed.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NkeyPair &&
+                runtimeType == other.runtimeType
+                && publicKey == other.publicKey&& seed == other.seed;
+        
+            }
+
+/// Subjects a user is allowed and denied to publish or subscribe to.
+class SubjectPermissions  {
+                final List<String> allow;
+final List<String> deny;
+
+                const SubjectPermissions({required this.allow ,required this.deny ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => allow.hashCode^deny.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SubjectPermissions &&
+                runtimeType == other.runtimeType
+                && allow == other.allow&& deny == other.deny;
+        
+            }
+            
\ No newline at end of file
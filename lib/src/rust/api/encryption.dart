@@ -0,0 +1,88 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.8.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `cleanup_client_encryption`, `key_pair_from_seed`, `open_for_client`, `public_key`, `seal_for_client`, `seal_reply`, `seal`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ClientKeys`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+
+            /// Generates a new xkey pair.
+Future<XKeyPair>  xkeyGenerate() => RustLib.instance.api.crateApiEncryptionXkeyGenerate();
+
+/// Sets the xkey pair a client encrypts payloads with and decrypts received payloads with.
+///
+/// Returns the public key to hand to the other parties.
+Future<String>  xkeyImport({required String clientId , required String seed }) => RustLib.instance.api.crateApiEncryptionXkeyImport(clientId: clientId, seed: seed);
+
+/// Returns the xkey pair of a client, or `None` if it has not imported one.
+Future<XKeyPair?>  xkeyExport({required String clientId }) => RustLib.instance.api.crateApiEncryptionXkeyExport(clientId: clientId);
+
+/// Encrypts the payloads a client sends on subjects matching `subject` for `public_key`.
+///
+/// `subject` may contain the `*` and `>` wildcards. When several recipient keys match a
+/// subject, the first one set is used. Setting a key for an existing subject replaces it.
+Future<void>  setRecipientKey({required String clientId , required String subject , required String publicKey }) => RustLib.instance.api.crateApiEncryptionSetRecipientKey(clientId: clientId, subject: subject, publicKey: publicKey);
+
+/// Stops encrypting the payloads a client sends on `subject`.
+Future<bool>  removeRecipientKey({required String clientId , required String subject }) => RustLib.instance.api.crateApiEncryptionRemoveRecipientKey(clientId: clientId, subject: subject);
+
+/// Lists the recipient keys of a client.
+Future<List<RecipientKey>>  listRecipientKeys({required String clientId }) => RustLib.instance.api.crateApiEncryptionListRecipientKeys(clientId: clientId);
+
+            /// The public xkey payloads sent on subjects matching `subject` are encrypted for.
+class RecipientKey  {
+                final String subject;
+final String publicKey;
+
+                const RecipientKey({required this.subject ,required this.publicKey ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => subject.hashCode^publicKey.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RecipientKey &&
+                runtimeType == other.runtimeType
+                && subject == other.subject&& publicKey == other.publicKey;
+        
+            }
+
+/// A curve25519 xkey pair. The `seed` is the private part and must be kept secret.
+class XKeyPair  {
+                final String publicKey;
+final String seed;
+
+                const XKeyPair({required this.publicKey ,required this.seed ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => publicKey.hashCode^seed.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is XKeyPair &&
+                runtimeType == other.runtimeType
+                && publicKey == other.publicKey&& seed == other.seed;
+        
+            }
+            
\ No newline at end of file
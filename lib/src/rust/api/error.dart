@@ -0,0 +1,60 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.8.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `new`, `payload_too_large`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `from`, `from`
+
+
+            
+
+            /// A typed error returned by APIs whose failures callers are expected to handle.
+///
+/// `payload_size` and `max_payload` are only set for `NatsErrorKind::PayloadTooLarge`.
+class NatsError  {
+                final NatsErrorKind kind;
+final String message;
+final BigInt? payloadSize;
+final BigInt? maxPayload;
+
+                const NatsError({required this.kind ,required this.message ,this.payloadSize ,this.maxPayload ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => kind.hashCode^message.hashCode^payloadSize.hashCode^maxPayload.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NatsError &&
+                runtimeType == other.runtimeType
+                && kind == other.kind&& message == other.message&& payloadSize == other.payloadSize&& maxPayload == other.maxPayload;
+        
+            }
+
+/// The category of a [`NatsError`], letting Dart react to specific failures.
+enum NatsErrorKind {
+                    clientNotFound,
+streamNotFound,
+streamMismatch,
+wrongLastMessageId,
+wrongLastSequence,
+wrongRevision,
+timedOut,
+invalidSubject,
+payloadTooLarge,
+other,
+                    ;
+                    
+                }
+            
\ No newline at end of file
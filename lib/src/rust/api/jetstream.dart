@@ -0,0 +1,365 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.8.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `from_unix_millis`, `get_stream_handle`, `overlay_stream_config`, `to_stored_message`, `unix_millis`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`
+
+
+            /// Creates a JetStream stream for the specified client and returns its info.
+Future<StreamInfo>  createStream({required String clientId , required StreamConfig config }) => RustLib.instance.api.crateApiJetstreamCreateStream(clientId: clientId, config: config);
+
+/// Updates the configuration of an existing JetStream stream and returns its info.
+///
+/// Limits left as `None` keep their current value, as do settings `StreamConfig` does not
+/// expose, such as sources, mirrors and republishing.
+Future<StreamInfo>  updateStream({required String clientId , required StreamConfig config }) => RustLib.instance.api.crateApiJetstreamUpdateStream(clientId: clientId, config: config);
+
+/// Returns the configuration and state of a JetStream stream.
+Future<StreamInfo>  getStreamInfo({required String clientId , required String stream }) => RustLib.instance.api.crateApiJetstreamGetStreamInfo(clientId: clientId, stream: stream);
+
+/// Deletes a JetStream stream together with all of its messages and consumers.
+Future<bool>  deleteStream({required String clientId , required String stream }) => RustLib.instance.api.crateApiJetstreamDeleteStream(clientId: clientId, stream: stream);
+
+/// Purges messages from a JetStream stream and returns the number of purged messages.
+///
+/// The purge can be narrowed down to a `filter` subject, and either `keep` the latest
+/// messages or remove everything below a `sequence`, but not both.
+Future<BigInt>  purgeStream({required String clientId , required String stream , String? filter , BigInt? keep , BigInt? sequence }) => RustLib.instance.api.crateApiJetstreamPurgeStream(clientId: clientId, stream: stream, filter: filter, keep: keep, sequence: sequence);
+
+/// Returns the info of every JetStream stream visible to the specified client.
+Future<List<StreamInfo>>  listStreams({required String clientId }) => RustLib.instance.api.crateApiJetstreamListStreams(clientId: clientId);
+
+/// Returns the names of every JetStream stream visible to the specified client.
+Future<List<String>>  streamNames({required String clientId }) => RustLib.instance.api.crateApiJetstreamStreamNames(clientId: clientId);
+
+/// Publishes a message to JetStream and waits for the stream to acknowledge it.
+Future<PubAck>  jsPublish({required String clientId , required String subject , required String payload , required JsPublishOptions options }) => RustLib.instance.api.crateApiJetstreamJsPublish(clientId: clientId, subject: subject, payload: payload, options: options);
+
+/// Reads a single message stored in a stream by its sequence.
+///
+/// With `direct` the message is served by any replica through the direct get API,
+/// which requires the stream to be created with `allow_direct`.
+Future<StoredMessage>  getStreamMessage({required String clientId , required String stream , required BigInt sequence , required bool direct }) => RustLib.instance.api.crateApiJetstreamGetStreamMessage(clientId: clientId, stream: stream, sequence: sequence, direct: direct);
+
+/// Reads the last message stored in a stream for a subject.
+Future<StoredMessage>  getLastMessageForSubject({required String clientId , required String stream , required String subject , required bool direct }) => RustLib.instance.api.crateApiJetstreamGetLastMessageForSubject(clientId: clientId, stream: stream, subject: subject, direct: direct);
+
+/// Deletes a single message from a stream, overwriting its data on disk when `erase` is set.
+Future<bool>  deleteStreamMessage({required String clientId , required String stream , required BigInt sequence , required bool erase }) => RustLib.instance.api.crateApiJetstreamDeleteStreamMessage(clientId: clientId, stream: stream, sequence: sequence, erase: erase);
+
+/// Configures the JetStream context used by all JetStream, KV and consumer calls of a client.
+///
+/// Use a domain to reach JetStream through a leaf node, or an API prefix for cross-account access.
+Future<void>  configureJetstream({required String clientId , required JetStreamOptions options }) => RustLib.instance.api.crateApiJetstreamConfigureJetstream(clientId: clientId, options: options);
+
+/// Gets the JetStream usage and limits of the account the client is connected as.
+Future<AccountInfo>  accountInfo({required String clientId }) => RustLib.instance.api.crateApiJetstreamAccountInfo(clientId: clientId);
+
+            /// JetStream usage and limits of the account a client is connected as.
+class AccountInfo  {
+                final BigInt memory;
+final BigInt storage;
+final BigInt streams;
+final BigInt consumers;
+final String? domain;
+final AccountLimits limits;
+final BigInt apiRequests;
+final BigInt apiErrors;
+
+                const AccountInfo({required this.memory ,required this.storage ,required this.streams ,required this.consumers ,this.domain ,required this.limits ,required this.apiRequests ,required this.apiErrors ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => memory.hashCode^storage.hashCode^streams.hashCode^consumers.hashCode^domain.hashCode^limits.hashCode^apiRequests.hashCode^apiErrors.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AccountInfo &&
+                runtimeType == other.runtimeType
+                && memory == other.memory&& storage == other.storage&& streams == other.streams&& consumers == other.consumers&& domain == other.domain&& limits == other.limits&& apiRequests == other.apiRequests&& apiErrors == other.apiErrors;
+        
+            }
+
+/// JetStream limits imposed on an account. `None` means unlimited.
+class AccountLimits  {
+                final PlatformInt64? maxMemory;
+final PlatformInt64? maxStorage;
+final PlatformInt64? maxStreams;
+final PlatformInt64? maxConsumers;
+final PlatformInt64 maxAckPending;
+final PlatformInt64? memoryMaxStreamBytes;
+final PlatformInt64? storageMaxStreamBytes;
+final bool maxBytesRequired;
+
+                const AccountLimits({this.maxMemory ,this.maxStorage ,this.maxStreams ,this.maxConsumers ,required this.maxAckPending ,this.memoryMaxStreamBytes ,this.storageMaxStreamBytes ,required this.maxBytesRequired ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => maxMemory.hashCode^maxStorage.hashCode^maxStreams.hashCode^maxConsumers.hashCode^maxAckPending.hashCode^memoryMaxStreamBytes.hashCode^storageMaxStreamBytes.hashCode^maxBytesRequired.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AccountLimits &&
+                runtimeType == other.runtimeType
+                && maxMemory == other.maxMemory&& maxStorage == other.maxStorage&& maxStreams == other.maxStreams&& maxConsumers == other.maxConsumers&& maxAckPending == other.maxAckPending&& memoryMaxStreamBytes == other.memoryMaxStreamBytes&& storageMaxStreamBytes == other.storageMaxStreamBytes&& maxBytesRequired == other.maxBytesRequired;
+        
+            }
+
+enum DiscardPolicy {
+                    old,
+new_,
+                    ;
+                    
+                }
+
+/// Options for the JetStream context used by a client.
+///
+/// `domain` and `api_prefix` are mutually exclusive; a domain maps to the `$JS.<domain>.API` prefix.
+class JetStreamOptions  {
+                final String? domain;
+final String? apiPrefix;
+final BigInt? requestTimeoutMs;
+
+                const JetStreamOptions({this.domain ,this.apiPrefix ,this.requestTimeoutMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => domain.hashCode^apiPrefix.hashCode^requestTimeoutMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is JetStreamOptions &&
+                runtimeType == other.runtimeType
+                && domain == other.domain&& apiPrefix == other.apiPrefix&& requestTimeoutMs == other.requestTimeoutMs;
+        
+            }
+
+/// Deduplication and optimistic concurrency options for a JetStream publish.
+///
+/// When an expectation is not met the publish fails with the matching [`NatsErrorKind`].
+class JsPublishOptions  {
+                final String? messageId;
+final String? expectedStream;
+final String? expectedLastMessageId;
+final BigInt? expectedLastSequence;
+final BigInt? expectedLastSubjectSequence;
+
+                const JsPublishOptions({this.messageId ,this.expectedStream ,this.expectedLastMessageId ,this.expectedLastSequence ,this.expectedLastSubjectSequence ,});
+
+                static Future<JsPublishOptions>  default_()=>RustLib.instance.api.crateApiJetstreamJsPublishOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => messageId.hashCode^expectedStream.hashCode^expectedLastMessageId.hashCode^expectedLastSequence.hashCode^expectedLastSubjectSequence.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is JsPublishOptions &&
+                runtimeType == other.runtimeType
+                && messageId == other.messageId&& expectedStream == other.expectedStream&& expectedLastMessageId == other.expectedLastMessageId&& expectedLastSequence == other.expectedLastSequence&& expectedLastSubjectSequence == other.expectedLastSubjectSequence;
+        
+            }
+
+/// The acknowledgement returned by JetStream once a message is persisted.
+class PubAck  {
+                final String stream;
+final BigInt sequence;
+final bool duplicate;
+final String? domain;
+
+                const PubAck({required this.stream ,required this.sequence ,required this.duplicate ,this.domain ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => stream.hashCode^sequence.hashCode^duplicate.hashCode^domain.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PubAck &&
+                runtimeType == other.runtimeType
+                && stream == other.stream&& sequence == other.sequence&& duplicate == other.duplicate&& domain == other.domain;
+        
+            }
+
+enum RetentionPolicy {
+                    limits,
+interest,
+workQueue,
+                    ;
+                    
+                }
+
+enum StorageType {
+                    file,
+memory,
+                    ;
+                    
+                }
+
+/// A message stored in a stream, read directly by its sequence or subject.
+///
+/// `payload` is the text of `bytes`, with invalid UTF-8 sequences replaced. Payloads are
+/// decrypted and decompressed according to their headers; if that fails, `decode_error` says
+/// why and `bytes` holds the payload as stored.
+class StoredMessage  {
+                final String subject;
+final BigInt sequence;
+final Map<String, List<String>> headers;
+final String payload;
+final Uint8List bytes;
+final String? decodeError;
+final PlatformInt64 timestampMs;
+
+                const StoredMessage({required this.subject ,required this.sequence ,required this.headers ,required this.payload ,required this.bytes ,this.decodeError ,required this.timestampMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => subject.hashCode^sequence.hashCode^headers.hashCode^payload.hashCode^bytes.hashCode^decodeError.hashCode^timestampMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is StoredMessage &&
+                runtimeType == other.runtimeType
+                && subject == other.subject&& sequence == other.sequence&& headers == other.headers&& payload == other.payload&& bytes == other.bytes&& decodeError == other.decodeError&& timestampMs == other.timestampMs;
+        
+            }
+
+/// Configuration of a JetStream stream.
+///
+/// Limits left as `None` use the server defaults, which means unlimited.
+class StreamConfig  {
+                final String name;
+final List<String> subjects;
+final String? description;
+final RetentionPolicy retention;
+final StorageType storage;
+final DiscardPolicy discard;
+final PlatformInt64? maxMessages;
+final PlatformInt64? maxMessagesPerSubject;
+final PlatformInt64? maxBytes;
+final BigInt? maxAgeMs;
+final int? maxMessageSize;
+final int? maxConsumers;
+final int? numReplicas;
+final BigInt? duplicateWindowMs;
+final bool allowDirect;
+
+                const StreamConfig({required this.name ,required this.subjects ,this.description ,required this.retention ,required this.storage ,required this.discard ,this.maxMessages ,this.maxMessagesPerSubject ,this.maxBytes ,this.maxAgeMs ,this.maxMessageSize ,this.maxConsumers ,this.numReplicas ,this.duplicateWindowMs ,required this.allowDirect ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^subjects.hashCode^description.hashCode^retention.hashCode^storage.hashCode^discard.hashCode^maxMessages.hashCode^maxMessagesPerSubject.hashCode^maxBytes.hashCode^maxAgeMs.hashCode^maxMessageSize.hashCode^maxConsumers.hashCode^numReplicas.hashCode^duplicateWindowMs.hashCode^allowDirect.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is StreamConfig &&
+                runtimeType == other.runtimeType
+                && name == other.name&& subjects == other.subjects&& description == other.description&& retention == other.retention&& storage == other.storage&& discard == other.discard&& maxMessages == other.maxMessages&& maxMessagesPerSubject == other.maxMessagesPerSubject&& maxBytes == other.maxBytes&& maxAgeMs == other.maxAgeMs&& maxMessageSize == other.maxMessageSize&& maxConsumers == other.maxConsumers&& numReplicas == other.numReplicas&& duplicateWindowMs == other.duplicateWindowMs&& allowDirect == other.allowDirect;
+        
+            }
+
+class StreamInfo  {
+                final StreamConfig config;
+final PlatformInt64 createdMs;
+final StreamState state;
+
+                const StreamInfo({required this.config ,required this.createdMs ,required this.state ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => config.hashCode^createdMs.hashCode^state.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is StreamInfo &&
+                runtimeType == other.runtimeType
+                && config == other.config&& createdMs == other.createdMs&& state == other.state;
+        
+            }
+
+class StreamState  {
+                final BigInt messages;
+final BigInt bytes;
+final BigInt firstSequence;
+final PlatformInt64 firstTimestampMs;
+final BigInt lastSequence;
+final PlatformInt64 lastTimestampMs;
+final BigInt consumerCount;
+final BigInt subjectsCount;
+final BigInt? deletedCount;
+
+                const StreamState({required this.messages ,required this.bytes ,required this.firstSequence ,required this.firstTimestampMs ,required this.lastSequence ,required this.lastTimestampMs ,required this.consumerCount ,required this.subjectsCount ,this.deletedCount ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => messages.hashCode^bytes.hashCode^firstSequence.hashCode^firstTimestampMs.hashCode^lastSequence.hashCode^lastTimestampMs.hashCode^consumerCount.hashCode^subjectsCount.hashCode^deletedCount.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is StreamState &&
+                runtimeType == other.runtimeType
+                && messages == other.messages&& bytes == other.bytes&& firstSequence == other.firstSequence&& firstTimestampMs == other.firstTimestampMs&& lastSequence == other.lastSequence&& lastTimestampMs == other.lastTimestampMs&& consumerCount == other.consumerCount&& subjectsCount == other.subjectsCount&& deletedCount == other.deletedCount;
+        
+            }
+            
\ No newline at end of file
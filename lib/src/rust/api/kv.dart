@@ -0,0 +1,261 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.8.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'jetstream.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `decode_kv_entry`, `forward_entries`, `get_store`, `kv_stream_name`, `latest_entries`, `latest_entry`, `message_to_kv_entry`, `overlay_kv_config`, `read_entries`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`
+
+
+            /// Creates a Key-Value bucket and returns its status.
+Future<KvStatus>  kvCreateBucket({required String clientId , required KvConfig config }) => RustLib.instance.api.crateApiKvKvCreateBucket(clientId: clientId, config: config);
+
+/// Updates the configuration of an existing Key-Value bucket and returns its status.
+///
+/// The storage type and mirror of a bucket cannot be changed once it is created.
+Future<KvStatus>  kvUpdateBucket({required String clientId , required KvConfig config }) => RustLib.instance.api.crateApiKvKvUpdateBucket(clientId: clientId, config: config);
+
+/// Deletes a Key-Value bucket and all of its entries.
+Future<bool>  kvDeleteBucket({required String clientId , required String bucket }) => RustLib.instance.api.crateApiKvKvDeleteBucket(clientId: clientId, bucket: bucket);
+
+/// Returns the configuration and current state of a Key-Value bucket.
+Future<KvStatus>  kvBucketStatus({required String clientId , required String bucket }) => RustLib.instance.api.crateApiKvKvBucketStatus(clientId: clientId, bucket: bucket);
+
+/// Gets the latest entry for a key with its revision metadata, or `None` if the key was never set.
+///
+/// Deleted and purged keys return an entry with the matching operation and an empty value.
+/// Fails with `NatsErrorKind::StreamNotFound` if the bucket does not exist.
+Future<KvEntry?>  kvEntry({required String clientId , required String bucket , required String key }) => RustLib.instance.api.crateApiKvKvEntry(clientId: clientId, bucket: bucket, key: key);
+
+/// Puts a value only if the key does not exist yet and returns its revision.
+///
+/// Fails with `NatsErrorKind::WrongRevision` if the key already has a value.
+Future<BigInt>  kvCreate({required String clientId , required String bucket , required String key , required String value }) => RustLib.instance.api.crateApiKvKvCreate(clientId: clientId, bucket: bucket, key: key, value: value);
+
+/// Puts a value only if the latest revision of the key is `expected_revision` and returns the new revision.
+///
+/// Fails with `NatsErrorKind::WrongRevision` if the key was changed since that revision.
+Future<BigInt>  kvUpdate({required String clientId , required String bucket , required String key , required String value , required BigInt expectedRevision }) => RustLib.instance.api.crateApiKvKvUpdate(clientId: clientId, bucket: bucket, key: key, value: value, expectedRevision: expectedRevision);
+
+/// Streams changes to the keys matching `key` to Dart until stopped with `unsubscribe`.
+///
+/// `key` may contain the `*` and `>` wildcards, e.g. `settings.>`. The bucket must already exist.
+Stream<KvEntry>  kvWatch({required String clientId , required String bucket , required String key , required KvWatchOptions options , required String subscriptionId }) => RustLib.instance.api.crateApiKvKvWatch(clientId: clientId, bucket: bucket, key: key, options: options, subscriptionId: subscriptionId);
+
+/// Streams changes to all keys of a bucket to Dart until stopped with `unsubscribe`.
+Stream<KvEntry>  kvWatchAll({required String clientId , required String bucket , required KvWatchOptions options , required String subscriptionId }) => RustLib.instance.api.crateApiKvKvWatchAll(clientId: clientId, bucket: bucket, options: options, subscriptionId: subscriptionId);
+
+/// Lists the keys of a bucket that currently hold a value, sorted by name.
+///
+/// `filter` may contain the `*` and `>` wildcards and defaults to all keys. Use `offset` and
+/// `limit` to page through large buckets.
+Future<List<String>>  kvKeys({required String clientId , required String bucket , String? filter , required BigInt offset , BigInt? limit }) => RustLib.instance.api.crateApiKvKvKeys(clientId: clientId, bucket: bucket, filter: filter, offset: offset, limit: limit);
+
+/// Returns all retained revisions of a key, oldest first.
+///
+/// Fails with `NatsErrorKind::StreamNotFound` if the bucket does not exist.
+Future<List<KvEntry>>  kvHistory({required String clientId , required String bucket , required String key }) => RustLib.instance.api.crateApiKvKvHistory(clientId: clientId, bucket: bucket, key: key);
+
+/// Removes a key and all of its history, leaving a single purge marker.
+Future<void>  kvPurge({required String clientId , required String bucket , required String key }) => RustLib.instance.api.crateApiKvKvPurge(clientId: clientId, bucket: bucket, key: key);
+
+/// Removes the history of deleted and purged keys and returns how many keys were cleaned up.
+///
+/// Delete markers older than `older_than_ms` are removed entirely, newer ones are kept so
+/// watchers still see the deletion. Without `older_than_ms` all markers are removed.
+Future<BigInt>  kvPurgeDeletes({required String clientId , required String bucket , BigInt? olderThanMs }) => RustLib.instance.api.crateApiKvKvPurgeDeletes(clientId: clientId, bucket: bucket, olderThanMs: olderThanMs);
+
+            /// Configuration of a JetStream Key-Value bucket.
+///
+/// Limits left as `None` use the server defaults, which means unlimited, and a history of one revision.
+/// When updating a bucket, fields left as `None` keep their current value instead.
+class KvConfig  {
+                final String bucket;
+final String? description;
+final PlatformInt64? history;
+final BigInt? maxAgeMs;
+final PlatformInt64? maxBytes;
+final int? maxValueSize;
+final StorageType storage;
+final int? numReplicas;
+final bool? compression;
+final KvRepublish? republish;
+final KvSource? mirror;
+final List<KvSource>? sources;
+
+                const KvConfig({required this.bucket ,this.description ,this.history ,this.maxAgeMs ,this.maxBytes ,this.maxValueSize ,required this.storage ,this.numReplicas ,this.compression ,this.republish ,this.mirror ,this.sources ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => bucket.hashCode^description.hashCode^history.hashCode^maxAgeMs.hashCode^maxBytes.hashCode^maxValueSize.hashCode^storage.hashCode^numReplicas.hashCode^compression.hashCode^republish.hashCode^mirror.hashCode^sources.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is KvConfig &&
+                runtimeType == other.runtimeType
+                && bucket == other.bucket&& description == other.description&& history == other.history&& maxAgeMs == other.maxAgeMs&& maxBytes == other.maxBytes&& maxValueSize == other.maxValueSize&& storage == other.storage&& numReplicas == other.numReplicas&& compression == other.compression&& republish == other.republish&& mirror == other.mirror&& sources == other.sources;
+        
+            }
+
+/// A Key-Value entry with its revision metadata.
+///
+/// `delta` is the distance from the latest revision of the key, so the latest entry has a delta of zero.
+class KvEntry  {
+                final String bucket;
+final String key;
+final String value;
+final BigInt revision;
+final BigInt delta;
+final PlatformInt64 createdMs;
+final KvOperation operation;
+
+                const KvEntry({required this.bucket ,required this.key ,required this.value ,required this.revision ,required this.delta ,required this.createdMs ,required this.operation ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => bucket.hashCode^key.hashCode^value.hashCode^revision.hashCode^delta.hashCode^createdMs.hashCode^operation.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is KvEntry &&
+                runtimeType == other.runtimeType
+                && bucket == other.bucket&& key == other.key&& value == other.value&& revision == other.revision&& delta == other.delta&& createdMs == other.createdMs&& operation == other.operation;
+        
+            }
+
+/// The operation that produced a Key-Value entry.
+enum KvOperation {
+                    put,
+delete,
+purge,
+                    ;
+                    
+                }
+
+/// Republishes entries stored in a bucket to another subject.
+class KvRepublish  {
+                final String source;
+final String destination;
+final bool headersOnly;
+
+                const KvRepublish({required this.source ,required this.destination ,required this.headersOnly ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => source.hashCode^destination.hashCode^headersOnly.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is KvRepublish &&
+                runtimeType == other.runtimeType
+                && source == other.source&& destination == other.destination&& headersOnly == other.headersOnly;
+        
+            }
+
+/// A bucket mirrored or sourced into another bucket.
+class KvSource  {
+                final String bucket;
+final String? filterSubject;
+final String? domain;
+
+                const KvSource({required this.bucket ,this.filterSubject ,this.domain ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => bucket.hashCode^filterSubject.hashCode^domain.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is KvSource &&
+                runtimeType == other.runtimeType
+                && bucket == other.bucket&& filterSubject == other.filterSubject&& domain == other.domain;
+        
+            }
+
+/// Configuration and state of a Key-Value bucket.
+class KvStatus  {
+                final KvConfig config;
+final BigInt values;
+final BigInt bytes;
+final PlatformInt64 createdMs;
+
+                const KvStatus({required this.config ,required this.values ,required this.bytes ,required this.createdMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => config.hashCode^values.hashCode^bytes.hashCode^createdMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is KvStatus &&
+                runtimeType == other.runtimeType
+                && config == other.config&& values == other.values&& bytes == other.bytes&& createdMs == other.createdMs;
+        
+            }
+
+/// Options for watching Key-Value entries.
+///
+/// By default a watch delivers the latest entry of each matching key, then every update.
+/// `include_history` delivers all retained revisions first instead, and `updates_only` skips
+/// existing entries. `metadata_only` delivers entries without their values.
+class KvWatchOptions  {
+                final bool includeHistory;
+final bool updatesOnly;
+final bool metadataOnly;
+
+                const KvWatchOptions({required this.includeHistory ,required this.updatesOnly ,required this.metadataOnly ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => includeHistory.hashCode^updatesOnly.hashCode^metadataOnly.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is KvWatchOptions &&
+                runtimeType == other.runtimeType
+                && includeHistory == other.includeHistory&& updatesOnly == other.updatesOnly&& metadataOnly == other.metadataOnly;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,107 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.8.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `apply`, `cleanup_client_kv_caches`, `emit`, `get_cache`, `is_current`, `is_online`, `mark_synced`, `offline_value`, `persist`, `queue_write`, `reconcile`, `sync_replica`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CachedEntry`, `KvCache`, `PendingWrite`, `Replica`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`, `default`
+
+
+            /// Keeps a local replica of a KV bucket, persisted at `path`, current through a watcher.
+///
+/// The replica left at `path` by a previous run is loaded first, so reads work offline
+/// right after the app starts. A replica file that cannot be decoded is moved to
+/// `<path>.corrupt`, keeping any offline writes in it, and the cache starts empty.
+Future<void>  enableKvCache({required String clientId , required String bucket , required String path }) => RustLib.instance.api.crateApiKvCacheEnableKvCache(clientId: clientId, bucket: bucket, path: path);
+
+/// Stops keeping the local replica of a bucket current. The replica file is kept.
+Future<void>  disableKvCache({required String clientId , required String bucket }) => RustLib.instance.api.crateApiKvCacheDisableKvCache(clientId: clientId, bucket: bucket);
+
+/// Streams sync and reconciliation events of a bucket's local replica to Dart.
+Stream<KvCacheEvent>  kvCacheEvents({required String clientId , required String bucket }) => RustLib.instance.api.crateApiKvCacheKvCacheEvents(clientId: clientId, bucket: bucket);
+
+/// Reads a value from the local replica of a bucket, or `None` if the key has no value.
+Future<CachedKvValue?>  kvCacheGet({required String clientId , required String bucket , required String key }) => RustLib.instance.api.crateApiKvCacheKvCacheGet(clientId: clientId, bucket: bucket, key: key);
+
+/// Writes a value through the local replica of a bucket.
+///
+/// Online, the value is written only if the key is still at the cached revision and the new
+/// revision is returned. Offline, the write is applied locally, persisted and `None` is
+/// returned; it is reconciled with the same revision check once the client reconnects.
+Future<BigInt?>  kvCachePut({required String clientId , required String bucket , required String key , required String value }) => RustLib.instance.api.crateApiKvCacheKvCachePut(clientId: clientId, bucket: bucket, key: key, value: value);
+
+            /// A value read from the local replica of a bucket.
+///
+/// `stale` is set while the client is offline or the replica has not caught up with the
+/// server yet, and `pending` while a local write is waiting to be reconciled.
+class CachedKvValue  {
+                final String value;
+final BigInt revision;
+final bool stale;
+final bool pending;
+final PlatformInt64? syncedMs;
+
+                const CachedKvValue({required this.value ,required this.revision ,required this.stale ,required this.pending ,this.syncedMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => value.hashCode^revision.hashCode^stale.hashCode^pending.hashCode^syncedMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CachedKvValue &&
+                runtimeType == other.runtimeType
+                && value == other.value&& revision == other.revision&& stale == other.stale&& pending == other.pending&& syncedMs == other.syncedMs;
+        
+            }
+
+/// A change to the state of a local replica.
+///
+/// `Synced` is sent once the replica caught up with the server. `Reconciled` and `Conflict`
+/// report the outcome of a write made offline; on conflict the server value wins.
+class KvCacheEvent  {
+                final KvCacheEventKind kind;
+final String? key;
+final BigInt? revision;
+
+                const KvCacheEvent({required this.kind ,this.key ,this.revision ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => kind.hashCode^key.hashCode^revision.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is KvCacheEvent &&
+                runtimeType == other.runtimeType
+                && kind == other.kind&& key == other.key&& revision == other.revision;
+        
+            }
+
+enum KvCacheEventKind {
+                    synced,
+reconciled,
+conflict,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -4,245 +4,240 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `cleanup_client_subscriptions`, `cleanup_subscription`, `get_client`, `get_jetstream`, `get_kv_store_with_callback`, `get_next_message`, `get_or_create_kv_store`, `is_subscription_active`, `process_responder_requests`, `process_subscription_messages`, `subscription_exists`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+            // These functions are ignored because they are not marked as `pub`: `activate_subscription`, `cache_kv_store`, `check_payload_size`, `cleanup_client_subscriptions`, `cleanup_subscription`, `compress_and_seal`, `connect_with_options`, `encoded_headers_len`, `evict_kv_store`, `get_client`, `get_jetstream`, `get_kv_store_with_callback`, `get_kv_store`, `get_kv_value`, `get_next_message`, `get_or_create_kv_store`, `headers_to_map`, `is_subscription_active`, `key_not_found`, `lookup_kv_store`, `open_kv_store`, `prepare_payload`, `prepare_reply`, `process_responder_requests`, `process_subscription_messages`, `put_kv_value`, `put_kv_with_headers`, `read_payload`, `request_many`, `set_jetstream_context`, `subscription_exists`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `try_from`
+
+
+            /// Returns the largest payload in bytes the server of a client accepts.
+Future<BigInt>  maxPayload({required String clientId }) => RustLib.instance.api.crateApiNatsMaxPayload(clientId: clientId);
 
 /// Connects to a NATS server with the specified client ID and calls appropriate callback based on result.
-Future<void> connect(
-        {required String clientId,
-        required NatsConfig config,
-        required FutureOr<void> Function(bool) onSuccess,
-        required FutureOr<void> Function(String) onFailure}) =>
-    RustLib.instance.api.crateApiNatsConnect(
-        clientId: clientId,
-        config: config,
-        onSuccess: onSuccess,
-        onFailure: onFailure);
+Future<void>  connect({required String clientId , required NatsConfig config , required FutureOr<void> Function(bool) onSuccess , required FutureOr<void> Function(String) onFailure }) => RustLib.instance.api.crateApiNatsConnect(clientId: clientId, config: config, onSuccess: onSuccess, onFailure: onFailure);
+
+/// Connects to a NATS server, asking Dart for credentials on every connect and reconnect.
+///
+/// `auth_callback` receives the nonce sent by the server and returns the credentials to
+/// authenticate with, such as a fresh token or a user JWT with the nonce signed by its nkey.
+/// The credentials in `config` are not used.
+Future<void>  connectWithAuthCallback({required String clientId , required NatsConfig config , required FutureOr<AuthCredentials> Function(Uint8List) authCallback , required FutureOr<void> Function(bool) onSuccess , required FutureOr<void> Function(String) onFailure }) => RustLib.instance.api.crateApiNatsConnectWithAuthCallback(clientId: clientId, config: config, authCallback: authCallback, onSuccess: onSuccess, onFailure: onFailure);
 
 /// Disconnects a specific client from the NATS server.
-Future<void> disconnect(
-        {required String clientId,
-        required FutureOr<void> Function(bool) onSuccess,
-        required FutureOr<void> Function(String) onFailure}) =>
-    RustLib.instance.api.crateApiNatsDisconnect(
-        clientId: clientId, onSuccess: onSuccess, onFailure: onFailure);
+Future<void>  disconnect({required String clientId , required FutureOr<void> Function(bool) onSuccess , required FutureOr<void> Function(String) onFailure }) => RustLib.instance.api.crateApiNatsDisconnect(clientId: clientId, onSuccess: onSuccess, onFailure: onFailure);
 
 /// Sends a request to NATS server using the specified client and returns the response.
-Future<String> sendRequest(
-        {required String clientId,
-        required String subject,
-        required String payload,
-        required BigInt timeoutMs}) =>
-    RustLib.instance.api.crateApiNatsSendRequest(
-        clientId: clientId,
-        subject: subject,
-        payload: payload,
-        timeoutMs: timeoutMs);
+Future<String>  sendRequest({required String clientId , required String subject , required String payload , required BigInt timeoutMs }) => RustLib.instance.api.crateApiNatsSendRequest(clientId: clientId, subject: subject, payload: payload, timeoutMs: timeoutMs);
 
 /// Sends a request to NATS server using the specified client and handles response via callbacks.
-Future<void> sendRequestWithCallbacks(
-        {required String clientId,
-        required String subject,
-        required String payload,
-        required BigInt timeoutMs,
-        required FutureOr<void> Function(String) onSuccess,
-        required FutureOr<void> Function(String) onFailure}) =>
-    RustLib.instance.api.crateApiNatsSendRequestWithCallbacks(
-        clientId: clientId,
-        subject: subject,
-        payload: payload,
-        timeoutMs: timeoutMs,
-        onSuccess: onSuccess,
-        onFailure: onFailure);
+Future<void>  sendRequestWithCallbacks({required String clientId , required String subject , required String payload , required BigInt timeoutMs , required FutureOr<void> Function(String) onSuccess , required FutureOr<void> Function(NatsError) onFailure }) => RustLib.instance.api.crateApiNatsSendRequestWithCallbacks(clientId: clientId, subject: subject, payload: payload, timeoutMs: timeoutMs, onSuccess: onSuccess, onFailure: onFailure);
 
 /// Publishes a message to the specified subject using the specified client.
-Future<void> publish(
-        {required String clientId,
-        required String subject,
-        required String payload,
-        required FutureOr<void> Function(bool) onSuccess,
-        required FutureOr<void> Function(String) onFailure}) =>
-    RustLib.instance.api.crateApiNatsPublish(
-        clientId: clientId,
-        subject: subject,
-        payload: payload,
-        onSuccess: onSuccess,
-        onFailure: onFailure);
+Future<void>  publish({required String clientId , required String subject , required String payload , required FutureOr<void> Function(bool) onSuccess , required FutureOr<void> Function(NatsError) onFailure }) => RustLib.instance.api.crateApiNatsPublish(clientId: clientId, subject: subject, payload: payload, onSuccess: onSuccess, onFailure: onFailure);
 
 /// Sets up a responder to handle requests on a specified subject using the specified client.
-Future<void> setupResponder(
-        {required String clientId,
-        required String subject,
-        required String responderId,
-        required FutureOr<String> Function(String) processRequest,
-        required FutureOr<void> Function(bool) onSuccess,
-        required FutureOr<void> Function(String) onError}) =>
-    RustLib.instance.api.crateApiNatsSetupResponder(
-        clientId: clientId,
-        subject: subject,
-        responderId: responderId,
-        processRequest: processRequest,
-        onSuccess: onSuccess,
-        onError: onError);
+Future<void>  setupResponder({required String clientId , required String subject , required String responderId , required FutureOr<String> Function(String) processRequest , required FutureOr<void> Function(bool) onSuccess , required FutureOr<void> Function(String) onError }) => RustLib.instance.api.crateApiNatsSetupResponder(clientId: clientId, subject: subject, responderId: responderId, processRequest: processRequest, onSuccess: onSuccess, onError: onError);
 
 /// Subscribes to a subject and receives messages via a callback using the specified client.
-Future<void> subscribe(
-        {required String clientId,
-        required String subject,
-        required String subscriptionId,
-        required int maxMessages,
-        required FutureOr<void> Function(String, String) onMessage,
-        required FutureOr<void> Function(bool) onSuccess,
-        required FutureOr<void> Function(String) onError,
-        required FutureOr<void> Function() onDone}) =>
-    RustLib.instance.api.crateApiNatsSubscribe(
-        clientId: clientId,
-        subject: subject,
-        subscriptionId: subscriptionId,
-        maxMessages: maxMessages,
-        onMessage: onMessage,
-        onSuccess: onSuccess,
-        onError: onError,
-        onDone: onDone);
+Future<void>  subscribe({required String clientId , required String subject , required String subscriptionId , required int maxMessages , required FutureOr<void> Function(String, String) onMessage , required FutureOr<void> Function(bool) onSuccess , required FutureOr<void> Function(NatsError) onError , required FutureOr<void> Function() onDone }) => RustLib.instance.api.crateApiNatsSubscribe(clientId: clientId, subject: subject, subscriptionId: subscriptionId, maxMessages: maxMessages, onMessage: onMessage, onSuccess: onSuccess, onError: onError, onDone: onDone);
 
 /// Unsubscribes from a subject for the specified client.
-Future<void> unsubscribe(
-        {required String clientId,
-        required String subscriptionId,
-        required FutureOr<void> Function(bool) onSuccess,
-        required FutureOr<void> Function(String) onFailure}) =>
-    RustLib.instance.api.crateApiNatsUnsubscribe(
-        clientId: clientId,
-        subscriptionId: subscriptionId,
-        onSuccess: onSuccess,
-        onFailure: onFailure);
+Future<void>  unsubscribe({required String clientId , required String subscriptionId , required FutureOr<void> Function(bool) onSuccess , required FutureOr<void> Function(String) onFailure }) => RustLib.instance.api.crateApiNatsUnsubscribe(clientId: clientId, subscriptionId: subscriptionId, onSuccess: onSuccess, onFailure: onFailure);
 
 /// Returns a list of active subscription IDs for the specified client.
-Future<List<String>> listSubscriptions({required String clientId}) =>
-    RustLib.instance.api.crateApiNatsListSubscriptions(clientId: clientId);
+Future<List<String>>  listSubscriptions({required String clientId }) => RustLib.instance.api.crateApiNatsListSubscriptions(clientId: clientId);
 
 /// Returns a list of connected client IDs.
-Future<List<String>> listClients() =>
-    RustLib.instance.api.crateApiNatsListClients();
+Future<List<String>>  listClients() => RustLib.instance.api.crateApiNatsListClients();
+
+/// Returns information about the server a client is connected to.
+Future<ServerInfo>  serverInfo({required String clientId }) => RustLib.instance.api.crateApiNatsServerInfo(clientId: clientId);
+
+/// Returns the traffic counters and connection state of a client.
+Future<ConnectionStats>  connectionStats({required String clientId }) => RustLib.instance.api.crateApiNatsConnectionStats(clientId: clientId);
 
 /// Puts a value in the key-value store using JetStream for the specified client.
-Future<void> kvPut(
-        {required String clientId,
-        required String bucketName,
-        required String key,
-        required String value,
-        required FutureOr<void> Function(bool) onSuccess,
-        required FutureOr<void> Function(String) onFailure}) =>
-    RustLib.instance.api.crateApiNatsKvPut(
-        clientId: clientId,
-        bucketName: bucketName,
-        key: key,
-        value: value,
-        onSuccess: onSuccess,
-        onFailure: onFailure);
+Future<void>  kvPut({required String clientId , required String bucketName , required String key , required String value , required FutureOr<void> Function(bool) onSuccess , required FutureOr<void> Function(NatsError) onFailure }) => RustLib.instance.api.crateApiNatsKvPut(clientId: clientId, bucketName: bucketName, key: key, value: value, onSuccess: onSuccess, onFailure: onFailure);
 
 /// Gets a value from the key-value store using JetStream for the specified client.
-Future<void> kvGet(
-        {required String clientId,
-        required String bucketName,
-        required String key,
-        required FutureOr<void> Function(String) onSuccess,
-        required FutureOr<void> Function(String) onFailure}) =>
-    RustLib.instance.api.crateApiNatsKvGet(
-        clientId: clientId,
-        bucketName: bucketName,
-        key: key,
-        onSuccess: onSuccess,
-        onFailure: onFailure);
+///
+/// `on_success` receives the value and whether it is stale, i.e. served from the bucket's
+/// local replica while the client is offline.
+Future<void>  kvGet({required String clientId , required String bucketName , required String key , required FutureOr<void> Function(String, bool) onSuccess , required FutureOr<void> Function(NatsError) onFailure }) => RustLib.instance.api.crateApiNatsKvGet(clientId: clientId, bucketName: bucketName, key: key, onSuccess: onSuccess, onFailure: onFailure);
 
 /// Deletes a key from the key-value store using JetStream for the specified client.
-Future<void> kvDelete(
-        {required String clientId,
-        required String bucketName,
-        required String key,
-        required FutureOr<void> Function(bool) onSuccess,
-        required FutureOr<void> Function(String) onFailure}) =>
-    RustLib.instance.api.crateApiNatsKvDelete(
-        clientId: clientId,
-        bucketName: bucketName,
-        key: key,
-        onSuccess: onSuccess,
-        onFailure: onFailure);
-
-class NatsConfig {
-  final String host;
-  final int port;
-  final String? token;
-  final String? nkey;
-  final String? creds;
-  final String? user;
-  final String? pass;
-  final ReconnectionConfig? reconnection;
-  final BigInt? pingInterval;
-  final int? maxPingFails;
-
-  const NatsConfig({
-    required this.host,
-    required this.port,
-    this.token,
-    this.nkey,
-    this.creds,
-    this.user,
-    this.pass,
-    this.reconnection,
-    this.pingInterval,
-    this.maxPingFails,
-  });
-
-  @override
-  int get hashCode =>
-      host.hashCode ^
-      port.hashCode ^
-      token.hashCode ^
-      nkey.hashCode ^
-      creds.hashCode ^
-      user.hashCode ^
-      pass.hashCode ^
-      reconnection.hashCode ^
-      pingInterval.hashCode ^
-      maxPingFails.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is NatsConfig &&
-          runtimeType == other.runtimeType &&
-          host == other.host &&
-          port == other.port &&
-          token == other.token &&
-          nkey == other.nkey &&
-          creds == other.creds &&
-          user == other.user &&
-          pass == other.pass &&
-          reconnection == other.reconnection &&
-          pingInterval == other.pingInterval &&
-          maxPingFails == other.maxPingFails;
-}
-
-class ReconnectionConfig {
-  final int? maxAttempts;
-  final BigInt? delay;
-
-  const ReconnectionConfig({
-    this.maxAttempts,
-    this.delay,
-  });
-
-  @override
-  int get hashCode => maxAttempts.hashCode ^ delay.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is ReconnectionConfig &&
-          runtimeType == other.runtimeType &&
-          maxAttempts == other.maxAttempts &&
-          delay == other.delay;
-}
+Future<void>  kvDelete({required String clientId , required String bucketName , required String key , required FutureOr<void> Function(bool) onSuccess , required FutureOr<void> Function(NatsError) onFailure }) => RustLib.instance.api.crateApiNatsKvDelete(clientId: clientId, bucketName: bucketName, key: key, onSuccess: onSuccess, onFailure: onFailure);
+
+/// Enables or disables creating missing KV buckets on first use in `kv_put`, `kv_get` and `kv_delete`.
+///
+/// With auto-creation disabled, buckets must be created with `kv_create_bucket` first.
+Future<void>  setKvAutoCreate({required String clientId , required bool enabled }) => RustLib.instance.api.crateApiNatsSetKvAutoCreate(clientId: clientId, enabled: enabled);
+
+            /// Credentials returned by an auth callback. Only the fields the server expects need to be set.
+///
+/// `signature` is the server nonce signed with the user's nkey, as returned by `nkey_sign`.
+class AuthCredentials  {
+                final String? token;
+final String? user;
+final String? pass;
+final String? jwt;
+final String? nkey;
+final String? signature;
+
+                const AuthCredentials({this.token ,this.user ,this.pass ,this.jwt ,this.nkey ,this.signature ,});
+
+                static Future<AuthCredentials>  default_()=>RustLib.instance.api.crateApiNatsAuthCredentialsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => token.hashCode^user.hashCode^pass.hashCode^jwt.hashCode^nkey.hashCode^signature.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AuthCredentials &&
+                runtimeType == other.runtimeType
+                && token == other.token&& user == other.user&& pass == other.pass&& jwt == other.jwt&& nkey == other.nkey&& signature == other.signature;
+        
+            }
+
+enum ConnectionState {
+                    pending,
+connected,
+disconnected,
+                    ;
+                    
+                }
+
+/// Traffic of a client since it was created. Bytes exclude the protocol overhead.
+class ConnectionStats  {
+                final BigInt inMessages;
+final BigInt outMessages;
+final BigInt inBytes;
+final BigInt outBytes;
+final BigInt reconnects;
+final ConnectionState state;
+
+                const ConnectionStats({required this.inMessages ,required this.outMessages ,required this.inBytes ,required this.outBytes ,required this.reconnects ,required this.state ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => inMessages.hashCode^outMessages.hashCode^inBytes.hashCode^outBytes.hashCode^reconnects.hashCode^state.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ConnectionStats &&
+                runtimeType == other.runtimeType
+                && inMessages == other.inMessages&& outMessages == other.outMessages&& inBytes == other.inBytes&& outBytes == other.outBytes&& reconnects == other.reconnects&& state == other.state;
+        
+            }
+
+class NatsConfig  {
+                final String host;
+final int port;
+final String? token;
+final String? nkey;
+final String? creds;
+final String? user;
+final String? pass;
+final ReconnectionConfig? reconnection;
+final BigInt? pingInterval;
+final int? maxPingFails;
+
+                const NatsConfig({required this.host ,required this.port ,this.token ,this.nkey ,this.creds ,this.user ,this.pass ,this.reconnection ,this.pingInterval ,this.maxPingFails ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => host.hashCode^port.hashCode^token.hashCode^nkey.hashCode^creds.hashCode^user.hashCode^pass.hashCode^reconnection.hashCode^pingInterval.hashCode^maxPingFails.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NatsConfig &&
+                runtimeType == other.runtimeType
+                && host == other.host&& port == other.port&& token == other.token&& nkey == other.nkey&& creds == other.creds&& user == other.user&& pass == other.pass&& reconnection == other.reconnection&& pingInterval == other.pingInterval&& maxPingFails == other.maxPingFails;
+        
+            }
+
+class ReconnectionConfig  {
+                final int? maxAttempts;
+final BigInt? delay;
+
+                const ReconnectionConfig({this.maxAttempts ,this.delay ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => maxAttempts.hashCode^delay.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ReconnectionConfig &&
+                runtimeType == other.runtimeType
+                && maxAttempts == other.maxAttempts&& delay == other.delay;
+        
+            }
+
+/// The server a client is connected to, as announced by the server.
+///
+/// `jetstream_enabled` tells whether JetStream is available to the client's account, or is
+/// `None` when that could not be determined, e.g. because the check timed out or was denied.
+/// The cluster name the server announces is not included, as async-nats does not expose it.
+class ServerInfo  {
+                final String serverId;
+final String serverName;
+final String version;
+final String host;
+final int port;
+final BigInt maxPayload;
+final BigInt clientId;
+final String clientIp;
+final List<String> connectUrls;
+final bool authRequired;
+final bool tlsRequired;
+final bool lameDuckMode;
+final bool? jetstreamEnabled;
+
+                const ServerInfo({required this.serverId ,required this.serverName ,required this.version ,required this.host ,required this.port ,required this.maxPayload ,required this.clientId ,required this.clientIp ,required this.connectUrls ,required this.authRequired ,required this.tlsRequired ,required this.lameDuckMode ,this.jetstreamEnabled ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => serverId.hashCode^serverName.hashCode^version.hashCode^host.hashCode^port.hashCode^maxPayload.hashCode^clientId.hashCode^clientIp.hashCode^connectUrls.hashCode^authRequired.hashCode^tlsRequired.hashCode^lameDuckMode.hashCode^jetstreamEnabled.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ServerInfo &&
+                runtimeType == other.runtimeType
+                && serverId == other.serverId&& serverName == other.serverName&& version == other.version&& host == other.host&& port == other.port&& maxPayload == other.maxPayload&& clientId == other.clientId&& clientIp == other.clientIp&& connectUrls == other.connectUrls&& authRequired == other.authRequired&& tlsRequired == other.tlsRequired&& lameDuckMode == other.lameDuckMode&& jetstreamEnabled == other.jetstreamEnabled;
+        
+            }
+            
\ No newline at end of file
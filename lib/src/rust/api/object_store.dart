@@ -0,0 +1,174 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.8.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'jetstream.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `get_object_store`, `get_with_progress`, `open_object`, `put_with_progress`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ProgressReader`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `poll_read`
+
+
+            /// Creates an Object Store bucket.
+Future<void>  objectStoreCreate({required String clientId , required ObjectStoreConfig config }) => RustLib.instance.api.crateApiObjectStoreObjectStoreCreate(clientId: clientId, config: config);
+
+/// Deletes an Object Store bucket and all of its objects.
+Future<void>  objectStoreDelete({required String clientId , required String bucket }) => RustLib.instance.api.crateApiObjectStoreObjectStoreDelete(clientId: clientId, bucket: bucket);
+
+/// Stores an object from bytes, calling `on_progress` with the bytes sent and the total size.
+Future<ObjectInfo>  objectPut({required String clientId , required String bucket , required ObjectMeta meta , required List<int> data , required FutureOr<void> Function(BigInt, BigInt) onProgress }) => RustLib.instance.api.crateApiObjectStoreObjectPut(clientId: clientId, bucket: bucket, meta: meta, data: data, onProgress: onProgress);
+
+/// Stores an object streamed from a local file, calling `on_progress` with the bytes sent and the
+/// file size.
+Future<ObjectInfo>  objectPutFile({required String clientId , required String bucket , required ObjectMeta meta , required String path , required FutureOr<void> Function(BigInt, BigInt) onProgress }) => RustLib.instance.api.crateApiObjectStoreObjectPutFile(clientId: clientId, bucket: bucket, meta: meta, path: path, onProgress: onProgress);
+
+/// Reads an object into memory, following links, calling `on_progress` with the bytes received
+/// and the object size.
+Future<Uint8List>  objectGet({required String clientId , required String bucket , required String name , required FutureOr<void> Function(BigInt, BigInt) onProgress }) => RustLib.instance.api.crateApiObjectStoreObjectGet(clientId: clientId, bucket: bucket, name: name, onProgress: onProgress);
+
+/// Streams an object into a local file chunk by chunk, following links, and returns its info.
+///
+/// The object is written to `<path>.tmp` first, so an existing file at `path` is only replaced
+/// once the whole object was received.
+Future<ObjectInfo>  objectGetFile({required String clientId , required String bucket , required String name , required String path , required FutureOr<void> Function(BigInt, BigInt) onProgress }) => RustLib.instance.api.crateApiObjectStoreObjectGetFile(clientId: clientId, bucket: bucket, name: name, path: path, onProgress: onProgress);
+
+/// Gets the info of an object without reading its data.
+Future<ObjectInfo>  objectInfo({required String clientId , required String bucket , required String name }) => RustLib.instance.api.crateApiObjectStoreObjectInfo(clientId: clientId, bucket: bucket, name: name);
+
+/// Deletes an object from a bucket.
+Future<void>  objectDelete({required String clientId , required String bucket , required String name }) => RustLib.instance.api.crateApiObjectStoreObjectDelete(clientId: clientId, bucket: bucket, name: name);
+
+/// Lists the objects stored in a bucket, excluding deleted ones.
+Future<List<ObjectInfo>>  objectList({required String clientId , required String bucket }) => RustLib.instance.api.crateApiObjectStoreObjectList(clientId: clientId, bucket: bucket);
+
+/// Streams changes to the objects of a bucket to Dart until stopped with `unsubscribe`.
+///
+/// With `include_history` the current info of every object is delivered first.
+Stream<ObjectInfo>  objectWatch({required String clientId , required String bucket , required bool includeHistory , required String subscriptionId }) => RustLib.instance.api.crateApiObjectStoreObjectWatch(clientId: clientId, bucket: bucket, includeHistory: includeHistory, subscriptionId: subscriptionId);
+
+/// Adds a link named `name` to an object, which may live in another bucket.
+Future<ObjectInfo>  objectAddLink({required String clientId , required String bucket , required String name , required ObjectLink target }) => RustLib.instance.api.crateApiObjectStoreObjectAddLink(clientId: clientId, bucket: bucket, name: name, target: target);
+
+            /// Information about an object stored in an Object Store bucket.
+class ObjectInfo  {
+                final String bucket;
+final String name;
+final String? description;
+final Map<String, String> metadata;
+final BigInt size;
+final BigInt chunks;
+final PlatformInt64? modifiedMs;
+final String? digest;
+final bool deleted;
+final ObjectLink? link;
+
+                const ObjectInfo({required this.bucket ,required this.name ,this.description ,required this.metadata ,required this.size ,required this.chunks ,this.modifiedMs ,this.digest ,required this.deleted ,this.link ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => bucket.hashCode^name.hashCode^description.hashCode^metadata.hashCode^size.hashCode^chunks.hashCode^modifiedMs.hashCode^digest.hashCode^deleted.hashCode^link.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ObjectInfo &&
+                runtimeType == other.runtimeType
+                && bucket == other.bucket&& name == other.name&& description == other.description&& metadata == other.metadata&& size == other.size&& chunks == other.chunks&& modifiedMs == other.modifiedMs&& digest == other.digest&& deleted == other.deleted&& link == other.link;
+        
+            }
+
+/// The target of an object link. A link without an object name links a whole bucket.
+class ObjectLink  {
+                final String bucket;
+final String? name;
+
+                const ObjectLink({required this.bucket ,this.name ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => bucket.hashCode^name.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ObjectLink &&
+                runtimeType == other.runtimeType
+                && bucket == other.bucket&& name == other.name;
+        
+            }
+
+/// Metadata of an object being stored.
+///
+/// `chunk_size` defaults to 128KiB.
+class ObjectMeta  {
+                final String name;
+final String? description;
+final Map<String, String> metadata;
+final int? chunkSize;
+
+                const ObjectMeta({required this.name ,this.description ,required this.metadata ,this.chunkSize ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^description.hashCode^metadata.hashCode^chunkSize.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ObjectMeta &&
+                runtimeType == other.runtimeType
+                && name == other.name&& description == other.description&& metadata == other.metadata&& chunkSize == other.chunkSize;
+        
+            }
+
+/// Configuration of a JetStream Object Store bucket.
+///
+/// Limits left as `None` use the server defaults, which means unlimited.
+class ObjectStoreConfig  {
+                final String bucket;
+final String? description;
+final BigInt? maxAgeMs;
+final PlatformInt64? maxBytes;
+final StorageType storage;
+final int? numReplicas;
+final bool compression;
+
+                const ObjectStoreConfig({required this.bucket ,this.description ,this.maxAgeMs ,this.maxBytes ,required this.storage ,this.numReplicas ,required this.compression ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => bucket.hashCode^description.hashCode^maxAgeMs.hashCode^maxBytes.hashCode^storage.hashCode^numReplicas.hashCode^compression.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ObjectStoreConfig &&
+                runtimeType == other.runtimeType
+                && bucket == other.bucket&& description == other.description&& maxAgeMs == other.maxAgeMs&& maxBytes == other.maxBytes&& storage == other.storage&& numReplicas == other.numReplicas&& compression == other.compression;
+        
+            }
+            
\ No newline at end of file
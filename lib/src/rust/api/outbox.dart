@@ -0,0 +1,126 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.8.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `cleanup_client_outbox`, `emit_with_error`, `emit`, `enqueue_if_offline`, `expire`, `get_outbox`, `load_messages`, `now_ms`, `pending_bytes`, `persist`, `replay`, `write_atomically`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Outbox`, `QueuedMessage`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            /// Enables a persistent outbox for a client.
+///
+/// While the client is disconnected, `publish` appends messages to the outbox instead of
+/// failing or buffering them in memory. Once the client is connected again they are replayed
+/// in order. Messages left in the log by a previous run are replayed as well. Delivery is
+/// at least once: messages replayed right before the app is killed may be sent again.
+Future<void>  enableOutbox({required String clientId , required OutboxConfig config }) => RustLib.instance.api.crateApiOutboxEnableOutbox(clientId: clientId, config: config);
+
+/// Disables the outbox of a client. Queued messages stay in the log unless `clear` is set.
+Future<void>  disableOutbox({required String clientId , required bool clear }) => RustLib.instance.api.crateApiOutboxDisableOutbox(clientId: clientId, clear: clear);
+
+/// Streams the queued, delivered, expired and failed events of a client's outbox to Dart.
+Stream<OutboxEvent>  outboxEvents({required String clientId }) => RustLib.instance.api.crateApiOutboxOutboxEvents(clientId: clientId);
+
+/// Returns how many messages are waiting in a client's outbox.
+Future<OutboxStatus>  outboxStatus({required String clientId }) => RustLib.instance.api.crateApiOutboxOutboxStatus(clientId: clientId);
+
+            /// Configuration of a client's persistent outbox.
+///
+/// Messages are appended to the log at `path` while the client is offline. When the outbox
+/// holds `max_messages` or `max_bytes` of payload, further publishes are rejected. Messages
+/// still queued `ttl_ms` after they were published are dropped instead of being delivered.
+class OutboxConfig  {
+                final String path;
+final BigInt? maxMessages;
+final BigInt? maxBytes;
+final BigInt? ttlMs;
+
+                const OutboxConfig({required this.path ,this.maxMessages ,this.maxBytes ,this.ttlMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => path.hashCode^maxMessages.hashCode^maxBytes.hashCode^ttlMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is OutboxConfig &&
+                runtimeType == other.runtimeType
+                && path == other.path&& maxMessages == other.maxMessages&& maxBytes == other.maxBytes&& ttlMs == other.ttlMs;
+        
+            }
+
+/// A change to a message held in the outbox.
+///
+/// `error` tells why a `Failed` message was dropped and is `None` for other events.
+class OutboxEvent  {
+                final OutboxEventKind kind;
+final BigInt id;
+final String subject;
+final PlatformInt64 queuedMs;
+final String? error;
+
+                const OutboxEvent({required this.kind ,required this.id ,required this.subject ,required this.queuedMs ,this.error ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => kind.hashCode^id.hashCode^subject.hashCode^queuedMs.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is OutboxEvent &&
+                runtimeType == other.runtimeType
+                && kind == other.kind&& id == other.id&& subject == other.subject&& queuedMs == other.queuedMs&& error == other.error;
+        
+            }
+
+enum OutboxEventKind {
+                    queued,
+delivered,
+expired,
+failed,
+                    ;
+                    
+                }
+
+/// The number of messages and payload bytes waiting in an outbox.
+class OutboxStatus  {
+                final BigInt pendingMessages;
+final BigInt pendingBytes;
+
+                const OutboxStatus({required this.pendingMessages ,required this.pendingBytes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => pendingMessages.hashCode^pendingBytes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is OutboxStatus &&
+                runtimeType == other.runtimeType
+                && pendingMessages == other.pendingMessages&& pendingBytes == other.pendingBytes;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,267 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.8.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `cleanup_client_services`, `discover`, `get_endpoint_builder`, `process_endpoint_requests`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ServiceEntry`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `from`
+
+
+            /// Starts a NATS micro service on the specified client and returns its instance ID.
+///
+/// The service answers `$SRV.PING`, `$SRV.INFO` and `$SRV.STATS` requests on its own,
+/// so it is discoverable with `nats micro ls` as soon as it is started.
+Future<String>  addService({required String clientId , required String name , required String version , String? description , required Map<String, String> metadata }) => RustLib.instance.api.crateApiServiceAddService(clientId: clientId, name: name, version: version, description: description, metadata: metadata);
+
+/// Adds a group to a service and returns its full prefix, used as the group handle.
+///
+/// When `parent_group` is given the new group is nested under it. Without a custom
+/// `queue_group` the group inherits the queue group of its parent.
+Future<String>  addServiceGroup({required String clientId , required String serviceId , String? parentGroup , required String prefix , String? queueGroup }) => RustLib.instance.api.crateApiServiceAddServiceGroup(clientId: clientId, serviceId: serviceId, parentGroup: parentGroup, prefix: prefix, queueGroup: queueGroup);
+
+/// Adds an endpoint to a service, optionally under one of its groups, and handles its requests.
+Future<void>  addServiceEndpoint({required String clientId , required String serviceId , String? group , required String name , required String subject , required Map<String, String> metadata , required FutureOr<ServiceResponse> Function(ServiceRequest) handler , required FutureOr<void> Function(bool) onSuccess , required FutureOr<void> Function(String) onError }) => RustLib.instance.api.crateApiServiceAddServiceEndpoint(clientId: clientId, serviceId: serviceId, group: group, name: name, subject: subject, metadata: metadata, handler: handler, onSuccess: onSuccess, onError: onError);
+
+/// Returns the info of a service started by the specified client.
+Future<ServiceInfo>  serviceInfo({required String clientId , required String serviceId }) => RustLib.instance.api.crateApiServiceServiceInfo(clientId: clientId, serviceId: serviceId);
+
+/// Returns the request, error and processing time stats of a service started by the specified client.
+Future<ServiceStats>  serviceStats({required String clientId , required String serviceId }) => RustLib.instance.api.crateApiServiceServiceStats(clientId: clientId, serviceId: serviceId);
+
+/// Resets the endpoint stats of a service started by the specified client.
+Future<void>  resetServiceStats({required String clientId , required String serviceId }) => RustLib.instance.api.crateApiServiceResetServiceStats(clientId: clientId, serviceId: serviceId);
+
+/// Stops a service, unsubscribing all of its endpoints.
+Future<void>  stopService({required String clientId , required String serviceId }) => RustLib.instance.api.crateApiServiceStopService(clientId: clientId, serviceId: serviceId);
+
+/// Returns a list of running service IDs for the specified client.
+Future<List<String>>  listServices({required String clientId }) => RustLib.instance.api.crateApiServiceListServices(clientId: clientId);
+
+/// Pings all micro services, or only those with the given name, and returns every reply.
+Future<List<ServicePing>>  pingServices({required String clientId , String? name , required BigInt timeoutMs }) => RustLib.instance.api.crateApiServicePingServices(clientId: clientId, name: name, timeoutMs: timeoutMs);
+
+/// Discovers all micro services, or only those with the given name, and returns their info.
+Future<List<ServiceInfo>>  discoverServices({required String clientId , String? name , required BigInt timeoutMs }) => RustLib.instance.api.crateApiServiceDiscoverServices(clientId: clientId, name: name, timeoutMs: timeoutMs);
+
+/// Collects the stats of all micro services, or only those with the given name.
+Future<List<ServiceStats>>  discoverServiceStats({required String clientId , String? name , required BigInt timeoutMs }) => RustLib.instance.api.crateApiServiceDiscoverServiceStats(clientId: clientId, name: name, timeoutMs: timeoutMs);
+
+            class EndpointInfo  {
+                final String name;
+final String subject;
+final String queueGroup;
+final Map<String, String> metadata;
+
+                const EndpointInfo({required this.name ,required this.subject ,required this.queueGroup ,required this.metadata ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^subject.hashCode^queueGroup.hashCode^metadata.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EndpointInfo &&
+                runtimeType == other.runtimeType
+                && name == other.name&& subject == other.subject&& queueGroup == other.queueGroup&& metadata == other.metadata;
+        
+            }
+
+class EndpointStats  {
+                final String name;
+final String subject;
+final String queueGroup;
+final BigInt numRequests;
+final BigInt numErrors;
+final BigInt processingTimeNs;
+final BigInt averageProcessingTimeNs;
+final String? lastError;
+
+                const EndpointStats({required this.name ,required this.subject ,required this.queueGroup ,required this.numRequests ,required this.numErrors ,required this.processingTimeNs ,required this.averageProcessingTimeNs ,this.lastError ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^subject.hashCode^queueGroup.hashCode^numRequests.hashCode^numErrors.hashCode^processingTimeNs.hashCode^averageProcessingTimeNs.hashCode^lastError.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EndpointStats &&
+                runtimeType == other.runtimeType
+                && name == other.name&& subject == other.subject&& queueGroup == other.queueGroup&& numRequests == other.numRequests&& numErrors == other.numErrors&& processingTimeNs == other.processingTimeNs&& averageProcessingTimeNs == other.averageProcessingTimeNs&& lastError == other.lastError;
+        
+            }
+
+class ServiceError  {
+                final int code;
+final String description;
+
+                const ServiceError({required this.code ,required this.description ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => code.hashCode^description.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ServiceError &&
+                runtimeType == other.runtimeType
+                && code == other.code&& description == other.description;
+        
+            }
+
+class ServiceInfo  {
+                final String name;
+final String id;
+final String description;
+final String version;
+final Map<String, String> metadata;
+final List<EndpointInfo> endpoints;
+
+                const ServiceInfo({required this.name ,required this.id ,required this.description ,required this.version ,required this.metadata ,required this.endpoints ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^id.hashCode^description.hashCode^version.hashCode^metadata.hashCode^endpoints.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ServiceInfo &&
+                runtimeType == other.runtimeType
+                && name == other.name&& id == other.id&& description == other.description&& version == other.version&& metadata == other.metadata&& endpoints == other.endpoints;
+        
+            }
+
+class ServicePing  {
+                final String name;
+final String id;
+final String version;
+final Map<String, String> metadata;
+
+                const ServicePing({required this.name ,required this.id ,required this.version ,required this.metadata ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^id.hashCode^version.hashCode^metadata.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ServicePing &&
+                runtimeType == other.runtimeType
+                && name == other.name&& id == other.id&& version == other.version&& metadata == other.metadata;
+        
+            }
+
+/// A request received by a service endpoint.
+class ServiceRequest  {
+                final String subject;
+final String payload;
+
+                const ServiceRequest({required this.subject ,required this.payload ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => subject.hashCode^payload.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ServiceRequest &&
+                runtimeType == other.runtimeType
+                && subject == other.subject&& payload == other.payload;
+        
+            }
+
+/// The reply produced by a service endpoint handler.
+///
+/// When `error` is set the payload is ignored and the error is sent back using the
+/// `Nats-Service-Error` headers, counting towards the endpoint's error stats.
+class ServiceResponse  {
+                final String payload;
+final ServiceError? error;
+
+                const ServiceResponse({required this.payload ,this.error ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => payload.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ServiceResponse &&
+                runtimeType == other.runtimeType
+                && payload == other.payload&& error == other.error;
+        
+            }
+
+class ServiceStats  {
+                final String name;
+final String id;
+final String version;
+final PlatformInt64 startedMs;
+final List<EndpointStats> endpoints;
+
+                const ServiceStats({required this.name ,required this.id ,required this.version ,required this.startedMs ,required this.endpoints ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^id.hashCode^version.hashCode^startedMs.hashCode^endpoints.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ServiceStats &&
+                runtimeType == other.runtimeType
+                && name == other.name&& id == other.id&& version == other.version&& startedMs == other.startedMs&& endpoints == other.endpoints;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,29 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.8.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `check_kv_key`, `check_subject`, `invalid`, `matches_pattern`
+
+
+            /// Checks that a subject is well formed.
+///
+/// Subjects must be non-empty, contain no whitespace and no empty tokens. The `*` and `>`
+/// wildcards are only accepted when `allow_wildcards` is set, as whole tokens, with `>` last.
+Future<void>  validateSubject({required String subject , required bool allowWildcards }) => RustLib.instance.api.crateApiSubjectValidateSubject(subject: subject, allowWildcards: allowWildcards);
+
+/// Returns whether a subject matches a pattern using the `*` and `>` wildcards.
+///
+/// `*` matches exactly one token and `>` matches one or more trailing tokens.
+Future<bool>  subjectMatches({required String pattern , required String subject }) => RustLib.instance.api.crateApiSubjectSubjectMatches(pattern: pattern, subject: subject);
+
+/// Builds a subject from its tokens, checking that each one is a valid literal token.
+Future<String>  subjectJoin({required List<String> tokens }) => RustLib.instance.api.crateApiSubjectSubjectJoin(tokens: tokens);
+
+            
+            
\ No newline at end of file
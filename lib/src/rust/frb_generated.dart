@@ -3,1469 +3,6530 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/codec.dart';
+import 'api/compression.dart';
+import 'api/consumer.dart';
+import 'api/credentials.dart';
+import 'api/encryption.dart';
+import 'api/error.dart';
+import 'api/jetstream.dart';
+import 'api/kv.dart';
+import 'api/kv_cache.dart';
 import 'api/nats.dart';
+import 'api/object_store.dart';
+import 'api/outbox.dart';
+import 'api/service.dart';
+import 'api/subject.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
-import 'frb_generated.io.dart'
-    if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  @internal
-  static final instance = RustLib._();
-
-  RustLib._();
-
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    RustLibApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-    );
-  }
-
-  /// Initialize flutter_rust_bridge in mock mode.
-  /// No libraries for FFI are loaded.
-  static void initMock({
-    required RustLibApi api,
-  }) {
-    instance.initMockImpl(
-      api: api,
-    );
-  }
-
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
-
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor =>
-      RustLibApiImpl.new;
-
-  @override
-  WireConstructor<RustLibWire> get wireConstructor =>
-      RustLibWire.fromExternalLibrary;
-
-  @override
-  Future<void> executeRustInitializers() async {
-    await api.crateApiNatsInitApp();
-  }
-
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
-      kDefaultExternalLibraryLoaderConfig;
-
-  @override
-  String get codegenVersion => '2.8.0';
-
-  @override
-  int get rustContentHash => -606814519;
-
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-    stem: 'rust_lib_flutter_nats',
-    ioDirectory: 'rust/target/release/',
-    webPrefix: 'pkg/',
-  );
-}
-
-abstract class RustLibApi extends BaseApi {
-  Future<void> crateApiNatsSendRequestWithCallbacks(
-      {required String clientId,
-      required String subject,
-      required String payload,
-      required BigInt timeoutMs,
-      required FutureOr<void> Function(String) onSuccess,
-      required FutureOr<void> Function(String) onFailure});
-
-  Future<void> crateApiNatsConnect(
-      {required String clientId,
-      required NatsConfig config,
-      required FutureOr<void> Function(bool) onSuccess,
-      required FutureOr<void> Function(String) onFailure});
-
-  Future<void> crateApiNatsDisconnect(
-      {required String clientId,
-      required FutureOr<void> Function(bool) onSuccess,
-      required FutureOr<void> Function(String) onFailure});
-
-  Future<void> crateApiNatsInitApp();
-
-  Future<void> crateApiNatsKvDelete(
-      {required String clientId,
-      required String bucketName,
-      required String key,
-      required FutureOr<void> Function(bool) onSuccess,
-      required FutureOr<void> Function(String) onFailure});
-
-  Future<void> crateApiNatsKvGet(
-      {required String clientId,
-      required String bucketName,
-      required String key,
-      required FutureOr<void> Function(String) onSuccess,
-      required FutureOr<void> Function(String) onFailure});
-
-  Future<void> crateApiNatsKvPut(
-      {required String clientId,
-      required String bucketName,
-      required String key,
-      required String value,
-      required FutureOr<void> Function(bool) onSuccess,
-      required FutureOr<void> Function(String) onFailure});
-
-  Future<List<String>> crateApiNatsListClients();
-
-  Future<List<String>> crateApiNatsListSubscriptions(
-      {required String clientId});
-
-  Future<void> crateApiNatsPublish(
-      {required String clientId,
-      required String subject,
-      required String payload,
-      required FutureOr<void> Function(bool) onSuccess,
-      required FutureOr<void> Function(String) onFailure});
-
-  Future<String> crateApiNatsSendRequest(
-      {required String clientId,
-      required String subject,
-      required String payload,
-      required BigInt timeoutMs});
-
-  Future<void> crateApiNatsSetupResponder(
-      {required String clientId,
-      required String subject,
-      required String responderId,
-      required FutureOr<String> Function(String) processRequest,
-      required FutureOr<void> Function(bool) onSuccess,
-      required FutureOr<void> Function(String) onError});
-
-  Future<void> crateApiNatsSubscribe(
-      {required String clientId,
-      required String subject,
-      required String subscriptionId,
-      required int maxMessages,
-      required FutureOr<void> Function(String, String) onMessage,
-      required FutureOr<void> Function(bool) onSuccess,
-      required FutureOr<void> Function(String) onError,
-      required FutureOr<void> Function() onDone});
-
-  Future<void> crateApiNatsUnsubscribe(
-      {required String clientId,
-      required String subscriptionId,
-      required FutureOr<void> Function(bool) onSuccess,
-      required FutureOr<void> Function(String) onFailure});
-}
-
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  @override
-  Future<void> crateApiNatsSendRequestWithCallbacks(
-      {required String clientId,
-      required String subject,
-      required String payload,
-      required BigInt timeoutMs,
-      required FutureOr<void> Function(String) onSuccess,
-      required FutureOr<void> Function(String) onFailure}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(clientId, serializer);
-        sse_encode_String(subject, serializer);
-        sse_encode_String(payload, serializer);
-        sse_encode_u_64(timeoutMs, serializer);
-        sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(
-            onSuccess, serializer);
-        sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(
-            onFailure, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 1, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiNatsSendRequestWithCallbacksConstMeta,
-      argValues: [clientId, subject, payload, timeoutMs, onSuccess, onFailure],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiNatsSendRequestWithCallbacksConstMeta =>
-      const TaskConstMeta(
-        debugName: "_send_request_with_callbacks",
-        argNames: [
-          "clientId",
-          "subject",
-          "payload",
-          "timeoutMs",
-          "onSuccess",
-          "onFailure"
-        ],
-      );
-
-  @override
-  Future<void> crateApiNatsConnect(
-      {required String clientId,
-      required NatsConfig config,
-      required FutureOr<void> Function(bool) onSuccess,
-      required FutureOr<void> Function(String) onFailure}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(clientId, serializer);
-        sse_encode_box_autoadd_nats_config(config, serializer);
-        sse_encode_DartFn_Inputs_bool_Output_unit_AnyhowException(
-            onSuccess, serializer);
-        sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(
-            onFailure, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 2, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiNatsConnectConstMeta,
-      argValues: [clientId, config, onSuccess, onFailure],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiNatsConnectConstMeta => const TaskConstMeta(
-        debugName: "connect",
-        argNames: ["clientId", "config", "onSuccess", "onFailure"],
-      );
-
-  @override
-  Future<void> crateApiNatsDisconnect(
-      {required String clientId,
-      required FutureOr<void> Function(bool) onSuccess,
-      required FutureOr<void> Function(String) onFailure}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(clientId, serializer);
-        sse_encode_DartFn_Inputs_bool_Output_unit_AnyhowException(
-            onSuccess, serializer);
-        sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(
-            onFailure, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 3, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiNatsDisconnectConstMeta,
-      argValues: [clientId, onSuccess, onFailure],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiNatsDisconnectConstMeta => const TaskConstMeta(
-        debugName: "disconnect",
-        argNames: ["clientId", "onSuccess", "onFailure"],
-      );
-
-  @override
-  Future<void> crateApiNatsInitApp() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 4, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiNatsInitAppConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiNatsInitAppConstMeta => const TaskConstMeta(
-        debugName: "init_app",
-        argNames: [],
-      );
-
-  @override
-  Future<void> crateApiNatsKvDelete(
-      {required String clientId,
-      required String bucketName,
-      required String key,
-      required FutureOr<void> Function(bool) onSuccess,
-      required FutureOr<void> Function(String) onFailure}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(clientId, serializer);
-        sse_encode_String(bucketName, serializer);
-        sse_encode_String(key, serializer);
-        sse_encode_DartFn_Inputs_bool_Output_unit_AnyhowException(
-            onSuccess, serializer);
-        sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(
-            onFailure, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 5, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiNatsKvDeleteConstMeta,
-      argValues: [clientId, bucketName, key, onSuccess, onFailure],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiNatsKvDeleteConstMeta => const TaskConstMeta(
-        debugName: "kv_delete",
-        argNames: ["clientId", "bucketName", "key", "onSuccess", "onFailure"],
-      );
-
-  @override
-  Future<void> crateApiNatsKvGet(
-      {required String clientId,
-      required String bucketName,
-      required String key,
-      required FutureOr<void> Function(String) onSuccess,
-      required FutureOr<void> Function(String) onFailure}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(clientId, serializer);
-        sse_encode_String(bucketName, serializer);
-        sse_encode_String(key, serializer);
-        sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(
-            onSuccess, serializer);
-        sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(
-            onFailure, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 6, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiNatsKvGetConstMeta,
-      argValues: [clientId, bucketName, key, onSuccess, onFailure],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiNatsKvGetConstMeta => const TaskConstMeta(
-        debugName: "kv_get",
-        argNames: ["clientId", "bucketName", "key", "onSuccess", "onFailure"],
-      );
-
-  @override
-  Future<void> crateApiNatsKvPut(
-      {required String clientId,
-      required String bucketName,
-      required String key,
-      required String value,
-      required FutureOr<void> Function(bool) onSuccess,
-      required FutureOr<void> Function(String) onFailure}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(clientId, serializer);
-        sse_encode_String(bucketName, serializer);
-        sse_encode_String(key, serializer);
-        sse_encode_String(value, serializer);
-        sse_encode_DartFn_Inputs_bool_Output_unit_AnyhowException(
-            onSuccess, serializer);
-        sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(
-            onFailure, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 7, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiNatsKvPutConstMeta,
-      argValues: [clientId, bucketName, key, value, onSuccess, onFailure],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiNatsKvPutConstMeta => const TaskConstMeta(
-        debugName: "kv_put",
-        argNames: [
-          "clientId",
-          "bucketName",
-          "key",
-          "value",
-          "onSuccess",
-          "onFailure"
-        ],
-      );
-
-  @override
-  Future<List<String>> crateApiNatsListClients() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 8, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiNatsListClientsConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiNatsListClientsConstMeta => const TaskConstMeta(
-        debugName: "list_clients",
-        argNames: [],
-      );
-
-  @override
-  Future<List<String>> crateApiNatsListSubscriptions(
-      {required String clientId}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(clientId, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 9, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiNatsListSubscriptionsConstMeta,
-      argValues: [clientId],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiNatsListSubscriptionsConstMeta =>
-      const TaskConstMeta(
-        debugName: "list_subscriptions",
-        argNames: ["clientId"],
-      );
-
-  @override
-  Future<void> crateApiNatsPublish(
-      {required String clientId,
-      required String subject,
-      required String payload,
-      required FutureOr<void> Function(bool) onSuccess,
-      required FutureOr<void> Function(String) onFailure}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(clientId, serializer);
-        sse_encode_String(subject, serializer);
-        sse_encode_String(payload, serializer);
-        sse_encode_DartFn_Inputs_bool_Output_unit_AnyhowException(
-            onSuccess, serializer);
-        sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(
-            onFailure, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 10, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiNatsPublishConstMeta,
-      argValues: [clientId, subject, payload, onSuccess, onFailure],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiNatsPublishConstMeta => const TaskConstMeta(
-        debugName: "publish",
-        argNames: ["clientId", "subject", "payload", "onSuccess", "onFailure"],
-      );
-
-  @override
-  Future<String> crateApiNatsSendRequest(
-      {required String clientId,
-      required String subject,
-      required String payload,
-      required BigInt timeoutMs}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(clientId, serializer);
-        sse_encode_String(subject, serializer);
-        sse_encode_String(payload, serializer);
-        sse_encode_u_64(timeoutMs, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 11, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiNatsSendRequestConstMeta,
-      argValues: [clientId, subject, payload, timeoutMs],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiNatsSendRequestConstMeta => const TaskConstMeta(
-        debugName: "send_request",
-        argNames: ["clientId", "subject", "payload", "timeoutMs"],
-      );
-
-  @override
-  Future<void> crateApiNatsSetupResponder(
-      {required String clientId,
-      required String subject,
-      required String responderId,
-      required FutureOr<String> Function(String) processRequest,
-      required FutureOr<void> Function(bool) onSuccess,
-      required FutureOr<void> Function(String) onError}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(clientId, serializer);
-        sse_encode_String(subject, serializer);
-        sse_encode_String(responderId, serializer);
-        sse_encode_DartFn_Inputs_String_Output_String_AnyhowException(
-            processRequest, serializer);
-        sse_encode_DartFn_Inputs_bool_Output_unit_AnyhowException(
-            onSuccess, serializer);
-        sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(
-            onError, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 12, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiNatsSetupResponderConstMeta,
-      argValues: [
-        clientId,
-        subject,
-        responderId,
-        processRequest,
-        onSuccess,
-        onError
-      ],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiNatsSetupResponderConstMeta => const TaskConstMeta(
-        debugName: "setup_responder",
-        argNames: [
-          "clientId",
-          "subject",
-          "responderId",
-          "processRequest",
-          "onSuccess",
-          "onError"
-        ],
-      );
-
-  @override
-  Future<void> crateApiNatsSubscribe(
-      {required String clientId,
-      required String subject,
-      required String subscriptionId,
-      required int maxMessages,
-      required FutureOr<void> Function(String, String) onMessage,
-      required FutureOr<void> Function(bool) onSuccess,
-      required FutureOr<void> Function(String) onError,
-      required FutureOr<void> Function() onDone}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(clientId, serializer);
-        sse_encode_String(subject, serializer);
-        sse_encode_String(subscriptionId, serializer);
-        sse_encode_u_32(maxMessages, serializer);
-        sse_encode_DartFn_Inputs_String_String_Output_unit_AnyhowException(
-            onMessage, serializer);
-        sse_encode_DartFn_Inputs_bool_Output_unit_AnyhowException(
-            onSuccess, serializer);
-        sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(
-            onError, serializer);
-        sse_encode_DartFn_Inputs__Output_unit_AnyhowException(
-            onDone, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 13, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiNatsSubscribeConstMeta,
-      argValues: [
-        clientId,
-        subject,
-        subscriptionId,
-        maxMessages,
-        onMessage,
-        onSuccess,
-        onError,
-        onDone
-      ],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiNatsSubscribeConstMeta => const TaskConstMeta(
-        debugName: "subscribe",
-        argNames: [
-          "clientId",
-          "subject",
-          "subscriptionId",
-          "maxMessages",
-          "onMessage",
-          "onSuccess",
-          "onError",
-          "onDone"
-        ],
-      );
-
-  @override
-  Future<void> crateApiNatsUnsubscribe(
-      {required String clientId,
-      required String subscriptionId,
-      required FutureOr<void> Function(bool) onSuccess,
-      required FutureOr<void> Function(String) onFailure}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(clientId, serializer);
-        sse_encode_String(subscriptionId, serializer);
-        sse_encode_DartFn_Inputs_bool_Output_unit_AnyhowException(
-            onSuccess, serializer);
-        sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(
-            onFailure, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 14, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiNatsUnsubscribeConstMeta,
-      argValues: [clientId, subscriptionId, onSuccess, onFailure],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiNatsUnsubscribeConstMeta => const TaskConstMeta(
-        debugName: "unsubscribe",
-        argNames: ["clientId", "subscriptionId", "onSuccess", "onFailure"],
-      );
-
-  Future<void> Function(int, dynamic)
-      encode_DartFn_Inputs_String_Output_String_AnyhowException(
-          FutureOr<String> Function(String) raw) {
-    return (callId, rawArg0) async {
-      final arg0 = dco_decode_String(rawArg0);
-
-      Box<String>? rawOutput;
-      Box<AnyhowException>? rawError;
-      try {
-        rawOutput = Box(await raw(arg0));
-      } catch (e, s) {
-        rawError = Box(AnyhowException("$e\n\n$s"));
-      }
-
-      final serializer = SseSerializer(generalizedFrbRustBinding);
-      assert((rawOutput != null) ^ (rawError != null));
-      if (rawOutput != null) {
-        serializer.buffer.putUint8(0);
-        sse_encode_String(rawOutput.value, serializer);
-      } else {
-        serializer.buffer.putUint8(1);
-        sse_encode_AnyhowException(rawError!.value, serializer);
-      }
-      final output = serializer.intoRaw();
-
-      generalizedFrbRustBinding.dartFnDeliverOutput(
-          callId: callId,
-          ptr: output.ptr,
-          rustVecLen: output.rustVecLen,
-          dataLen: output.dataLen);
-    };
-  }
-
-  Future<void> Function(int, dynamic)
-      encode_DartFn_Inputs_String_Output_unit_AnyhowException(
-          FutureOr<void> Function(String) raw) {
-    return (callId, rawArg0) async {
-      final arg0 = dco_decode_String(rawArg0);
-
-      Box<void>? rawOutput;
-      Box<AnyhowException>? rawError;
-      try {
-        rawOutput = Box(await raw(arg0));
-      } catch (e, s) {
-        rawError = Box(AnyhowException("$e\n\n$s"));
-      }
-
-      final serializer = SseSerializer(generalizedFrbRustBinding);
-      assert((rawOutput != null) ^ (rawError != null));
-      if (rawOutput != null) {
-        serializer.buffer.putUint8(0);
-        sse_encode_unit(rawOutput.value, serializer);
-      } else {
-        serializer.buffer.putUint8(1);
-        sse_encode_AnyhowException(rawError!.value, serializer);
-      }
-      final output = serializer.intoRaw();
-
-      generalizedFrbRustBinding.dartFnDeliverOutput(
-          callId: callId,
-          ptr: output.ptr,
-          rustVecLen: output.rustVecLen,
-          dataLen: output.dataLen);
-    };
-  }
-
-  Future<void> Function(int, dynamic, dynamic)
-      encode_DartFn_Inputs_String_String_Output_unit_AnyhowException(
-          FutureOr<void> Function(String, String) raw) {
-    return (callId, rawArg0, rawArg1) async {
-      final arg0 = dco_decode_String(rawArg0);
-      final arg1 = dco_decode_String(rawArg1);
-
-      Box<void>? rawOutput;
-      Box<AnyhowException>? rawError;
-      try {
-        rawOutput = Box(await raw(arg0, arg1));
-      } catch (e, s) {
-        rawError = Box(AnyhowException("$e\n\n$s"));
-      }
-
-      final serializer = SseSerializer(generalizedFrbRustBinding);
-      assert((rawOutput != null) ^ (rawError != null));
-      if (rawOutput != null) {
-        serializer.buffer.putUint8(0);
-        sse_encode_unit(rawOutput.value, serializer);
-      } else {
-        serializer.buffer.putUint8(1);
-        sse_encode_AnyhowException(rawError!.value, serializer);
-      }
-      final output = serializer.intoRaw();
-
-      generalizedFrbRustBinding.dartFnDeliverOutput(
-          callId: callId,
-          ptr: output.ptr,
-          rustVecLen: output.rustVecLen,
-          dataLen: output.dataLen);
-    };
-  }
-
-  Future<void> Function(
-    int,
-  ) encode_DartFn_Inputs__Output_unit_AnyhowException(
-      FutureOr<void> Function() raw) {
-    return (
-      callId,
-    ) async {
-      Box<void>? rawOutput;
-      Box<AnyhowException>? rawError;
-      try {
-        rawOutput = Box(await raw());
-      } catch (e, s) {
-        rawError = Box(AnyhowException("$e\n\n$s"));
-      }
-
-      final serializer = SseSerializer(generalizedFrbRustBinding);
-      assert((rawOutput != null) ^ (rawError != null));
-      if (rawOutput != null) {
-        serializer.buffer.putUint8(0);
-        sse_encode_unit(rawOutput.value, serializer);
-      } else {
-        serializer.buffer.putUint8(1);
-        sse_encode_AnyhowException(rawError!.value, serializer);
-      }
-      final output = serializer.intoRaw();
-
-      generalizedFrbRustBinding.dartFnDeliverOutput(
-          callId: callId,
-          ptr: output.ptr,
-          rustVecLen: output.rustVecLen,
-          dataLen: output.dataLen);
-    };
-  }
-
-  Future<void> Function(int, dynamic)
-      encode_DartFn_Inputs_bool_Output_unit_AnyhowException(
-          FutureOr<void> Function(bool) raw) {
-    return (callId, rawArg0) async {
-      final arg0 = dco_decode_bool(rawArg0);
-
-      Box<void>? rawOutput;
-      Box<AnyhowException>? rawError;
-      try {
-        rawOutput = Box(await raw(arg0));
-      } catch (e, s) {
-        rawError = Box(AnyhowException("$e\n\n$s"));
-      }
-
-      final serializer = SseSerializer(generalizedFrbRustBinding);
-      assert((rawOutput != null) ^ (rawError != null));
-      if (rawOutput != null) {
-        serializer.buffer.putUint8(0);
-        sse_encode_unit(rawOutput.value, serializer);
-      } else {
-        serializer.buffer.putUint8(1);
-        sse_encode_AnyhowException(rawError!.value, serializer);
-      }
-      final output = serializer.intoRaw();
-
-      generalizedFrbRustBinding.dartFnDeliverOutput(
-          callId: callId,
-          ptr: output.ptr,
-          rustVecLen: output.rustVecLen,
-          dataLen: output.dataLen);
-    };
-  }
-
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
-  }
-
-  @protected
-  FutureOr<String> Function(String)
-      dco_decode_DartFn_Inputs_String_Output_String_AnyhowException(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError('');
-  }
-
-  @protected
-  FutureOr<void> Function(String)
-      dco_decode_DartFn_Inputs_String_Output_unit_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError('');
-  }
-
-  @protected
-  FutureOr<void> Function(String, String)
-      dco_decode_DartFn_Inputs_String_String_Output_unit_AnyhowException(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError('');
-  }
-
-  @protected
-  FutureOr<void> Function()
-      dco_decode_DartFn_Inputs__Output_unit_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError('');
-  }
-
-  @protected
-  FutureOr<void> Function(bool)
-      dco_decode_DartFn_Inputs_bool_Output_unit_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError('');
-  }
-
-  @protected
-  Object dco_decode_DartOpaque(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return decodeDartOpaque(raw, generalizedFrbRustBinding);
-  }
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
-
-  @protected
-  NatsConfig dco_decode_box_autoadd_nats_config(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_nats_config(raw);
-  }
-
-  @protected
-  ReconnectionConfig dco_decode_box_autoadd_reconnection_config(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_reconnection_config(raw);
-  }
-
-  @protected
-  int dco_decode_box_autoadd_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_u_64(raw);
-  }
-
-  @protected
-  PlatformInt64 dco_decode_isize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeI64(raw);
-  }
-
-  @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  NatsConfig dco_decode_nats_config(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 10)
-      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
-    return NatsConfig(
-      host: dco_decode_String(arr[0]),
-      port: dco_decode_u_16(arr[1]),
-      token: dco_decode_opt_String(arr[2]),
-      nkey: dco_decode_opt_String(arr[3]),
-      creds: dco_decode_opt_String(arr[4]),
-      user: dco_decode_opt_String(arr[5]),
-      pass: dco_decode_opt_String(arr[6]),
-      reconnection: dco_decode_opt_box_autoadd_reconnection_config(arr[7]),
-      pingInterval: dco_decode_opt_box_autoadd_u_64(arr[8]),
-      maxPingFails: dco_decode_opt_box_autoadd_u_32(arr[9]),
-    );
-  }
-
-  @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
-  }
-
-  @protected
-  ReconnectionConfig? dco_decode_opt_box_autoadd_reconnection_config(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_reconnection_config(raw);
-  }
-
-  @protected
-  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
-  }
-
-  @protected
-  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
-  }
-
-  @protected
-  ReconnectionConfig dco_decode_reconnection_config(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return ReconnectionConfig(
-      maxAttempts: dco_decode_opt_box_autoadd_u_32(arr[0]),
-      delay: dco_decode_opt_box_autoadd_u_64(arr[1]),
-    );
-  }
-
-  @protected
-  int dco_decode_u_16(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  BigInt dco_decode_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
-  }
-
-  @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
-  }
-
-  @protected
-  Object sse_decode_DartOpaque(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_isize(deserializer);
-    return decodeDartOpaque(inner, generalizedFrbRustBinding);
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  NatsConfig sse_decode_box_autoadd_nats_config(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_nats_config(deserializer));
-  }
-
-  @protected
-  ReconnectionConfig sse_decode_box_autoadd_reconnection_config(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_reconnection_config(deserializer));
-  }
-
-  @protected
-  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_u_32(deserializer));
-  }
-
-  @protected
-  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_u_64(deserializer));
-  }
-
-  @protected
-  PlatformInt64 sse_decode_isize(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getPlatformInt64();
-  }
-
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  NatsConfig sse_decode_nats_config(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_host = sse_decode_String(deserializer);
-    var var_port = sse_decode_u_16(deserializer);
-    var var_token = sse_decode_opt_String(deserializer);
-    var var_nkey = sse_decode_opt_String(deserializer);
-    var var_creds = sse_decode_opt_String(deserializer);
-    var var_user = sse_decode_opt_String(deserializer);
-    var var_pass = sse_decode_opt_String(deserializer);
-    var var_reconnection =
-        sse_decode_opt_box_autoadd_reconnection_config(deserializer);
-    var var_pingInterval = sse_decode_opt_box_autoadd_u_64(deserializer);
-    var var_maxPingFails = sse_decode_opt_box_autoadd_u_32(deserializer);
-    return NatsConfig(
-        host: var_host,
-        port: var_port,
-        token: var_token,
-        nkey: var_nkey,
-        creds: var_creds,
-        user: var_user,
-        pass: var_pass,
-        reconnection: var_reconnection,
-        pingInterval: var_pingInterval,
-        maxPingFails: var_maxPingFails);
-  }
-
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  ReconnectionConfig? sse_decode_opt_box_autoadd_reconnection_config(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_reconnection_config(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_32(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_64(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  ReconnectionConfig sse_decode_reconnection_config(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_maxAttempts = sse_decode_opt_box_autoadd_u_32(deserializer);
-    var var_delay = sse_decode_opt_box_autoadd_u_64(deserializer);
-    return ReconnectionConfig(maxAttempts: var_maxAttempts, delay: var_delay);
-  }
-
-  @protected
-  int sse_decode_u_16(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint16();
-  }
-
-  @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
-  }
-
-  @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  void sse_encode_AnyhowException(
-      AnyhowException self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
-  }
-
-  @protected
-  void sse_encode_DartFn_Inputs_String_Output_String_AnyhowException(
-      FutureOr<String> Function(String) self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_DartOpaque(
-        encode_DartFn_Inputs_String_Output_String_AnyhowException(self),
-        serializer);
-  }
-
-  @protected
-  void sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(
-      FutureOr<void> Function(String) self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_DartOpaque(
-        encode_DartFn_Inputs_String_Output_unit_AnyhowException(self),
-        serializer);
-  }
-
-  @protected
-  void sse_encode_DartFn_Inputs_String_String_Output_unit_AnyhowException(
-      FutureOr<void> Function(String, String) self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_DartOpaque(
-        encode_DartFn_Inputs_String_String_Output_unit_AnyhowException(self),
-        serializer);
-  }
-
-  @protected
-  void sse_encode_DartFn_Inputs__Output_unit_AnyhowException(
-      FutureOr<void> Function() self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_DartOpaque(
-        encode_DartFn_Inputs__Output_unit_AnyhowException(self), serializer);
-  }
-
-  @protected
-  void sse_encode_DartFn_Inputs_bool_Output_unit_AnyhowException(
-      FutureOr<void> Function(bool) self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_DartOpaque(
-        encode_DartFn_Inputs_bool_Output_unit_AnyhowException(self),
-        serializer);
-  }
-
-  @protected
-  void sse_encode_DartOpaque(Object self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_isize(
-        PlatformPointerUtil.ptrToPlatformInt64(encodeDartOpaque(
-            self, portManager.dartHandlerPort, generalizedFrbRustBinding)),
-        serializer);
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_nats_config(
-      NatsConfig self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_nats_config(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_reconnection_config(
-      ReconnectionConfig self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_reconnection_config(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_64(self, serializer);
-  }
-
-  @protected
-  void sse_encode_isize(PlatformInt64 self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putPlatformInt64(self);
-  }
-
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-      Uint8List self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_nats_config(NatsConfig self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.host, serializer);
-    sse_encode_u_16(self.port, serializer);
-    sse_encode_opt_String(self.token, serializer);
-    sse_encode_opt_String(self.nkey, serializer);
-    sse_encode_opt_String(self.creds, serializer);
-    sse_encode_opt_String(self.user, serializer);
-    sse_encode_opt_String(self.pass, serializer);
-    sse_encode_opt_box_autoadd_reconnection_config(
-        self.reconnection, serializer);
-    sse_encode_opt_box_autoadd_u_64(self.pingInterval, serializer);
-    sse_encode_opt_box_autoadd_u_32(self.maxPingFails, serializer);
-  }
-
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_reconnection_config(
-      ReconnectionConfig? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_reconnection_config(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_u_32(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_u_64(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_reconnection_config(
-      ReconnectionConfig self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_opt_box_autoadd_u_32(self.maxAttempts, serializer);
-    sse_encode_opt_box_autoadd_u_64(self.delay, serializer);
-  }
-
-  @protected
-  void sse_encode_u_16(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint16(self);
-  }
-
-  @protected
-  void sse_encode_u_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint32(self);
-  }
-
-  @protected
-  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-}
+
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
+
+                  RustLib._();
+
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                    );
+                  }
+
+                  /// Initialize flutter_rust_bridge in mock mode.
+                  /// No libraries for FFI are loaded.
+                  static void initMock({
+                    required RustLibApi api,
+                  }) {
+                    instance.initMockImpl(
+                      api: api,
+                    );
+                  }
+
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
+
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
+
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
+
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    await api.crateApiNatsInitApp();
+
+                  }
+
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
+
+                  @override
+                  String get codegenVersion => '2.8.0';
+
+                  @override
+                  int get rustContentHash => 1437585126;
+
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'rust_lib_flutter_nats',
+                    ioDirectory: 'rust/target/release/',
+                    webPrefix: 'pkg/',
+                  );
+                }
+                
+
+                abstract class RustLibApi extends BaseApi {
+                  Future<void> crateApiNatsSendRequestWithCallbacks({required String clientId , required String subject , required String payload , required BigInt timeoutMs , required FutureOr<void> Function(String) onSuccess , required FutureOr<void> Function(NatsError) onFailure });
+
+Future<AccountInfo> crateApiJetstreamAccountInfo({required String clientId });
+
+Future<String> crateApiServiceAddService({required String clientId , required String name , required String version , String? description , required Map<String, String> metadata });
+
+Future<void> crateApiServiceAddServiceEndpoint({required String clientId , required String serviceId , String? group , required String name , required String subject , required Map<String, String> metadata , required FutureOr<ServiceResponse> Function(ServiceRequest) handler , required FutureOr<void> Function(bool) onSuccess , required FutureOr<void> Function(String) onError });
+
+Future<String> crateApiServiceAddServiceGroup({required String clientId , required String serviceId , String? parentGroup , required String prefix , String? queueGroup });
+
+Future<AuthCredentials> crateApiNatsAuthCredentialsDefault();
+
+Future<void> crateApiJetstreamConfigureJetstream({required String clientId , required JetStreamOptions options });
+
+Future<void> crateApiNatsConnect({required String clientId , required NatsConfig config , required FutureOr<void> Function(bool) onSuccess , required FutureOr<void> Function(String) onFailure });
+
+Future<void> crateApiNatsConnectWithAuthCallback({required String clientId , required NatsConfig config , required FutureOr<AuthCredentials> Function(Uint8List) authCallback , required FutureOr<void> Function(bool) onSuccess , required FutureOr<void> Function(String) onFailure });
+
+Future<ConnectionStats> crateApiNatsConnectionStats({required String clientId });
+
+Future<List<JsMessage>> crateApiConsumerConsumerFetch({required String clientId , required String stream , required String consumer , required int batch , required BigInt expiresMs });
+
+Future<ConsumerInfo> crateApiConsumerConsumerInfo({required String clientId , required String stream , required String consumer });
+
+Stream<JsMessage> crateApiConsumerConsumerMessages({required String clientId , required String stream , required String consumer , required String subscriptionId });
+
+Future<JsMessage?> crateApiConsumerConsumerNext({required String clientId , required String stream , required String consumer , required BigInt timeoutMs });
+
+Future<void> crateApiConsumerConsumerSubscribe({required String clientId , required String stream , required String consumer , required String subscriptionId , required bool autoAck , required FutureOr<bool> Function(JsMessage) onMessage , required FutureOr<void> Function(bool) onSuccess , required FutureOr<void> Function(String) onError , required FutureOr<void> Function() onDone });
+
+Future<ConsumerInfo> crateApiConsumerCreateConsumer({required String clientId , required String stream , required ConsumerConfig config });
+
+Future<StreamInfo> crateApiJetstreamCreateStream({required String clientId , required StreamConfig config });
+
+Future<JwtClaims> crateApiCredentialsDecodeJwt({required String jwt });
+
+Future<bool> crateApiConsumerDeleteConsumer({required String clientId , required String stream , required String consumer });
+
+Future<bool> crateApiJetstreamDeleteStream({required String clientId , required String stream });
+
+Future<bool> crateApiJetstreamDeleteStreamMessage({required String clientId , required String stream , required BigInt sequence , required bool erase });
+
+Future<void> crateApiKvCacheDisableKvCache({required String clientId , required String bucket });
+
+Future<void> crateApiOutboxDisableOutbox({required String clientId , required bool clear });
+
+Future<void> crateApiNatsDisconnect({required String clientId , required FutureOr<void> Function(bool) onSuccess , required FutureOr<void> Function(String) onFailure });
+
+Future<List<ServiceStats>> crateApiServiceDiscoverServiceStats({required String clientId , String? name , required BigInt timeoutMs });
+
+Future<List<ServiceInfo>> crateApiServiceDiscoverServices({required String clientId , String? name , required BigInt timeoutMs });
+
+Future<void> crateApiKvCacheEnableKvCache({required String clientId , required String bucket , required String path });
+
+Future<void> crateApiOutboxEnableOutbox({required String clientId , required OutboxConfig config });
+
+Future<StoredMessage> crateApiJetstreamGetLastMessageForSubject({required String clientId , required String stream , required String subject , required bool direct });
+
+Future<StreamInfo> crateApiJetstreamGetStreamInfo({required String clientId , required String stream });
+
+Future<StoredMessage> crateApiJetstreamGetStreamMessage({required String clientId , required String stream , required BigInt sequence , required bool direct });
+
+Future<void> crateApiNatsInitApp();
+
+Future<void> crateApiConsumerJsAck({required String clientId , required String handle });
+
+Future<void> crateApiConsumerJsDoubleAck({required String clientId , required String handle });
+
+Future<void> crateApiConsumerJsInProgress({required String clientId , required String handle });
+
+Future<void> crateApiConsumerJsNak({required String clientId , required String handle , BigInt? delayMs });
+
+Future<PubAck> crateApiJetstreamJsPublish({required String clientId , required String subject , required String payload , required JsPublishOptions options });
+
+Future<JsPublishOptions> crateApiJetstreamJsPublishOptionsDefault();
+
+Future<void> crateApiConsumerJsTerm({required String clientId , required String handle });
+
+Future<KvStatus> crateApiKvKvBucketStatus({required String clientId , required String bucket });
+
+Stream<KvCacheEvent> crateApiKvCacheKvCacheEvents({required String clientId , required String bucket });
+
+Future<CachedKvValue?> crateApiKvCacheKvCacheGet({required String clientId , required String bucket , required String key });
+
+Future<BigInt?> crateApiKvCacheKvCachePut({required String clientId , required String bucket , required String key , required String value });
+
+Future<BigInt> crateApiKvKvCreate({required String clientId , required String bucket , required String key , required String value });
+
+Future<KvStatus> crateApiKvKvCreateBucket({required String clientId , required KvConfig config });
+
+Future<void> crateApiNatsKvDelete({required String clientId , required String bucketName , required String key , required FutureOr<void> Function(bool) onSuccess , required FutureOr<void> Function(NatsError) onFailure });
+
+Future<bool> crateApiKvKvDeleteBucket({required String clientId , required String bucket });
+
+Future<KvEntry?> crateApiKvKvEntry({required String clientId , required String bucket , required String key });
+
+Future<void> crateApiNatsKvGet({required String clientId , required String bucketName , required String key , required FutureOr<void> Function(String, bool) onSuccess , required FutureOr<void> Function(NatsError) onFailure });
+
+Future<DecodedPayload?> crateApiCodecKvGetDecoded({required String clientId , required String bucket , required String key , required PayloadCodec codec });
+
+Future<List<KvEntry>> crateApiKvKvHistory({required String clientId , required String bucket , required String key });
+
+Future<List<String>> crateApiKvKvKeys({required String clientId , required String bucket , String? filter , required BigInt offset , BigInt? limit });
+
+Future<void> crateApiKvKvPurge({required String clientId , required String bucket , required String key });
+
+Future<BigInt> crateApiKvKvPurgeDeletes({required String clientId , required String bucket , BigInt? olderThanMs });
+
+Future<void> crateApiNatsKvPut({required String clientId , required String bucketName , required String key , required String value , required FutureOr<void> Function(bool) onSuccess , required FutureOr<void> Function(NatsError) onFailure });
+
+Future<BigInt> crateApiCodecKvPutEncoded({required String clientId , required String bucket , required String key , required List<int> payload , required PayloadCodec codec });
+
+Future<BigInt> crateApiKvKvUpdate({required String clientId , required String bucket , required String key , required String value , required BigInt expectedRevision });
+
+Future<KvStatus> crateApiKvKvUpdateBucket({required String clientId , required KvConfig config });
+
+Stream<KvEntry> crateApiKvKvWatch({required String clientId , required String bucket , required String key , required KvWatchOptions options , required String subscriptionId });
+
+Stream<KvEntry> crateApiKvKvWatchAll({required String clientId , required String bucket , required KvWatchOptions options , required String subscriptionId });
+
+Future<List<String>> crateApiNatsListClients();
+
+Future<List<ConsumerInfo>> crateApiConsumerListConsumers({required String clientId , required String stream });
+
+Future<List<RecipientKey>> crateApiEncryptionListRecipientKeys({required String clientId });
+
+Future<List<String>> crateApiServiceListServices({required String clientId });
+
+Future<List<StreamInfo>> crateApiJetstreamListStreams({required String clientId });
+
+Future<List<String>> crateApiNatsListSubscriptions({required String clientId });
+
+Future<BigInt> crateApiNatsMaxPayload({required String clientId });
+
+Future<NkeyPair> crateApiCredentialsNkeyCreate({required NkeyKind kind });
+
+Future<String> crateApiCredentialsNkeyPublicKey({required String seed });
+
+Future<String> crateApiCredentialsNkeySign({required String seed , required List<int> nonce });
+
+Future<bool> crateApiCredentialsNkeyVerify({required String publicKey , required List<int> nonce , required String signature });
+
+Future<ObjectInfo> crateApiObjectStoreObjectAddLink({required String clientId , required String bucket , required String name , required ObjectLink target });
+
+Future<void> crateApiObjectStoreObjectDelete({required String clientId , required String bucket , required String name });
+
+Future<Uint8List> crateApiObjectStoreObjectGet({required String clientId , required String bucket , required String name , required FutureOr<void> Function(BigInt, BigInt) onProgress });
+
+Future<ObjectInfo> crateApiObjectStoreObjectGetFile({required String clientId , required String bucket , required String name , required String path , required FutureOr<void> Function(BigInt, BigInt) onProgress });
+
+Future<ObjectInfo> crateApiObjectStoreObjectInfo({required String clientId , required String bucket , required String name });
+
+Future<List<ObjectInfo>> crateApiObjectStoreObjectList({required String clientId , required String bucket });
+
+Future<ObjectInfo> crateApiObjectStoreObjectPut({required String clientId , required String bucket , required ObjectMeta meta , required List<int> data , required FutureOr<void> Function(BigInt, BigInt) onProgress });
+
+Future<ObjectInfo> crateApiObjectStoreObjectPutFile({required String clientId , required String bucket , required ObjectMeta meta , required String path , required FutureOr<void> Function(BigInt, BigInt) onProgress });
+
+Future<void> crateApiObjectStoreObjectStoreCreate({required String clientId , required ObjectStoreConfig config });
+
+Future<void> crateApiObjectStoreObjectStoreDelete({required String clientId , required String bucket });
+
+Stream<ObjectInfo> crateApiObjectStoreObjectWatch({required String clientId , required String bucket , required bool includeHistory , required String subscriptionId });
+
+Stream<JsMessage> crateApiConsumerOrderedConsume({required String clientId , required String stream , required List<String> filterSubjects , required DeliverPolicy deliverPolicy , BigInt? startSequence , PlatformInt64? startTimeMs , required String subscriptionId });
+
+Stream<OutboxEvent> crateApiOutboxOutboxEvents({required String clientId });
+
+Future<OutboxStatus> crateApiOutboxOutboxStatus({required String clientId });
+
+Future<Credentials> crateApiCredentialsParseCreds({required String contents });
+
+Future<Credentials> crateApiCredentialsParseCredsFile({required String path });
+
+Future<ConsumerPauseState> crateApiConsumerPauseConsumer({required String clientId , required String stream , required String consumer , required PlatformInt64 pauseUntilMs });
+
+Future<List<ServicePing>> crateApiServicePingServices({required String clientId , String? name , required BigInt timeoutMs });
+
+Future<void> crateApiNatsPublish({required String clientId , required String subject , required String payload , required FutureOr<void> Function(bool) onSuccess , required FutureOr<void> Function(NatsError) onFailure });
+
+Future<void> crateApiCodecPublishEncoded({required String clientId , required String subject , required List<int> payload , required PayloadCodec codec });
+
+Future<BigInt> crateApiJetstreamPurgeStream({required String clientId , required String stream , String? filter , BigInt? keep , BigInt? sequence });
+
+Future<bool> crateApiEncryptionRemoveRecipientKey({required String clientId , required String subject });
+
+Future<DecodedMessage> crateApiCodecRequestEncoded({required String clientId , required String subject , required List<int> payload , required PayloadCodec codec , required BigInt timeoutMs });
+
+Future<void> crateApiServiceResetServiceStats({required String clientId , required String serviceId });
+
+Future<ConsumerPauseState> crateApiConsumerResumeConsumer({required String clientId , required String stream , required String consumer });
+
+Future<String> crateApiNatsSendRequest({required String clientId , required String subject , required String payload , required BigInt timeoutMs });
+
+Future<ServerInfo> crateApiNatsServerInfo({required String clientId });
+
+Future<ServiceInfo> crateApiServiceServiceInfo({required String clientId , required String serviceId });
+
+Future<ServiceStats> crateApiServiceServiceStats({required String clientId , required String serviceId });
+
+Future<void> crateApiCompressionSetCompression({required String clientId , CompressionConfig? config });
+
+Future<void> crateApiCompressionSetDecompressionLimit({required String clientId , BigInt? limit });
+
+Future<void> crateApiNatsSetKvAutoCreate({required String clientId , required bool enabled });
+
+Future<void> crateApiEncryptionSetRecipientKey({required String clientId , required String subject , required String publicKey });
+
+Future<void> crateApiNatsSetupResponder({required String clientId , required String subject , required String responderId , required FutureOr<String> Function(String) processRequest , required FutureOr<void> Function(bool) onSuccess , required FutureOr<void> Function(String) onError });
+
+Future<void> crateApiServiceStopService({required String clientId , required String serviceId });
+
+Future<List<String>> crateApiJetstreamStreamNames({required String clientId });
+
+Future<String> crateApiSubjectSubjectJoin({required List<String> tokens });
+
+Future<bool> crateApiSubjectSubjectMatches({required String pattern , required String subject });
+
+Future<void> crateApiNatsSubscribe({required String clientId , required String subject , required String subscriptionId , required int maxMessages , required FutureOr<void> Function(String, String) onMessage , required FutureOr<void> Function(bool) onSuccess , required FutureOr<void> Function(NatsError) onError , required FutureOr<void> Function() onDone });
+
+Stream<DecodedMessage> crateApiCodecSubscribeDecoded({required String clientId , required String subject , required PayloadCodec defaultCodec , required String subscriptionId });
+
+Future<void> crateApiNatsUnsubscribe({required String clientId , required String subscriptionId , required FutureOr<void> Function(bool) onSuccess , required FutureOr<void> Function(String) onFailure });
+
+Future<ConsumerInfo> crateApiConsumerUpdateConsumer({required String clientId , required String stream , required ConsumerConfig config });
+
+Future<StreamInfo> crateApiJetstreamUpdateStream({required String clientId , required StreamConfig config });
+
+Future<void> crateApiSubjectValidateSubject({required String subject , required bool allowWildcards });
+
+Future<XKeyPair?> crateApiEncryptionXkeyExport({required String clientId });
+
+Future<XKeyPair> crateApiEncryptionXkeyGenerate();
+
+Future<String> crateApiEncryptionXkeyImport({required String clientId , required String seed });
+
+
+                }
+                
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override Future<void> crateApiNatsSendRequestWithCallbacks({required String clientId , required String subject , required String payload , required BigInt timeoutMs , required FutureOr<void> Function(String) onSuccess , required FutureOr<void> Function(NatsError) onFailure })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(subject, serializer);
+sse_encode_String(payload, serializer);
+sse_encode_u_64(timeoutMs, serializer);
+sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(onSuccess, serializer);
+sse_encode_DartFn_Inputs_nats_error_Output_unit_AnyhowException(onFailure, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiNatsSendRequestWithCallbacksConstMeta,
+            argValues: [clientId, subject, payload, timeoutMs, onSuccess, onFailure],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNatsSendRequestWithCallbacksConstMeta => const TaskConstMeta(
+            debugName: "_send_request_with_callbacks",
+            argNames: ["clientId", "subject", "payload", "timeoutMs", "onSuccess", "onFailure"],
+        );
+        
+
+@override Future<AccountInfo> crateApiJetstreamAccountInfo({required String clientId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_account_info,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiJetstreamAccountInfoConstMeta,
+            argValues: [clientId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJetstreamAccountInfoConstMeta => const TaskConstMeta(
+            debugName: "account_info",
+            argNames: ["clientId"],
+        );
+        
+
+@override Future<String> crateApiServiceAddService({required String clientId , required String name , required String version , String? description , required Map<String, String> metadata })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(name, serializer);
+sse_encode_String(version, serializer);
+sse_encode_opt_String(description, serializer);
+sse_encode_Map_String_String(metadata, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiServiceAddServiceConstMeta,
+            argValues: [clientId, name, version, description, metadata],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiServiceAddServiceConstMeta => const TaskConstMeta(
+            debugName: "add_service",
+            argNames: ["clientId", "name", "version", "description", "metadata"],
+        );
+        
+
+@override Future<void> crateApiServiceAddServiceEndpoint({required String clientId , required String serviceId , String? group , required String name , required String subject , required Map<String, String> metadata , required FutureOr<ServiceResponse> Function(ServiceRequest) handler , required FutureOr<void> Function(bool) onSuccess , required FutureOr<void> Function(String) onError })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(serviceId, serializer);
+sse_encode_opt_String(group, serializer);
+sse_encode_String(name, serializer);
+sse_encode_String(subject, serializer);
+sse_encode_Map_String_String(metadata, serializer);
+sse_encode_DartFn_Inputs_service_request_Output_service_response_AnyhowException(handler, serializer);
+sse_encode_DartFn_Inputs_bool_Output_unit_AnyhowException(onSuccess, serializer);
+sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(onError, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiServiceAddServiceEndpointConstMeta,
+            argValues: [clientId, serviceId, group, name, subject, metadata, handler, onSuccess, onError],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiServiceAddServiceEndpointConstMeta => const TaskConstMeta(
+            debugName: "add_service_endpoint",
+            argNames: ["clientId", "serviceId", "group", "name", "subject", "metadata", "handler", "onSuccess", "onError"],
+        );
+        
+
+@override Future<String> crateApiServiceAddServiceGroup({required String clientId , required String serviceId , String? parentGroup , required String prefix , String? queueGroup })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(serviceId, serializer);
+sse_encode_opt_String(parentGroup, serializer);
+sse_encode_String(prefix, serializer);
+sse_encode_opt_String(queueGroup, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiServiceAddServiceGroupConstMeta,
+            argValues: [clientId, serviceId, parentGroup, prefix, queueGroup],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiServiceAddServiceGroupConstMeta => const TaskConstMeta(
+            debugName: "add_service_group",
+            argNames: ["clientId", "serviceId", "parentGroup", "prefix", "queueGroup"],
+        );
+        
+
+@override Future<AuthCredentials> crateApiNatsAuthCredentialsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_auth_credentials,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiNatsAuthCredentialsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNatsAuthCredentialsDefaultConstMeta => const TaskConstMeta(
+            debugName: "auth_credentials_default",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiJetstreamConfigureJetstream({required String clientId , required JetStreamOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_box_autoadd_jet_stream_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiJetstreamConfigureJetstreamConstMeta,
+            argValues: [clientId, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJetstreamConfigureJetstreamConstMeta => const TaskConstMeta(
+            debugName: "configure_jetstream",
+            argNames: ["clientId", "options"],
+        );
+        
+
+@override Future<void> crateApiNatsConnect({required String clientId , required NatsConfig config , required FutureOr<void> Function(bool) onSuccess , required FutureOr<void> Function(String) onFailure })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_box_autoadd_nats_config(config, serializer);
+sse_encode_DartFn_Inputs_bool_Output_unit_AnyhowException(onSuccess, serializer);
+sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(onFailure, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiNatsConnectConstMeta,
+            argValues: [clientId, config, onSuccess, onFailure],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNatsConnectConstMeta => const TaskConstMeta(
+            debugName: "connect",
+            argNames: ["clientId", "config", "onSuccess", "onFailure"],
+        );
+        
+
+@override Future<void> crateApiNatsConnectWithAuthCallback({required String clientId , required NatsConfig config , required FutureOr<AuthCredentials> Function(Uint8List) authCallback , required FutureOr<void> Function(bool) onSuccess , required FutureOr<void> Function(String) onFailure })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_box_autoadd_nats_config(config, serializer);
+sse_encode_DartFn_Inputs_list_prim_u_8_strict_Output_auth_credentials_AnyhowException(authCallback, serializer);
+sse_encode_DartFn_Inputs_bool_Output_unit_AnyhowException(onSuccess, serializer);
+sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(onFailure, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiNatsConnectWithAuthCallbackConstMeta,
+            argValues: [clientId, config, authCallback, onSuccess, onFailure],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNatsConnectWithAuthCallbackConstMeta => const TaskConstMeta(
+            debugName: "connect_with_auth_callback",
+            argNames: ["clientId", "config", "authCallback", "onSuccess", "onFailure"],
+        );
+        
+
+@override Future<ConnectionStats> crateApiNatsConnectionStats({required String clientId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_connection_stats,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiNatsConnectionStatsConstMeta,
+            argValues: [clientId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNatsConnectionStatsConstMeta => const TaskConstMeta(
+            debugName: "connection_stats",
+            argNames: ["clientId"],
+        );
+        
+
+@override Future<List<JsMessage>> crateApiConsumerConsumerFetch({required String clientId , required String stream , required String consumer , required int batch , required BigInt expiresMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(stream, serializer);
+sse_encode_String(consumer, serializer);
+sse_encode_u_32(batch, serializer);
+sse_encode_u_64(expiresMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_js_message,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiConsumerConsumerFetchConstMeta,
+            argValues: [clientId, stream, consumer, batch, expiresMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConsumerConsumerFetchConstMeta => const TaskConstMeta(
+            debugName: "consumer_fetch",
+            argNames: ["clientId", "stream", "consumer", "batch", "expiresMs"],
+        );
+        
+
+@override Future<ConsumerInfo> crateApiConsumerConsumerInfo({required String clientId , required String stream , required String consumer })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(stream, serializer);
+sse_encode_String(consumer, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_consumer_info,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiConsumerConsumerInfoConstMeta,
+            argValues: [clientId, stream, consumer],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConsumerConsumerInfoConstMeta => const TaskConstMeta(
+            debugName: "consumer_info",
+            argNames: ["clientId", "stream", "consumer"],
+        );
+        
+
+@override Stream<JsMessage> crateApiConsumerConsumerMessages({required String clientId , required String stream , required String consumer , required String subscriptionId })  { 
+            final sink = RustStreamSink<JsMessage>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(stream, serializer);
+sse_encode_String(consumer, serializer);
+sse_encode_String(subscriptionId, serializer);
+sse_encode_StreamSink_js_message_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiConsumerConsumerMessagesConstMeta,
+            argValues: [clientId, stream, consumer, subscriptionId, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiConsumerConsumerMessagesConstMeta => const TaskConstMeta(
+            debugName: "consumer_messages",
+            argNames: ["clientId", "stream", "consumer", "subscriptionId", "sink"],
+        );
+        
+
+@override Future<JsMessage?> crateApiConsumerConsumerNext({required String clientId , required String stream , required String consumer , required BigInt timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(stream, serializer);
+sse_encode_String(consumer, serializer);
+sse_encode_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_js_message,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiConsumerConsumerNextConstMeta,
+            argValues: [clientId, stream, consumer, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConsumerConsumerNextConstMeta => const TaskConstMeta(
+            debugName: "consumer_next",
+            argNames: ["clientId", "stream", "consumer", "timeoutMs"],
+        );
+        
+
+@override Future<void> crateApiConsumerConsumerSubscribe({required String clientId , required String stream , required String consumer , required String subscriptionId , required bool autoAck , required FutureOr<bool> Function(JsMessage) onMessage , required FutureOr<void> Function(bool) onSuccess , required FutureOr<void> Function(String) onError , required FutureOr<void> Function() onDone })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(stream, serializer);
+sse_encode_String(consumer, serializer);
+sse_encode_String(subscriptionId, serializer);
+sse_encode_bool(autoAck, serializer);
+sse_encode_DartFn_Inputs_js_message_Output_bool_AnyhowException(onMessage, serializer);
+sse_encode_DartFn_Inputs_bool_Output_unit_AnyhowException(onSuccess, serializer);
+sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(onError, serializer);
+sse_encode_DartFn_Inputs__Output_unit_AnyhowException(onDone, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiConsumerConsumerSubscribeConstMeta,
+            argValues: [clientId, stream, consumer, subscriptionId, autoAck, onMessage, onSuccess, onError, onDone],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConsumerConsumerSubscribeConstMeta => const TaskConstMeta(
+            debugName: "consumer_subscribe",
+            argNames: ["clientId", "stream", "consumer", "subscriptionId", "autoAck", "onMessage", "onSuccess", "onError", "onDone"],
+        );
+        
+
+@override Future<ConsumerInfo> crateApiConsumerCreateConsumer({required String clientId , required String stream , required ConsumerConfig config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(stream, serializer);
+sse_encode_box_autoadd_consumer_config(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_consumer_info,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiConsumerCreateConsumerConstMeta,
+            argValues: [clientId, stream, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConsumerCreateConsumerConstMeta => const TaskConstMeta(
+            debugName: "create_consumer",
+            argNames: ["clientId", "stream", "config"],
+        );
+        
+
+@override Future<StreamInfo> crateApiJetstreamCreateStream({required String clientId , required StreamConfig config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_box_autoadd_stream_config(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_stream_info,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiJetstreamCreateStreamConstMeta,
+            argValues: [clientId, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJetstreamCreateStreamConstMeta => const TaskConstMeta(
+            debugName: "create_stream",
+            argNames: ["clientId", "config"],
+        );
+        
+
+@override Future<JwtClaims> crateApiCredentialsDecodeJwt({required String jwt })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(jwt, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_jwt_claims,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCredentialsDecodeJwtConstMeta,
+            argValues: [jwt],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCredentialsDecodeJwtConstMeta => const TaskConstMeta(
+            debugName: "decode_jwt",
+            argNames: ["jwt"],
+        );
+        
+
+@override Future<bool> crateApiConsumerDeleteConsumer({required String clientId , required String stream , required String consumer })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(stream, serializer);
+sse_encode_String(consumer, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiConsumerDeleteConsumerConstMeta,
+            argValues: [clientId, stream, consumer],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConsumerDeleteConsumerConstMeta => const TaskConstMeta(
+            debugName: "delete_consumer",
+            argNames: ["clientId", "stream", "consumer"],
+        );
+        
+
+@override Future<bool> crateApiJetstreamDeleteStream({required String clientId , required String stream })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(stream, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiJetstreamDeleteStreamConstMeta,
+            argValues: [clientId, stream],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJetstreamDeleteStreamConstMeta => const TaskConstMeta(
+            debugName: "delete_stream",
+            argNames: ["clientId", "stream"],
+        );
+        
+
+@override Future<bool> crateApiJetstreamDeleteStreamMessage({required String clientId , required String stream , required BigInt sequence , required bool erase })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(stream, serializer);
+sse_encode_u_64(sequence, serializer);
+sse_encode_bool(erase, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiJetstreamDeleteStreamMessageConstMeta,
+            argValues: [clientId, stream, sequence, erase],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJetstreamDeleteStreamMessageConstMeta => const TaskConstMeta(
+            debugName: "delete_stream_message",
+            argNames: ["clientId", "stream", "sequence", "erase"],
+        );
+        
+
+@override Future<void> crateApiKvCacheDisableKvCache({required String clientId , required String bucket })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(bucket, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiKvCacheDisableKvCacheConstMeta,
+            argValues: [clientId, bucket],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKvCacheDisableKvCacheConstMeta => const TaskConstMeta(
+            debugName: "disable_kv_cache",
+            argNames: ["clientId", "bucket"],
+        );
+        
+
+@override Future<void> crateApiOutboxDisableOutbox({required String clientId , required bool clear })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_bool(clear, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiOutboxDisableOutboxConstMeta,
+            argValues: [clientId, clear],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOutboxDisableOutboxConstMeta => const TaskConstMeta(
+            debugName: "disable_outbox",
+            argNames: ["clientId", "clear"],
+        );
+        
+
+@override Future<void> crateApiNatsDisconnect({required String clientId , required FutureOr<void> Function(bool) onSuccess , required FutureOr<void> Function(String) onFailure })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_DartFn_Inputs_bool_Output_unit_AnyhowException(onSuccess, serializer);
+sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(onFailure, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiNatsDisconnectConstMeta,
+            argValues: [clientId, onSuccess, onFailure],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNatsDisconnectConstMeta => const TaskConstMeta(
+            debugName: "disconnect",
+            argNames: ["clientId", "onSuccess", "onFailure"],
+        );
+        
+
+@override Future<List<ServiceStats>> crateApiServiceDiscoverServiceStats({required String clientId , String? name , required BigInt timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_opt_String(name, serializer);
+sse_encode_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_service_stats,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiServiceDiscoverServiceStatsConstMeta,
+            argValues: [clientId, name, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiServiceDiscoverServiceStatsConstMeta => const TaskConstMeta(
+            debugName: "discover_service_stats",
+            argNames: ["clientId", "name", "timeoutMs"],
+        );
+        
+
+@override Future<List<ServiceInfo>> crateApiServiceDiscoverServices({required String clientId , String? name , required BigInt timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_opt_String(name, serializer);
+sse_encode_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_service_info,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiServiceDiscoverServicesConstMeta,
+            argValues: [clientId, name, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiServiceDiscoverServicesConstMeta => const TaskConstMeta(
+            debugName: "discover_services",
+            argNames: ["clientId", "name", "timeoutMs"],
+        );
+        
+
+@override Future<void> crateApiKvCacheEnableKvCache({required String clientId , required String bucket , required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(bucket, serializer);
+sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiKvCacheEnableKvCacheConstMeta,
+            argValues: [clientId, bucket, path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKvCacheEnableKvCacheConstMeta => const TaskConstMeta(
+            debugName: "enable_kv_cache",
+            argNames: ["clientId", "bucket", "path"],
+        );
+        
+
+@override Future<void> crateApiOutboxEnableOutbox({required String clientId , required OutboxConfig config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_box_autoadd_outbox_config(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiOutboxEnableOutboxConstMeta,
+            argValues: [clientId, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOutboxEnableOutboxConstMeta => const TaskConstMeta(
+            debugName: "enable_outbox",
+            argNames: ["clientId", "config"],
+        );
+        
+
+@override Future<StoredMessage> crateApiJetstreamGetLastMessageForSubject({required String clientId , required String stream , required String subject , required bool direct })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(stream, serializer);
+sse_encode_String(subject, serializer);
+sse_encode_bool(direct, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_stored_message,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiJetstreamGetLastMessageForSubjectConstMeta,
+            argValues: [clientId, stream, subject, direct],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJetstreamGetLastMessageForSubjectConstMeta => const TaskConstMeta(
+            debugName: "get_last_message_for_subject",
+            argNames: ["clientId", "stream", "subject", "direct"],
+        );
+        
+
+@override Future<StreamInfo> crateApiJetstreamGetStreamInfo({required String clientId , required String stream })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(stream, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_stream_info,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiJetstreamGetStreamInfoConstMeta,
+            argValues: [clientId, stream],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJetstreamGetStreamInfoConstMeta => const TaskConstMeta(
+            debugName: "get_stream_info",
+            argNames: ["clientId", "stream"],
+        );
+        
+
+@override Future<StoredMessage> crateApiJetstreamGetStreamMessage({required String clientId , required String stream , required BigInt sequence , required bool direct })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(stream, serializer);
+sse_encode_u_64(sequence, serializer);
+sse_encode_bool(direct, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_stored_message,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiJetstreamGetStreamMessageConstMeta,
+            argValues: [clientId, stream, sequence, direct],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJetstreamGetStreamMessageConstMeta => const TaskConstMeta(
+            debugName: "get_stream_message",
+            argNames: ["clientId", "stream", "sequence", "direct"],
+        );
+        
+
+@override Future<void> crateApiNatsInitApp()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiNatsInitAppConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNatsInitAppConstMeta => const TaskConstMeta(
+            debugName: "init_app",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiConsumerJsAck({required String clientId , required String handle })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(handle, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiConsumerJsAckConstMeta,
+            argValues: [clientId, handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConsumerJsAckConstMeta => const TaskConstMeta(
+            debugName: "js_ack",
+            argNames: ["clientId", "handle"],
+        );
+        
+
+@override Future<void> crateApiConsumerJsDoubleAck({required String clientId , required String handle })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(handle, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiConsumerJsDoubleAckConstMeta,
+            argValues: [clientId, handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConsumerJsDoubleAckConstMeta => const TaskConstMeta(
+            debugName: "js_double_ack",
+            argNames: ["clientId", "handle"],
+        );
+        
+
+@override Future<void> crateApiConsumerJsInProgress({required String clientId , required String handle })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(handle, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiConsumerJsInProgressConstMeta,
+            argValues: [clientId, handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConsumerJsInProgressConstMeta => const TaskConstMeta(
+            debugName: "js_in_progress",
+            argNames: ["clientId", "handle"],
+        );
+        
+
+@override Future<void> crateApiConsumerJsNak({required String clientId , required String handle , BigInt? delayMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(handle, serializer);
+sse_encode_opt_box_autoadd_u_64(delayMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiConsumerJsNakConstMeta,
+            argValues: [clientId, handle, delayMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConsumerJsNakConstMeta => const TaskConstMeta(
+            debugName: "js_nak",
+            argNames: ["clientId", "handle", "delayMs"],
+        );
+        
+
+@override Future<PubAck> crateApiJetstreamJsPublish({required String clientId , required String subject , required String payload , required JsPublishOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(subject, serializer);
+sse_encode_String(payload, serializer);
+sse_encode_box_autoadd_js_publish_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_pub_ack,
+          decodeErrorData: sse_decode_nats_error,
+        )
+        ,
+            constMeta: kCrateApiJetstreamJsPublishConstMeta,
+            argValues: [clientId, subject, payload, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJetstreamJsPublishConstMeta => const TaskConstMeta(
+            debugName: "js_publish",
+            argNames: ["clientId", "subject", "payload", "options"],
+        );
+        
+
+@override Future<JsPublishOptions> crateApiJetstreamJsPublishOptionsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_js_publish_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiJetstreamJsPublishOptionsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJetstreamJsPublishOptionsDefaultConstMeta => const TaskConstMeta(
+            debugName: "js_publish_options_default",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiConsumerJsTerm({required String clientId , required String handle })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(handle, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiConsumerJsTermConstMeta,
+            argValues: [clientId, handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConsumerJsTermConstMeta => const TaskConstMeta(
+            debugName: "js_term",
+            argNames: ["clientId", "handle"],
+        );
+        
+
+@override Future<KvStatus> crateApiKvKvBucketStatus({required String clientId , required String bucket })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(bucket, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_kv_status,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiKvKvBucketStatusConstMeta,
+            argValues: [clientId, bucket],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKvKvBucketStatusConstMeta => const TaskConstMeta(
+            debugName: "kv_bucket_status",
+            argNames: ["clientId", "bucket"],
+        );
+        
+
+@override Stream<KvCacheEvent> crateApiKvCacheKvCacheEvents({required String clientId , required String bucket })  { 
+            final sink = RustStreamSink<KvCacheEvent>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(bucket, serializer);
+sse_encode_StreamSink_kv_cache_event_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiKvCacheKvCacheEventsConstMeta,
+            argValues: [clientId, bucket, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiKvCacheKvCacheEventsConstMeta => const TaskConstMeta(
+            debugName: "kv_cache_events",
+            argNames: ["clientId", "bucket", "sink"],
+        );
+        
+
+@override Future<CachedKvValue?> crateApiKvCacheKvCacheGet({required String clientId , required String bucket , required String key })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(bucket, serializer);
+sse_encode_String(key, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_cached_kv_value,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiKvCacheKvCacheGetConstMeta,
+            argValues: [clientId, bucket, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKvCacheKvCacheGetConstMeta => const TaskConstMeta(
+            debugName: "kv_cache_get",
+            argNames: ["clientId", "bucket", "key"],
+        );
+        
+
+@override Future<BigInt?> crateApiKvCacheKvCachePut({required String clientId , required String bucket , required String key , required String value })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(bucket, serializer);
+sse_encode_String(key, serializer);
+sse_encode_String(value, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_u_64,
+          decodeErrorData: sse_decode_nats_error,
+        )
+        ,
+            constMeta: kCrateApiKvCacheKvCachePutConstMeta,
+            argValues: [clientId, bucket, key, value],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKvCacheKvCachePutConstMeta => const TaskConstMeta(
+            debugName: "kv_cache_put",
+            argNames: ["clientId", "bucket", "key", "value"],
+        );
+        
+
+@override Future<BigInt> crateApiKvKvCreate({required String clientId , required String bucket , required String key , required String value })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(bucket, serializer);
+sse_encode_String(key, serializer);
+sse_encode_String(value, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_nats_error,
+        )
+        ,
+            constMeta: kCrateApiKvKvCreateConstMeta,
+            argValues: [clientId, bucket, key, value],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKvKvCreateConstMeta => const TaskConstMeta(
+            debugName: "kv_create",
+            argNames: ["clientId", "bucket", "key", "value"],
+        );
+        
+
+@override Future<KvStatus> crateApiKvKvCreateBucket({required String clientId , required KvConfig config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_box_autoadd_kv_config(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_kv_status,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiKvKvCreateBucketConstMeta,
+            argValues: [clientId, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKvKvCreateBucketConstMeta => const TaskConstMeta(
+            debugName: "kv_create_bucket",
+            argNames: ["clientId", "config"],
+        );
+        
+
+@override Future<void> crateApiNatsKvDelete({required String clientId , required String bucketName , required String key , required FutureOr<void> Function(bool) onSuccess , required FutureOr<void> Function(NatsError) onFailure })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(bucketName, serializer);
+sse_encode_String(key, serializer);
+sse_encode_DartFn_Inputs_bool_Output_unit_AnyhowException(onSuccess, serializer);
+sse_encode_DartFn_Inputs_nats_error_Output_unit_AnyhowException(onFailure, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiNatsKvDeleteConstMeta,
+            argValues: [clientId, bucketName, key, onSuccess, onFailure],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNatsKvDeleteConstMeta => const TaskConstMeta(
+            debugName: "kv_delete",
+            argNames: ["clientId", "bucketName", "key", "onSuccess", "onFailure"],
+        );
+        
+
+@override Future<bool> crateApiKvKvDeleteBucket({required String clientId , required String bucket })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(bucket, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiKvKvDeleteBucketConstMeta,
+            argValues: [clientId, bucket],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKvKvDeleteBucketConstMeta => const TaskConstMeta(
+            debugName: "kv_delete_bucket",
+            argNames: ["clientId", "bucket"],
+        );
+        
+
+@override Future<KvEntry?> crateApiKvKvEntry({required String clientId , required String bucket , required String key })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(bucket, serializer);
+sse_encode_String(key, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_kv_entry,
+          decodeErrorData: sse_decode_nats_error,
+        )
+        ,
+            constMeta: kCrateApiKvKvEntryConstMeta,
+            argValues: [clientId, bucket, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKvKvEntryConstMeta => const TaskConstMeta(
+            debugName: "kv_entry",
+            argNames: ["clientId", "bucket", "key"],
+        );
+        
+
+@override Future<void> crateApiNatsKvGet({required String clientId , required String bucketName , required String key , required FutureOr<void> Function(String, bool) onSuccess , required FutureOr<void> Function(NatsError) onFailure })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(bucketName, serializer);
+sse_encode_String(key, serializer);
+sse_encode_DartFn_Inputs_String_bool_Output_unit_AnyhowException(onSuccess, serializer);
+sse_encode_DartFn_Inputs_nats_error_Output_unit_AnyhowException(onFailure, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiNatsKvGetConstMeta,
+            argValues: [clientId, bucketName, key, onSuccess, onFailure],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNatsKvGetConstMeta => const TaskConstMeta(
+            debugName: "kv_get",
+            argNames: ["clientId", "bucketName", "key", "onSuccess", "onFailure"],
+        );
+        
+
+@override Future<DecodedPayload?> crateApiCodecKvGetDecoded({required String clientId , required String bucket , required String key , required PayloadCodec codec })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(bucket, serializer);
+sse_encode_String(key, serializer);
+sse_encode_payload_codec(codec, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_decoded_payload,
+          decodeErrorData: sse_decode_nats_error,
+        )
+        ,
+            constMeta: kCrateApiCodecKvGetDecodedConstMeta,
+            argValues: [clientId, bucket, key, codec],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCodecKvGetDecodedConstMeta => const TaskConstMeta(
+            debugName: "kv_get_decoded",
+            argNames: ["clientId", "bucket", "key", "codec"],
+        );
+        
+
+@override Future<List<KvEntry>> crateApiKvKvHistory({required String clientId , required String bucket , required String key })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(bucket, serializer);
+sse_encode_String(key, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_kv_entry,
+          decodeErrorData: sse_decode_nats_error,
+        )
+        ,
+            constMeta: kCrateApiKvKvHistoryConstMeta,
+            argValues: [clientId, bucket, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKvKvHistoryConstMeta => const TaskConstMeta(
+            debugName: "kv_history",
+            argNames: ["clientId", "bucket", "key"],
+        );
+        
+
+@override Future<List<String>> crateApiKvKvKeys({required String clientId , required String bucket , String? filter , required BigInt offset , BigInt? limit })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(bucket, serializer);
+sse_encode_opt_String(filter, serializer);
+sse_encode_u_64(offset, serializer);
+sse_encode_opt_box_autoadd_u_64(limit, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_nats_error,
+        )
+        ,
+            constMeta: kCrateApiKvKvKeysConstMeta,
+            argValues: [clientId, bucket, filter, offset, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKvKvKeysConstMeta => const TaskConstMeta(
+            debugName: "kv_keys",
+            argNames: ["clientId", "bucket", "filter", "offset", "limit"],
+        );
+        
+
+@override Future<void> crateApiKvKvPurge({required String clientId , required String bucket , required String key })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(bucket, serializer);
+sse_encode_String(key, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_nats_error,
+        )
+        ,
+            constMeta: kCrateApiKvKvPurgeConstMeta,
+            argValues: [clientId, bucket, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKvKvPurgeConstMeta => const TaskConstMeta(
+            debugName: "kv_purge",
+            argNames: ["clientId", "bucket", "key"],
+        );
+        
+
+@override Future<BigInt> crateApiKvKvPurgeDeletes({required String clientId , required String bucket , BigInt? olderThanMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(bucket, serializer);
+sse_encode_opt_box_autoadd_u_64(olderThanMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_nats_error,
+        )
+        ,
+            constMeta: kCrateApiKvKvPurgeDeletesConstMeta,
+            argValues: [clientId, bucket, olderThanMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKvKvPurgeDeletesConstMeta => const TaskConstMeta(
+            debugName: "kv_purge_deletes",
+            argNames: ["clientId", "bucket", "olderThanMs"],
+        );
+        
+
+@override Future<void> crateApiNatsKvPut({required String clientId , required String bucketName , required String key , required String value , required FutureOr<void> Function(bool) onSuccess , required FutureOr<void> Function(NatsError) onFailure })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(bucketName, serializer);
+sse_encode_String(key, serializer);
+sse_encode_String(value, serializer);
+sse_encode_DartFn_Inputs_bool_Output_unit_AnyhowException(onSuccess, serializer);
+sse_encode_DartFn_Inputs_nats_error_Output_unit_AnyhowException(onFailure, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiNatsKvPutConstMeta,
+            argValues: [clientId, bucketName, key, value, onSuccess, onFailure],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNatsKvPutConstMeta => const TaskConstMeta(
+            debugName: "kv_put",
+            argNames: ["clientId", "bucketName", "key", "value", "onSuccess", "onFailure"],
+        );
+        
+
+@override Future<BigInt> crateApiCodecKvPutEncoded({required String clientId , required String bucket , required String key , required List<int> payload , required PayloadCodec codec })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(bucket, serializer);
+sse_encode_String(key, serializer);
+sse_encode_list_prim_u_8_loose(payload, serializer);
+sse_encode_payload_codec(codec, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_nats_error,
+        )
+        ,
+            constMeta: kCrateApiCodecKvPutEncodedConstMeta,
+            argValues: [clientId, bucket, key, payload, codec],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCodecKvPutEncodedConstMeta => const TaskConstMeta(
+            debugName: "kv_put_encoded",
+            argNames: ["clientId", "bucket", "key", "payload", "codec"],
+        );
+        
+
+@override Future<BigInt> crateApiKvKvUpdate({required String clientId , required String bucket , required String key , required String value , required BigInt expectedRevision })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(bucket, serializer);
+sse_encode_String(key, serializer);
+sse_encode_String(value, serializer);
+sse_encode_u_64(expectedRevision, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_nats_error,
+        )
+        ,
+            constMeta: kCrateApiKvKvUpdateConstMeta,
+            argValues: [clientId, bucket, key, value, expectedRevision],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKvKvUpdateConstMeta => const TaskConstMeta(
+            debugName: "kv_update",
+            argNames: ["clientId", "bucket", "key", "value", "expectedRevision"],
+        );
+        
+
+@override Future<KvStatus> crateApiKvKvUpdateBucket({required String clientId , required KvConfig config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_box_autoadd_kv_config(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_kv_status,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiKvKvUpdateBucketConstMeta,
+            argValues: [clientId, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKvKvUpdateBucketConstMeta => const TaskConstMeta(
+            debugName: "kv_update_bucket",
+            argNames: ["clientId", "config"],
+        );
+        
+
+@override Stream<KvEntry> crateApiKvKvWatch({required String clientId , required String bucket , required String key , required KvWatchOptions options , required String subscriptionId })  { 
+            final sink = RustStreamSink<KvEntry>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(bucket, serializer);
+sse_encode_String(key, serializer);
+sse_encode_box_autoadd_kv_watch_options(options, serializer);
+sse_encode_String(subscriptionId, serializer);
+sse_encode_StreamSink_kv_entry_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_nats_error,
+        )
+        ,
+            constMeta: kCrateApiKvKvWatchConstMeta,
+            argValues: [clientId, bucket, key, options, subscriptionId, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiKvKvWatchConstMeta => const TaskConstMeta(
+            debugName: "kv_watch",
+            argNames: ["clientId", "bucket", "key", "options", "subscriptionId", "sink"],
+        );
+        
+
+@override Stream<KvEntry> crateApiKvKvWatchAll({required String clientId , required String bucket , required KvWatchOptions options , required String subscriptionId })  { 
+            final sink = RustStreamSink<KvEntry>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(bucket, serializer);
+sse_encode_box_autoadd_kv_watch_options(options, serializer);
+sse_encode_String(subscriptionId, serializer);
+sse_encode_StreamSink_kv_entry_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_nats_error,
+        )
+        ,
+            constMeta: kCrateApiKvKvWatchAllConstMeta,
+            argValues: [clientId, bucket, options, subscriptionId, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiKvKvWatchAllConstMeta => const TaskConstMeta(
+            debugName: "kv_watch_all",
+            argNames: ["clientId", "bucket", "options", "subscriptionId", "sink"],
+        );
+        
+
+@override Future<List<String>> crateApiNatsListClients()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiNatsListClientsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNatsListClientsConstMeta => const TaskConstMeta(
+            debugName: "list_clients",
+            argNames: [],
+        );
+        
+
+@override Future<List<ConsumerInfo>> crateApiConsumerListConsumers({required String clientId , required String stream })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(stream, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_consumer_info,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiConsumerListConsumersConstMeta,
+            argValues: [clientId, stream],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConsumerListConsumersConstMeta => const TaskConstMeta(
+            debugName: "list_consumers",
+            argNames: ["clientId", "stream"],
+        );
+        
+
+@override Future<List<RecipientKey>> crateApiEncryptionListRecipientKeys({required String clientId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_recipient_key,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEncryptionListRecipientKeysConstMeta,
+            argValues: [clientId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEncryptionListRecipientKeysConstMeta => const TaskConstMeta(
+            debugName: "list_recipient_keys",
+            argNames: ["clientId"],
+        );
+        
+
+@override Future<List<String>> crateApiServiceListServices({required String clientId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiServiceListServicesConstMeta,
+            argValues: [clientId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiServiceListServicesConstMeta => const TaskConstMeta(
+            debugName: "list_services",
+            argNames: ["clientId"],
+        );
+        
+
+@override Future<List<StreamInfo>> crateApiJetstreamListStreams({required String clientId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_stream_info,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiJetstreamListStreamsConstMeta,
+            argValues: [clientId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJetstreamListStreamsConstMeta => const TaskConstMeta(
+            debugName: "list_streams",
+            argNames: ["clientId"],
+        );
+        
+
+@override Future<List<String>> crateApiNatsListSubscriptions({required String clientId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiNatsListSubscriptionsConstMeta,
+            argValues: [clientId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNatsListSubscriptionsConstMeta => const TaskConstMeta(
+            debugName: "list_subscriptions",
+            argNames: ["clientId"],
+        );
+        
+
+@override Future<BigInt> crateApiNatsMaxPayload({required String clientId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiNatsMaxPayloadConstMeta,
+            argValues: [clientId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNatsMaxPayloadConstMeta => const TaskConstMeta(
+            debugName: "max_payload",
+            argNames: ["clientId"],
+        );
+        
+
+@override Future<NkeyPair> crateApiCredentialsNkeyCreate({required NkeyKind kind })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_nkey_kind(kind, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_nkey_pair,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCredentialsNkeyCreateConstMeta,
+            argValues: [kind],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCredentialsNkeyCreateConstMeta => const TaskConstMeta(
+            debugName: "nkey_create",
+            argNames: ["kind"],
+        );
+        
+
+@override Future<String> crateApiCredentialsNkeyPublicKey({required String seed })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(seed, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCredentialsNkeyPublicKeyConstMeta,
+            argValues: [seed],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCredentialsNkeyPublicKeyConstMeta => const TaskConstMeta(
+            debugName: "nkey_public_key",
+            argNames: ["seed"],
+        );
+        
+
+@override Future<String> crateApiCredentialsNkeySign({required String seed , required List<int> nonce })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(seed, serializer);
+sse_encode_list_prim_u_8_loose(nonce, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCredentialsNkeySignConstMeta,
+            argValues: [seed, nonce],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCredentialsNkeySignConstMeta => const TaskConstMeta(
+            debugName: "nkey_sign",
+            argNames: ["seed", "nonce"],
+        );
+        
+
+@override Future<bool> crateApiCredentialsNkeyVerify({required String publicKey , required List<int> nonce , required String signature })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(publicKey, serializer);
+sse_encode_list_prim_u_8_loose(nonce, serializer);
+sse_encode_String(signature, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCredentialsNkeyVerifyConstMeta,
+            argValues: [publicKey, nonce, signature],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCredentialsNkeyVerifyConstMeta => const TaskConstMeta(
+            debugName: "nkey_verify",
+            argNames: ["publicKey", "nonce", "signature"],
+        );
+        
+
+@override Future<ObjectInfo> crateApiObjectStoreObjectAddLink({required String clientId , required String bucket , required String name , required ObjectLink target })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(bucket, serializer);
+sse_encode_String(name, serializer);
+sse_encode_box_autoadd_object_link(target, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_object_info,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiObjectStoreObjectAddLinkConstMeta,
+            argValues: [clientId, bucket, name, target],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiObjectStoreObjectAddLinkConstMeta => const TaskConstMeta(
+            debugName: "object_add_link",
+            argNames: ["clientId", "bucket", "name", "target"],
+        );
+        
+
+@override Future<void> crateApiObjectStoreObjectDelete({required String clientId , required String bucket , required String name })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(bucket, serializer);
+sse_encode_String(name, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiObjectStoreObjectDeleteConstMeta,
+            argValues: [clientId, bucket, name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiObjectStoreObjectDeleteConstMeta => const TaskConstMeta(
+            debugName: "object_delete",
+            argNames: ["clientId", "bucket", "name"],
+        );
+        
+
+@override Future<Uint8List> crateApiObjectStoreObjectGet({required String clientId , required String bucket , required String name , required FutureOr<void> Function(BigInt, BigInt) onProgress })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(bucket, serializer);
+sse_encode_String(name, serializer);
+sse_encode_DartFn_Inputs_u_64_u_64_Output_unit_AnyhowException(onProgress, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiObjectStoreObjectGetConstMeta,
+            argValues: [clientId, bucket, name, onProgress],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiObjectStoreObjectGetConstMeta => const TaskConstMeta(
+            debugName: "object_get",
+            argNames: ["clientId", "bucket", "name", "onProgress"],
+        );
+        
+
+@override Future<ObjectInfo> crateApiObjectStoreObjectGetFile({required String clientId , required String bucket , required String name , required String path , required FutureOr<void> Function(BigInt, BigInt) onProgress })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(bucket, serializer);
+sse_encode_String(name, serializer);
+sse_encode_String(path, serializer);
+sse_encode_DartFn_Inputs_u_64_u_64_Output_unit_AnyhowException(onProgress, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_object_info,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiObjectStoreObjectGetFileConstMeta,
+            argValues: [clientId, bucket, name, path, onProgress],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiObjectStoreObjectGetFileConstMeta => const TaskConstMeta(
+            debugName: "object_get_file",
+            argNames: ["clientId", "bucket", "name", "path", "onProgress"],
+        );
+        
+
+@override Future<ObjectInfo> crateApiObjectStoreObjectInfo({required String clientId , required String bucket , required String name })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(bucket, serializer);
+sse_encode_String(name, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_object_info,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiObjectStoreObjectInfoConstMeta,
+            argValues: [clientId, bucket, name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiObjectStoreObjectInfoConstMeta => const TaskConstMeta(
+            debugName: "object_info",
+            argNames: ["clientId", "bucket", "name"],
+        );
+        
+
+@override Future<List<ObjectInfo>> crateApiObjectStoreObjectList({required String clientId , required String bucket })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(bucket, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_object_info,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiObjectStoreObjectListConstMeta,
+            argValues: [clientId, bucket],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiObjectStoreObjectListConstMeta => const TaskConstMeta(
+            debugName: "object_list",
+            argNames: ["clientId", "bucket"],
+        );
+        
+
+@override Future<ObjectInfo> crateApiObjectStoreObjectPut({required String clientId , required String bucket , required ObjectMeta meta , required List<int> data , required FutureOr<void> Function(BigInt, BigInt) onProgress })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(bucket, serializer);
+sse_encode_box_autoadd_object_meta(meta, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_DartFn_Inputs_u_64_u_64_Output_unit_AnyhowException(onProgress, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_object_info,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiObjectStoreObjectPutConstMeta,
+            argValues: [clientId, bucket, meta, data, onProgress],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiObjectStoreObjectPutConstMeta => const TaskConstMeta(
+            debugName: "object_put",
+            argNames: ["clientId", "bucket", "meta", "data", "onProgress"],
+        );
+        
+
+@override Future<ObjectInfo> crateApiObjectStoreObjectPutFile({required String clientId , required String bucket , required ObjectMeta meta , required String path , required FutureOr<void> Function(BigInt, BigInt) onProgress })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(bucket, serializer);
+sse_encode_box_autoadd_object_meta(meta, serializer);
+sse_encode_String(path, serializer);
+sse_encode_DartFn_Inputs_u_64_u_64_Output_unit_AnyhowException(onProgress, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_object_info,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiObjectStoreObjectPutFileConstMeta,
+            argValues: [clientId, bucket, meta, path, onProgress],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiObjectStoreObjectPutFileConstMeta => const TaskConstMeta(
+            debugName: "object_put_file",
+            argNames: ["clientId", "bucket", "meta", "path", "onProgress"],
+        );
+        
+
+@override Future<void> crateApiObjectStoreObjectStoreCreate({required String clientId , required ObjectStoreConfig config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_box_autoadd_object_store_config(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiObjectStoreObjectStoreCreateConstMeta,
+            argValues: [clientId, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiObjectStoreObjectStoreCreateConstMeta => const TaskConstMeta(
+            debugName: "object_store_create",
+            argNames: ["clientId", "config"],
+        );
+        
+
+@override Future<void> crateApiObjectStoreObjectStoreDelete({required String clientId , required String bucket })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(bucket, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiObjectStoreObjectStoreDeleteConstMeta,
+            argValues: [clientId, bucket],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiObjectStoreObjectStoreDeleteConstMeta => const TaskConstMeta(
+            debugName: "object_store_delete",
+            argNames: ["clientId", "bucket"],
+        );
+        
+
+@override Stream<ObjectInfo> crateApiObjectStoreObjectWatch({required String clientId , required String bucket , required bool includeHistory , required String subscriptionId })  { 
+            final sink = RustStreamSink<ObjectInfo>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(bucket, serializer);
+sse_encode_bool(includeHistory, serializer);
+sse_encode_String(subscriptionId, serializer);
+sse_encode_StreamSink_object_info_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 82, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiObjectStoreObjectWatchConstMeta,
+            argValues: [clientId, bucket, includeHistory, subscriptionId, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiObjectStoreObjectWatchConstMeta => const TaskConstMeta(
+            debugName: "object_watch",
+            argNames: ["clientId", "bucket", "includeHistory", "subscriptionId", "sink"],
+        );
+        
+
+@override Stream<JsMessage> crateApiConsumerOrderedConsume({required String clientId , required String stream , required List<String> filterSubjects , required DeliverPolicy deliverPolicy , BigInt? startSequence , PlatformInt64? startTimeMs , required String subscriptionId })  { 
+            final sink = RustStreamSink<JsMessage>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(stream, serializer);
+sse_encode_list_String(filterSubjects, serializer);
+sse_encode_deliver_policy(deliverPolicy, serializer);
+sse_encode_opt_box_autoadd_u_64(startSequence, serializer);
+sse_encode_opt_box_autoadd_i_64(startTimeMs, serializer);
+sse_encode_String(subscriptionId, serializer);
+sse_encode_StreamSink_js_message_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiConsumerOrderedConsumeConstMeta,
+            argValues: [clientId, stream, filterSubjects, deliverPolicy, startSequence, startTimeMs, subscriptionId, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiConsumerOrderedConsumeConstMeta => const TaskConstMeta(
+            debugName: "ordered_consume",
+            argNames: ["clientId", "stream", "filterSubjects", "deliverPolicy", "startSequence", "startTimeMs", "subscriptionId", "sink"],
+        );
+        
+
+@override Stream<OutboxEvent> crateApiOutboxOutboxEvents({required String clientId })  { 
+            final sink = RustStreamSink<OutboxEvent>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_StreamSink_outbox_event_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiOutboxOutboxEventsConstMeta,
+            argValues: [clientId, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiOutboxOutboxEventsConstMeta => const TaskConstMeta(
+            debugName: "outbox_events",
+            argNames: ["clientId", "sink"],
+        );
+        
+
+@override Future<OutboxStatus> crateApiOutboxOutboxStatus({required String clientId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 85, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_outbox_status,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiOutboxOutboxStatusConstMeta,
+            argValues: [clientId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOutboxOutboxStatusConstMeta => const TaskConstMeta(
+            debugName: "outbox_status",
+            argNames: ["clientId"],
+        );
+        
+
+@override Future<Credentials> crateApiCredentialsParseCreds({required String contents })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(contents, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_credentials,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCredentialsParseCredsConstMeta,
+            argValues: [contents],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCredentialsParseCredsConstMeta => const TaskConstMeta(
+            debugName: "parse_creds",
+            argNames: ["contents"],
+        );
+        
+
+@override Future<Credentials> crateApiCredentialsParseCredsFile({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 87, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_credentials,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCredentialsParseCredsFileConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCredentialsParseCredsFileConstMeta => const TaskConstMeta(
+            debugName: "parse_creds_file",
+            argNames: ["path"],
+        );
+        
+
+@override Future<ConsumerPauseState> crateApiConsumerPauseConsumer({required String clientId , required String stream , required String consumer , required PlatformInt64 pauseUntilMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(stream, serializer);
+sse_encode_String(consumer, serializer);
+sse_encode_i_64(pauseUntilMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 88, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_consumer_pause_state,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiConsumerPauseConsumerConstMeta,
+            argValues: [clientId, stream, consumer, pauseUntilMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConsumerPauseConsumerConstMeta => const TaskConstMeta(
+            debugName: "pause_consumer",
+            argNames: ["clientId", "stream", "consumer", "pauseUntilMs"],
+        );
+        
+
+@override Future<List<ServicePing>> crateApiServicePingServices({required String clientId , String? name , required BigInt timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_opt_String(name, serializer);
+sse_encode_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_service_ping,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiServicePingServicesConstMeta,
+            argValues: [clientId, name, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiServicePingServicesConstMeta => const TaskConstMeta(
+            debugName: "ping_services",
+            argNames: ["clientId", "name", "timeoutMs"],
+        );
+        
+
+@override Future<void> crateApiNatsPublish({required String clientId , required String subject , required String payload , required FutureOr<void> Function(bool) onSuccess , required FutureOr<void> Function(NatsError) onFailure })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(subject, serializer);
+sse_encode_String(payload, serializer);
+sse_encode_DartFn_Inputs_bool_Output_unit_AnyhowException(onSuccess, serializer);
+sse_encode_DartFn_Inputs_nats_error_Output_unit_AnyhowException(onFailure, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 90, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiNatsPublishConstMeta,
+            argValues: [clientId, subject, payload, onSuccess, onFailure],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNatsPublishConstMeta => const TaskConstMeta(
+            debugName: "publish",
+            argNames: ["clientId", "subject", "payload", "onSuccess", "onFailure"],
+        );
+        
+
+@override Future<void> crateApiCodecPublishEncoded({required String clientId , required String subject , required List<int> payload , required PayloadCodec codec })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(subject, serializer);
+sse_encode_list_prim_u_8_loose(payload, serializer);
+sse_encode_payload_codec(codec, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 91, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_nats_error,
+        )
+        ,
+            constMeta: kCrateApiCodecPublishEncodedConstMeta,
+            argValues: [clientId, subject, payload, codec],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCodecPublishEncodedConstMeta => const TaskConstMeta(
+            debugName: "publish_encoded",
+            argNames: ["clientId", "subject", "payload", "codec"],
+        );
+        
+
+@override Future<BigInt> crateApiJetstreamPurgeStream({required String clientId , required String stream , String? filter , BigInt? keep , BigInt? sequence })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(stream, serializer);
+sse_encode_opt_String(filter, serializer);
+sse_encode_opt_box_autoadd_u_64(keep, serializer);
+sse_encode_opt_box_autoadd_u_64(sequence, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 92, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiJetstreamPurgeStreamConstMeta,
+            argValues: [clientId, stream, filter, keep, sequence],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJetstreamPurgeStreamConstMeta => const TaskConstMeta(
+            debugName: "purge_stream",
+            argNames: ["clientId", "stream", "filter", "keep", "sequence"],
+        );
+        
+
+@override Future<bool> crateApiEncryptionRemoveRecipientKey({required String clientId , required String subject })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(subject, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 93, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiEncryptionRemoveRecipientKeyConstMeta,
+            argValues: [clientId, subject],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEncryptionRemoveRecipientKeyConstMeta => const TaskConstMeta(
+            debugName: "remove_recipient_key",
+            argNames: ["clientId", "subject"],
+        );
+        
+
+@override Future<DecodedMessage> crateApiCodecRequestEncoded({required String clientId , required String subject , required List<int> payload , required PayloadCodec codec , required BigInt timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(subject, serializer);
+sse_encode_list_prim_u_8_loose(payload, serializer);
+sse_encode_payload_codec(codec, serializer);
+sse_encode_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 94, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_decoded_message,
+          decodeErrorData: sse_decode_nats_error,
+        )
+        ,
+            constMeta: kCrateApiCodecRequestEncodedConstMeta,
+            argValues: [clientId, subject, payload, codec, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCodecRequestEncodedConstMeta => const TaskConstMeta(
+            debugName: "request_encoded",
+            argNames: ["clientId", "subject", "payload", "codec", "timeoutMs"],
+        );
+        
+
+@override Future<void> crateApiServiceResetServiceStats({required String clientId , required String serviceId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(serviceId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 95, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiServiceResetServiceStatsConstMeta,
+            argValues: [clientId, serviceId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiServiceResetServiceStatsConstMeta => const TaskConstMeta(
+            debugName: "reset_service_stats",
+            argNames: ["clientId", "serviceId"],
+        );
+        
+
+@override Future<ConsumerPauseState> crateApiConsumerResumeConsumer({required String clientId , required String stream , required String consumer })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(stream, serializer);
+sse_encode_String(consumer, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 96, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_consumer_pause_state,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiConsumerResumeConsumerConstMeta,
+            argValues: [clientId, stream, consumer],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConsumerResumeConsumerConstMeta => const TaskConstMeta(
+            debugName: "resume_consumer",
+            argNames: ["clientId", "stream", "consumer"],
+        );
+        
+
+@override Future<String> crateApiNatsSendRequest({required String clientId , required String subject , required String payload , required BigInt timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(subject, serializer);
+sse_encode_String(payload, serializer);
+sse_encode_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 97, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_nats_error,
+        )
+        ,
+            constMeta: kCrateApiNatsSendRequestConstMeta,
+            argValues: [clientId, subject, payload, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNatsSendRequestConstMeta => const TaskConstMeta(
+            debugName: "send_request",
+            argNames: ["clientId", "subject", "payload", "timeoutMs"],
+        );
+        
+
+@override Future<ServerInfo> crateApiNatsServerInfo({required String clientId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 98, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_server_info,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiNatsServerInfoConstMeta,
+            argValues: [clientId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNatsServerInfoConstMeta => const TaskConstMeta(
+            debugName: "server_info",
+            argNames: ["clientId"],
+        );
+        
+
+@override Future<ServiceInfo> crateApiServiceServiceInfo({required String clientId , required String serviceId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(serviceId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 99, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_service_info,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiServiceServiceInfoConstMeta,
+            argValues: [clientId, serviceId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiServiceServiceInfoConstMeta => const TaskConstMeta(
+            debugName: "service_info",
+            argNames: ["clientId", "serviceId"],
+        );
+        
+
+@override Future<ServiceStats> crateApiServiceServiceStats({required String clientId , required String serviceId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(serviceId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 100, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_service_stats,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiServiceServiceStatsConstMeta,
+            argValues: [clientId, serviceId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiServiceServiceStatsConstMeta => const TaskConstMeta(
+            debugName: "service_stats",
+            argNames: ["clientId", "serviceId"],
+        );
+        
+
+@override Future<void> crateApiCompressionSetCompression({required String clientId , CompressionConfig? config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_opt_box_autoadd_compression_config(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 101, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCompressionSetCompressionConstMeta,
+            argValues: [clientId, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCompressionSetCompressionConstMeta => const TaskConstMeta(
+            debugName: "set_compression",
+            argNames: ["clientId", "config"],
+        );
+        
+
+@override Future<void> crateApiCompressionSetDecompressionLimit({required String clientId , BigInt? limit })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_opt_box_autoadd_u_64(limit, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 102, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCompressionSetDecompressionLimitConstMeta,
+            argValues: [clientId, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCompressionSetDecompressionLimitConstMeta => const TaskConstMeta(
+            debugName: "set_decompression_limit",
+            argNames: ["clientId", "limit"],
+        );
+        
+
+@override Future<void> crateApiNatsSetKvAutoCreate({required String clientId , required bool enabled })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_bool(enabled, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 103, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiNatsSetKvAutoCreateConstMeta,
+            argValues: [clientId, enabled],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNatsSetKvAutoCreateConstMeta => const TaskConstMeta(
+            debugName: "set_kv_auto_create",
+            argNames: ["clientId", "enabled"],
+        );
+        
+
+@override Future<void> crateApiEncryptionSetRecipientKey({required String clientId , required String subject , required String publicKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(subject, serializer);
+sse_encode_String(publicKey, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 104, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiEncryptionSetRecipientKeyConstMeta,
+            argValues: [clientId, subject, publicKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEncryptionSetRecipientKeyConstMeta => const TaskConstMeta(
+            debugName: "set_recipient_key",
+            argNames: ["clientId", "subject", "publicKey"],
+        );
+        
+
+@override Future<void> crateApiNatsSetupResponder({required String clientId , required String subject , required String responderId , required FutureOr<String> Function(String) processRequest , required FutureOr<void> Function(bool) onSuccess , required FutureOr<void> Function(String) onError })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(subject, serializer);
+sse_encode_String(responderId, serializer);
+sse_encode_DartFn_Inputs_String_Output_String_AnyhowException(processRequest, serializer);
+sse_encode_DartFn_Inputs_bool_Output_unit_AnyhowException(onSuccess, serializer);
+sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(onError, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 105, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiNatsSetupResponderConstMeta,
+            argValues: [clientId, subject, responderId, processRequest, onSuccess, onError],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNatsSetupResponderConstMeta => const TaskConstMeta(
+            debugName: "setup_responder",
+            argNames: ["clientId", "subject", "responderId", "processRequest", "onSuccess", "onError"],
+        );
+        
+
+@override Future<void> crateApiServiceStopService({required String clientId , required String serviceId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(serviceId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 106, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiServiceStopServiceConstMeta,
+            argValues: [clientId, serviceId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiServiceStopServiceConstMeta => const TaskConstMeta(
+            debugName: "stop_service",
+            argNames: ["clientId", "serviceId"],
+        );
+        
+
+@override Future<List<String>> crateApiJetstreamStreamNames({required String clientId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 107, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiJetstreamStreamNamesConstMeta,
+            argValues: [clientId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJetstreamStreamNamesConstMeta => const TaskConstMeta(
+            debugName: "stream_names",
+            argNames: ["clientId"],
+        );
+        
+
+@override Future<String> crateApiSubjectSubjectJoin({required List<String> tokens })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(tokens, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 108, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_nats_error,
+        )
+        ,
+            constMeta: kCrateApiSubjectSubjectJoinConstMeta,
+            argValues: [tokens],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSubjectSubjectJoinConstMeta => const TaskConstMeta(
+            debugName: "subject_join",
+            argNames: ["tokens"],
+        );
+        
+
+@override Future<bool> crateApiSubjectSubjectMatches({required String pattern , required String subject })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(pattern, serializer);
+sse_encode_String(subject, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 109, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSubjectSubjectMatchesConstMeta,
+            argValues: [pattern, subject],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSubjectSubjectMatchesConstMeta => const TaskConstMeta(
+            debugName: "subject_matches",
+            argNames: ["pattern", "subject"],
+        );
+        
+
+@override Future<void> crateApiNatsSubscribe({required String clientId , required String subject , required String subscriptionId , required int maxMessages , required FutureOr<void> Function(String, String) onMessage , required FutureOr<void> Function(bool) onSuccess , required FutureOr<void> Function(NatsError) onError , required FutureOr<void> Function() onDone })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(subject, serializer);
+sse_encode_String(subscriptionId, serializer);
+sse_encode_u_32(maxMessages, serializer);
+sse_encode_DartFn_Inputs_String_String_Output_unit_AnyhowException(onMessage, serializer);
+sse_encode_DartFn_Inputs_bool_Output_unit_AnyhowException(onSuccess, serializer);
+sse_encode_DartFn_Inputs_nats_error_Output_unit_AnyhowException(onError, serializer);
+sse_encode_DartFn_Inputs__Output_unit_AnyhowException(onDone, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 110, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiNatsSubscribeConstMeta,
+            argValues: [clientId, subject, subscriptionId, maxMessages, onMessage, onSuccess, onError, onDone],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNatsSubscribeConstMeta => const TaskConstMeta(
+            debugName: "subscribe",
+            argNames: ["clientId", "subject", "subscriptionId", "maxMessages", "onMessage", "onSuccess", "onError", "onDone"],
+        );
+        
+
+@override Stream<DecodedMessage> crateApiCodecSubscribeDecoded({required String clientId , required String subject , required PayloadCodec defaultCodec , required String subscriptionId })  { 
+            final sink = RustStreamSink<DecodedMessage>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(subject, serializer);
+sse_encode_payload_codec(defaultCodec, serializer);
+sse_encode_String(subscriptionId, serializer);
+sse_encode_StreamSink_decoded_message_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 111, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCodecSubscribeDecodedConstMeta,
+            argValues: [clientId, subject, defaultCodec, subscriptionId, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiCodecSubscribeDecodedConstMeta => const TaskConstMeta(
+            debugName: "subscribe_decoded",
+            argNames: ["clientId", "subject", "defaultCodec", "subscriptionId", "sink"],
+        );
+        
+
+@override Future<void> crateApiNatsUnsubscribe({required String clientId , required String subscriptionId , required FutureOr<void> Function(bool) onSuccess , required FutureOr<void> Function(String) onFailure })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(subscriptionId, serializer);
+sse_encode_DartFn_Inputs_bool_Output_unit_AnyhowException(onSuccess, serializer);
+sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(onFailure, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 112, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiNatsUnsubscribeConstMeta,
+            argValues: [clientId, subscriptionId, onSuccess, onFailure],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNatsUnsubscribeConstMeta => const TaskConstMeta(
+            debugName: "unsubscribe",
+            argNames: ["clientId", "subscriptionId", "onSuccess", "onFailure"],
+        );
+        
+
+@override Future<ConsumerInfo> crateApiConsumerUpdateConsumer({required String clientId , required String stream , required ConsumerConfig config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(stream, serializer);
+sse_encode_box_autoadd_consumer_config(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 113, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_consumer_info,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiConsumerUpdateConsumerConstMeta,
+            argValues: [clientId, stream, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConsumerUpdateConsumerConstMeta => const TaskConstMeta(
+            debugName: "update_consumer",
+            argNames: ["clientId", "stream", "config"],
+        );
+        
+
+@override Future<StreamInfo> crateApiJetstreamUpdateStream({required String clientId , required StreamConfig config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_box_autoadd_stream_config(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 114, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_stream_info,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiJetstreamUpdateStreamConstMeta,
+            argValues: [clientId, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJetstreamUpdateStreamConstMeta => const TaskConstMeta(
+            debugName: "update_stream",
+            argNames: ["clientId", "config"],
+        );
+        
+
+@override Future<void> crateApiSubjectValidateSubject({required String subject , required bool allowWildcards })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(subject, serializer);
+sse_encode_bool(allowWildcards, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 115, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_nats_error,
+        )
+        ,
+            constMeta: kCrateApiSubjectValidateSubjectConstMeta,
+            argValues: [subject, allowWildcards],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSubjectValidateSubjectConstMeta => const TaskConstMeta(
+            debugName: "validate_subject",
+            argNames: ["subject", "allowWildcards"],
+        );
+        
+
+@override Future<XKeyPair?> crateApiEncryptionXkeyExport({required String clientId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 116, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_x_key_pair,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiEncryptionXkeyExportConstMeta,
+            argValues: [clientId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEncryptionXkeyExportConstMeta => const TaskConstMeta(
+            debugName: "xkey_export",
+            argNames: ["clientId"],
+        );
+        
+
+@override Future<XKeyPair> crateApiEncryptionXkeyGenerate()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 117, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_x_key_pair,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiEncryptionXkeyGenerateConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEncryptionXkeyGenerateConstMeta => const TaskConstMeta(
+            debugName: "xkey_generate",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiEncryptionXkeyImport({required String clientId , required String seed })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(clientId, serializer);
+sse_encode_String(seed, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 118, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiEncryptionXkeyImportConstMeta,
+            argValues: [clientId, seed],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEncryptionXkeyImportConstMeta => const TaskConstMeta(
+            debugName: "xkey_import",
+            argNames: ["clientId", "seed"],
+        );
+        
+
+
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_String_Output_String_AnyhowException(FutureOr<String> Function(String) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_String(rawArg0);
+
+
+                Box<String>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_String(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_String_Output_unit_AnyhowException(FutureOr<void> Function(String) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_String(rawArg0);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic, dynamic)
+                encode_DartFn_Inputs_String_String_Output_unit_AnyhowException(FutureOr<void> Function(String, String) raw) {
+              return (callId, rawArg0, rawArg1) async {
+                final arg0 = dco_decode_String(rawArg0);
+final arg1 = dco_decode_String(rawArg1);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0, arg1));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic, dynamic)
+                encode_DartFn_Inputs_String_bool_Output_unit_AnyhowException(FutureOr<void> Function(String, bool) raw) {
+              return (callId, rawArg0, rawArg1) async {
+                final arg0 = dco_decode_String(rawArg0);
+final arg1 = dco_decode_bool(rawArg1);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0, arg1));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, )
+                encode_DartFn_Inputs__Output_unit_AnyhowException(FutureOr<void> Function() raw) {
+              return (callId, ) async {
+                
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw());
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_bool_Output_unit_AnyhowException(FutureOr<void> Function(bool) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_bool(rawArg0);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_js_message_Output_bool_AnyhowException(FutureOr<bool> Function(JsMessage) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_js_message(rawArg0);
+
+
+                Box<bool>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_bool(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_list_prim_u_8_strict_Output_auth_credentials_AnyhowException(FutureOr<AuthCredentials> Function(Uint8List) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_list_prim_u_8_strict(rawArg0);
+
+
+                Box<AuthCredentials>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_auth_credentials(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_nats_error_Output_unit_AnyhowException(FutureOr<void> Function(NatsError) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_nats_error(rawArg0);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_service_request_Output_service_response_AnyhowException(FutureOr<ServiceResponse> Function(ServiceRequest) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_service_request(rawArg0);
+
+
+                Box<ServiceResponse>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_service_response(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic, dynamic)
+                encode_DartFn_Inputs_u_64_u_64_Output_unit_AnyhowException(FutureOr<void> Function(BigInt, BigInt) raw) {
+              return (callId, rawArg0, rawArg1) async {
+                final arg0 = dco_decode_u_64(rawArg0);
+final arg1 = dco_decode_u_64(rawArg1);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0, arg1));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnyhowException(raw as String); }
+
+@protected FutureOr<String> Function(String) dco_decode_DartFn_Inputs_String_Output_String_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(String) dco_decode_DartFn_Inputs_String_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(String, String) dco_decode_DartFn_Inputs_String_String_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(String, bool) dco_decode_DartFn_Inputs_String_bool_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function() dco_decode_DartFn_Inputs__Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(bool) dco_decode_DartFn_Inputs_bool_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<bool> Function(JsMessage) dco_decode_DartFn_Inputs_js_message_Output_bool_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<AuthCredentials> Function(Uint8List) dco_decode_DartFn_Inputs_list_prim_u_8_strict_Output_auth_credentials_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(NatsError) dco_decode_DartFn_Inputs_nats_error_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<ServiceResponse> Function(ServiceRequest) dco_decode_DartFn_Inputs_service_request_Output_service_response_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(BigInt, BigInt) dco_decode_DartFn_Inputs_u_64_u_64_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected Object dco_decode_DartOpaque(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return decodeDartOpaque(raw, generalizedFrbRustBinding); }
+
+@protected Map<String, String> dco_decode_Map_String_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Map.fromEntries(dco_decode_list_record_string_string(raw).map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected Map<String, List<String>> dco_decode_Map_String_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Map.fromEntries(dco_decode_list_record_string_list_string(raw).map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected RustStreamSink<DecodedMessage> dco_decode_StreamSink_decoded_message_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<JsMessage> dco_decode_StreamSink_js_message_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<KvCacheEvent> dco_decode_StreamSink_kv_cache_event_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<KvEntry> dco_decode_StreamSink_kv_entry_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<ObjectInfo> dco_decode_StreamSink_object_info_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<OutboxEvent> dco_decode_StreamSink_outbox_event_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected AccountInfo dco_decode_account_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return AccountInfo(memory: dco_decode_u_64(arr[0]),
+storage: dco_decode_u_64(arr[1]),
+streams: dco_decode_u_64(arr[2]),
+consumers: dco_decode_u_64(arr[3]),
+domain: dco_decode_opt_String(arr[4]),
+limits: dco_decode_account_limits(arr[5]),
+apiRequests: dco_decode_u_64(arr[6]),
+apiErrors: dco_decode_u_64(arr[7]),); }
+
+@protected AccountLimits dco_decode_account_limits(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return AccountLimits(maxMemory: dco_decode_opt_box_autoadd_i_64(arr[0]),
+maxStorage: dco_decode_opt_box_autoadd_i_64(arr[1]),
+maxStreams: dco_decode_opt_box_autoadd_i_64(arr[2]),
+maxConsumers: dco_decode_opt_box_autoadd_i_64(arr[3]),
+maxAckPending: dco_decode_i_64(arr[4]),
+memoryMaxStreamBytes: dco_decode_opt_box_autoadd_i_64(arr[5]),
+storageMaxStreamBytes: dco_decode_opt_box_autoadd_i_64(arr[6]),
+maxBytesRequired: dco_decode_bool(arr[7]),); }
+
+@protected AckPolicy dco_decode_ack_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AckPolicy.values[raw as int]; }
+
+@protected AuthCredentials dco_decode_auth_credentials(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return AuthCredentials(token: dco_decode_opt_String(arr[0]),
+user: dco_decode_opt_String(arr[1]),
+pass: dco_decode_opt_String(arr[2]),
+jwt: dco_decode_opt_String(arr[3]),
+nkey: dco_decode_opt_String(arr[4]),
+signature: dco_decode_opt_String(arr[5]),); }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected bool dco_decode_box_autoadd_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected CachedKvValue dco_decode_box_autoadd_cached_kv_value(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_cached_kv_value(raw); }
+
+@protected CompressionConfig dco_decode_box_autoadd_compression_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_compression_config(raw); }
+
+@protected ConsumerConfig dco_decode_box_autoadd_consumer_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_consumer_config(raw); }
+
+@protected DecodedPayload dco_decode_box_autoadd_decoded_payload(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_decoded_payload(raw); }
+
+@protected int dco_decode_box_autoadd_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_i_64(raw); }
+
+@protected JetStreamOptions dco_decode_box_autoadd_jet_stream_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_jet_stream_options(raw); }
+
+@protected JsMessage dco_decode_box_autoadd_js_message(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_js_message(raw); }
+
+@protected JsPublishOptions dco_decode_box_autoadd_js_publish_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_js_publish_options(raw); }
+
+@protected KvConfig dco_decode_box_autoadd_kv_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_kv_config(raw); }
+
+@protected KvEntry dco_decode_box_autoadd_kv_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_kv_entry(raw); }
+
+@protected KvRepublish dco_decode_box_autoadd_kv_republish(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_kv_republish(raw); }
+
+@protected KvSource dco_decode_box_autoadd_kv_source(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_kv_source(raw); }
+
+@protected KvWatchOptions dco_decode_box_autoadd_kv_watch_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_kv_watch_options(raw); }
+
+@protected NatsConfig dco_decode_box_autoadd_nats_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_nats_config(raw); }
+
+@protected ObjectLink dco_decode_box_autoadd_object_link(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_object_link(raw); }
+
+@protected ObjectMeta dco_decode_box_autoadd_object_meta(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_object_meta(raw); }
+
+@protected ObjectStoreConfig dco_decode_box_autoadd_object_store_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_object_store_config(raw); }
+
+@protected OutboxConfig dco_decode_box_autoadd_outbox_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_outbox_config(raw); }
+
+@protected ReconnectionConfig dco_decode_box_autoadd_reconnection_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_reconnection_config(raw); }
+
+@protected ServiceError dco_decode_box_autoadd_service_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_service_error(raw); }
+
+@protected StreamConfig dco_decode_box_autoadd_stream_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_stream_config(raw); }
+
+@protected int dco_decode_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_u_64(raw); }
+
+@protected XKeyPair dco_decode_box_autoadd_x_key_pair(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_x_key_pair(raw); }
+
+@protected CachedKvValue dco_decode_cached_kv_value(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return CachedKvValue(value: dco_decode_String(arr[0]),
+revision: dco_decode_u_64(arr[1]),
+stale: dco_decode_bool(arr[2]),
+pending: dco_decode_bool(arr[3]),
+syncedMs: dco_decode_opt_box_autoadd_i_64(arr[4]),); }
+
+@protected CompressionAlgorithm dco_decode_compression_algorithm(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CompressionAlgorithm.values[raw as int]; }
+
+@protected CompressionConfig dco_decode_compression_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return CompressionConfig(algorithm: dco_decode_compression_algorithm(arr[0]),
+minSize: dco_decode_u_64(arr[1]),); }
+
+@protected ConnectionState dco_decode_connection_state(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ConnectionState.values[raw as int]; }
+
+@protected ConnectionStats dco_decode_connection_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return ConnectionStats(inMessages: dco_decode_u_64(arr[0]),
+outMessages: dco_decode_u_64(arr[1]),
+inBytes: dco_decode_u_64(arr[2]),
+outBytes: dco_decode_u_64(arr[3]),
+reconnects: dco_decode_u_64(arr[4]),
+state: dco_decode_connection_state(arr[5]),); }
+
+@protected ConsumerConfig dco_decode_consumer_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return ConsumerConfig(durableName: dco_decode_opt_String(arr[0]),
+description: dco_decode_opt_String(arr[1]),
+filterSubjects: dco_decode_list_String(arr[2]),
+ackPolicy: dco_decode_ack_policy(arr[3]),
+ackWaitMs: dco_decode_opt_box_autoadd_u_64(arr[4]),
+maxDeliver: dco_decode_opt_box_autoadd_i_64(arr[5]),
+backoffMs: dco_decode_list_prim_u_64_strict(arr[6]),
+maxAckPending: dco_decode_opt_box_autoadd_i_64(arr[7]),
+inactiveThresholdMs: dco_decode_opt_box_autoadd_u_64(arr[8]),); }
+
+@protected ConsumerInfo dco_decode_consumer_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 12) throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+                return ConsumerInfo(stream: dco_decode_String(arr[0]),
+name: dco_decode_String(arr[1]),
+createdMs: dco_decode_i_64(arr[2]),
+config: dco_decode_consumer_config(arr[3]),
+deliveredStreamSequence: dco_decode_u_64(arr[4]),
+deliveredConsumerSequence: dco_decode_u_64(arr[5]),
+ackFloorStreamSequence: dco_decode_u_64(arr[6]),
+ackFloorConsumerSequence: dco_decode_u_64(arr[7]),
+numAckPending: dco_decode_u_64(arr[8]),
+numRedelivered: dco_decode_u_64(arr[9]),
+numWaiting: dco_decode_u_64(arr[10]),
+numPending: dco_decode_u_64(arr[11]),); }
+
+@protected ConsumerPauseState dco_decode_consumer_pause_state(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return ConsumerPauseState(paused: dco_decode_bool(arr[0]),
+pauseUntilMs: dco_decode_opt_box_autoadd_i_64(arr[1]),
+pauseRemainingMs: dco_decode_opt_box_autoadd_u_64(arr[2]),); }
+
+@protected Credentials dco_decode_credentials(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return Credentials(jwt: dco_decode_String(arr[0]),
+publicKey: dco_decode_String(arr[1]),
+claims: dco_decode_jwt_claims(arr[2]),); }
+
+@protected DecodedMessage dco_decode_decoded_message(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return DecodedMessage(subject: dco_decode_String(arr[0]),
+reply: dco_decode_opt_String(arr[1]),
+headers: dco_decode_Map_String_list_String(arr[2]),
+payload: dco_decode_decoded_payload(arr[3]),); }
+
+@protected DecodedPayload dco_decode_decoded_payload(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return DecodedPayload(codec: dco_decode_payload_codec(arr[0]),
+json: dco_decode_opt_String(arr[1]),
+bytes: dco_decode_list_prim_u_8_strict(arr[2]),); }
+
+@protected DeliverPolicy dco_decode_deliver_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return DeliverPolicy.values[raw as int]; }
+
+@protected DiscardPolicy dco_decode_discard_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return DiscardPolicy.values[raw as int]; }
+
+@protected EndpointInfo dco_decode_endpoint_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return EndpointInfo(name: dco_decode_String(arr[0]),
+subject: dco_decode_String(arr[1]),
+queueGroup: dco_decode_String(arr[2]),
+metadata: dco_decode_Map_String_String(arr[3]),); }
+
+@protected EndpointStats dco_decode_endpoint_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return EndpointStats(name: dco_decode_String(arr[0]),
+subject: dco_decode_String(arr[1]),
+queueGroup: dco_decode_String(arr[2]),
+numRequests: dco_decode_u_64(arr[3]),
+numErrors: dco_decode_u_64(arr[4]),
+processingTimeNs: dco_decode_u_64(arr[5]),
+averageProcessingTimeNs: dco_decode_u_64(arr[6]),
+lastError: dco_decode_opt_String(arr[7]),); }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeI64(raw); }
+
+@protected PlatformInt64 dco_decode_isize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeI64(raw); }
+
+@protected JetStreamOptions dco_decode_jet_stream_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return JetStreamOptions(domain: dco_decode_opt_String(arr[0]),
+apiPrefix: dco_decode_opt_String(arr[1]),
+requestTimeoutMs: dco_decode_opt_box_autoadd_u_64(arr[2]),); }
+
+@protected JsMessage dco_decode_js_message(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 12) throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+                return JsMessage(handle: dco_decode_String(arr[0]),
+subject: dco_decode_String(arr[1]),
+payload: dco_decode_String(arr[2]),
+bytes: dco_decode_list_prim_u_8_strict(arr[3]),
+decodeError: dco_decode_opt_String(arr[4]),
+stream: dco_decode_String(arr[5]),
+consumer: dco_decode_String(arr[6]),
+streamSequence: dco_decode_u_64(arr[7]),
+consumerSequence: dco_decode_u_64(arr[8]),
+delivered: dco_decode_u_64(arr[9]),
+pending: dco_decode_u_64(arr[10]),
+timestampMs: dco_decode_i_64(arr[11]),); }
+
+@protected JsPublishOptions dco_decode_js_publish_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return JsPublishOptions(messageId: dco_decode_opt_String(arr[0]),
+expectedStream: dco_decode_opt_String(arr[1]),
+expectedLastMessageId: dco_decode_opt_String(arr[2]),
+expectedLastSequence: dco_decode_opt_box_autoadd_u_64(arr[3]),
+expectedLastSubjectSequence: dco_decode_opt_box_autoadd_u_64(arr[4]),); }
+
+@protected JwtClaims dco_decode_jwt_claims(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return JwtClaims(subject: dco_decode_String(arr[0]),
+name: dco_decode_opt_String(arr[1]),
+issuer: dco_decode_String(arr[2]),
+issuerAccount: dco_decode_opt_String(arr[3]),
+issuedAtMs: dco_decode_i_64(arr[4]),
+expiresMs: dco_decode_opt_box_autoadd_i_64(arr[5]),
+publish: dco_decode_subject_permissions(arr[6]),
+subscribe: dco_decode_subject_permissions(arr[7]),
+bearerToken: dco_decode_bool(arr[8]),); }
+
+@protected KvCacheEvent dco_decode_kv_cache_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return KvCacheEvent(kind: dco_decode_kv_cache_event_kind(arr[0]),
+key: dco_decode_opt_String(arr[1]),
+revision: dco_decode_opt_box_autoadd_u_64(arr[2]),); }
+
+@protected KvCacheEventKind dco_decode_kv_cache_event_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return KvCacheEventKind.values[raw as int]; }
+
+@protected KvConfig dco_decode_kv_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 12) throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+                return KvConfig(bucket: dco_decode_String(arr[0]),
+description: dco_decode_opt_String(arr[1]),
+history: dco_decode_opt_box_autoadd_i_64(arr[2]),
+maxAgeMs: dco_decode_opt_box_autoadd_u_64(arr[3]),
+maxBytes: dco_decode_opt_box_autoadd_i_64(arr[4]),
+maxValueSize: dco_decode_opt_box_autoadd_i_32(arr[5]),
+storage: dco_decode_storage_type(arr[6]),
+numReplicas: dco_decode_opt_box_autoadd_u_32(arr[7]),
+compression: dco_decode_opt_box_autoadd_bool(arr[8]),
+republish: dco_decode_opt_box_autoadd_kv_republish(arr[9]),
+mirror: dco_decode_opt_box_autoadd_kv_source(arr[10]),
+sources: dco_decode_opt_list_kv_source(arr[11]),); }
+
+@protected KvEntry dco_decode_kv_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return KvEntry(bucket: dco_decode_String(arr[0]),
+key: dco_decode_String(arr[1]),
+value: dco_decode_String(arr[2]),
+revision: dco_decode_u_64(arr[3]),
+delta: dco_decode_u_64(arr[4]),
+createdMs: dco_decode_i_64(arr[5]),
+operation: dco_decode_kv_operation(arr[6]),); }
+
+@protected KvOperation dco_decode_kv_operation(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return KvOperation.values[raw as int]; }
+
+@protected KvRepublish dco_decode_kv_republish(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return KvRepublish(source: dco_decode_String(arr[0]),
+destination: dco_decode_String(arr[1]),
+headersOnly: dco_decode_bool(arr[2]),); }
+
+@protected KvSource dco_decode_kv_source(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return KvSource(bucket: dco_decode_String(arr[0]),
+filterSubject: dco_decode_opt_String(arr[1]),
+domain: dco_decode_opt_String(arr[2]),); }
+
+@protected KvStatus dco_decode_kv_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return KvStatus(config: dco_decode_kv_config(arr[0]),
+values: dco_decode_u_64(arr[1]),
+bytes: dco_decode_u_64(arr[2]),
+createdMs: dco_decode_i_64(arr[3]),); }
+
+@protected KvWatchOptions dco_decode_kv_watch_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return KvWatchOptions(includeHistory: dco_decode_bool(arr[0]),
+updatesOnly: dco_decode_bool(arr[1]),
+metadataOnly: dco_decode_bool(arr[2]),); }
+
+@protected List<String> dco_decode_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_String).toList(); }
+
+@protected List<ConsumerInfo> dco_decode_list_consumer_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_consumer_info).toList(); }
+
+@protected List<EndpointInfo> dco_decode_list_endpoint_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_endpoint_info).toList(); }
+
+@protected List<EndpointStats> dco_decode_list_endpoint_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_endpoint_stats).toList(); }
+
+@protected List<JsMessage> dco_decode_list_js_message(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_js_message).toList(); }
+
+@protected List<KvEntry> dco_decode_list_kv_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_kv_entry).toList(); }
+
+@protected List<KvSource> dco_decode_list_kv_source(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_kv_source).toList(); }
+
+@protected List<ObjectInfo> dco_decode_list_object_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_object_info).toList(); }
+
+@protected Uint64List dco_decode_list_prim_u_64_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeUint64List(raw); }
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<int>; }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected List<RecipientKey> dco_decode_list_recipient_key(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_recipient_key).toList(); }
+
+@protected List<(String,List<String>)> dco_decode_list_record_string_list_string(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_string_list_string).toList(); }
+
+@protected List<(String,String)> dco_decode_list_record_string_string(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_string_string).toList(); }
+
+@protected List<ServiceInfo> dco_decode_list_service_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_service_info).toList(); }
+
+@protected List<ServicePing> dco_decode_list_service_ping(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_service_ping).toList(); }
+
+@protected List<ServiceStats> dco_decode_list_service_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_service_stats).toList(); }
+
+@protected List<StreamInfo> dco_decode_list_stream_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_stream_info).toList(); }
+
+@protected NatsConfig dco_decode_nats_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+                return NatsConfig(host: dco_decode_String(arr[0]),
+port: dco_decode_u_16(arr[1]),
+token: dco_decode_opt_String(arr[2]),
+nkey: dco_decode_opt_String(arr[3]),
+creds: dco_decode_opt_String(arr[4]),
+user: dco_decode_opt_String(arr[5]),
+pass: dco_decode_opt_String(arr[6]),
+reconnection: dco_decode_opt_box_autoadd_reconnection_config(arr[7]),
+pingInterval: dco_decode_opt_box_autoadd_u_64(arr[8]),
+maxPingFails: dco_decode_opt_box_autoadd_u_32(arr[9]),); }
+
+@protected NatsError dco_decode_nats_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return NatsError(kind: dco_decode_nats_error_kind(arr[0]),
+message: dco_decode_String(arr[1]),
+payloadSize: dco_decode_opt_box_autoadd_u_64(arr[2]),
+maxPayload: dco_decode_opt_box_autoadd_u_64(arr[3]),); }
+
+@protected NatsErrorKind dco_decode_nats_error_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return NatsErrorKind.values[raw as int]; }
+
+@protected NkeyKind dco_decode_nkey_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return NkeyKind.values[raw as int]; }
+
+@protected NkeyPair dco_decode_nkey_pair(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return NkeyPair(publicKey: dco_decode_String(arr[0]),
+seed: dco_decode_String(arr[1]),); }
+
+@protected ObjectInfo dco_decode_object_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+                return ObjectInfo(bucket: dco_decode_String(arr[0]),
+name: dco_decode_String(arr[1]),
+description: dco_decode_opt_String(arr[2]),
+metadata: dco_decode_Map_String_String(arr[3]),
+size: dco_decode_u_64(arr[4]),
+chunks: dco_decode_u_64(arr[5]),
+modifiedMs: dco_decode_opt_box_autoadd_i_64(arr[6]),
+digest: dco_decode_opt_String(arr[7]),
+deleted: dco_decode_bool(arr[8]),
+link: dco_decode_opt_box_autoadd_object_link(arr[9]),); }
+
+@protected ObjectLink dco_decode_object_link(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ObjectLink(bucket: dco_decode_String(arr[0]),
+name: dco_decode_opt_String(arr[1]),); }
+
+@protected ObjectMeta dco_decode_object_meta(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return ObjectMeta(name: dco_decode_String(arr[0]),
+description: dco_decode_opt_String(arr[1]),
+metadata: dco_decode_Map_String_String(arr[2]),
+chunkSize: dco_decode_opt_box_autoadd_u_32(arr[3]),); }
+
+@protected ObjectStoreConfig dco_decode_object_store_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return ObjectStoreConfig(bucket: dco_decode_String(arr[0]),
+description: dco_decode_opt_String(arr[1]),
+maxAgeMs: dco_decode_opt_box_autoadd_u_64(arr[2]),
+maxBytes: dco_decode_opt_box_autoadd_i_64(arr[3]),
+storage: dco_decode_storage_type(arr[4]),
+numReplicas: dco_decode_opt_box_autoadd_u_32(arr[5]),
+compression: dco_decode_bool(arr[6]),); }
+
+@protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_String(raw); }
+
+@protected bool? dco_decode_opt_box_autoadd_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_bool(raw); }
+
+@protected CachedKvValue? dco_decode_opt_box_autoadd_cached_kv_value(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_cached_kv_value(raw); }
+
+@protected CompressionConfig? dco_decode_opt_box_autoadd_compression_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_compression_config(raw); }
+
+@protected DecodedPayload? dco_decode_opt_box_autoadd_decoded_payload(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_decoded_payload(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_i_32(raw); }
+
+@protected PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_i_64(raw); }
+
+@protected JsMessage? dco_decode_opt_box_autoadd_js_message(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_js_message(raw); }
+
+@protected KvEntry? dco_decode_opt_box_autoadd_kv_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_kv_entry(raw); }
+
+@protected KvRepublish? dco_decode_opt_box_autoadd_kv_republish(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_kv_republish(raw); }
+
+@protected KvSource? dco_decode_opt_box_autoadd_kv_source(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_kv_source(raw); }
+
+@protected ObjectLink? dco_decode_opt_box_autoadd_object_link(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_object_link(raw); }
+
+@protected ReconnectionConfig? dco_decode_opt_box_autoadd_reconnection_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_reconnection_config(raw); }
+
+@protected ServiceError? dco_decode_opt_box_autoadd_service_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_service_error(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_32(raw); }
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_64(raw); }
+
+@protected XKeyPair? dco_decode_opt_box_autoadd_x_key_pair(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_x_key_pair(raw); }
+
+@protected List<KvSource>? dco_decode_opt_list_kv_source(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_list_kv_source(raw); }
+
+@protected OutboxConfig dco_decode_outbox_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return OutboxConfig(path: dco_decode_String(arr[0]),
+maxMessages: dco_decode_opt_box_autoadd_u_64(arr[1]),
+maxBytes: dco_decode_opt_box_autoadd_u_64(arr[2]),
+ttlMs: dco_decode_opt_box_autoadd_u_64(arr[3]),); }
+
+@protected OutboxEvent dco_decode_outbox_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return OutboxEvent(kind: dco_decode_outbox_event_kind(arr[0]),
+id: dco_decode_u_64(arr[1]),
+subject: dco_decode_String(arr[2]),
+queuedMs: dco_decode_i_64(arr[3]),
+error: dco_decode_opt_String(arr[4]),); }
+
+@protected OutboxEventKind dco_decode_outbox_event_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return OutboxEventKind.values[raw as int]; }
+
+@protected OutboxStatus dco_decode_outbox_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return OutboxStatus(pendingMessages: dco_decode_u_64(arr[0]),
+pendingBytes: dco_decode_u_64(arr[1]),); }
+
+@protected PayloadCodec dco_decode_payload_codec(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PayloadCodec.values[raw as int]; }
+
+@protected PubAck dco_decode_pub_ack(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return PubAck(stream: dco_decode_String(arr[0]),
+sequence: dco_decode_u_64(arr[1]),
+duplicate: dco_decode_bool(arr[2]),
+domain: dco_decode_opt_String(arr[3]),); }
+
+@protected RecipientKey dco_decode_recipient_key(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return RecipientKey(subject: dco_decode_String(arr[0]),
+publicKey: dco_decode_String(arr[1]),); }
+
+@protected ReconnectionConfig dco_decode_reconnection_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ReconnectionConfig(maxAttempts: dco_decode_opt_box_autoadd_u_32(arr[0]),
+delay: dco_decode_opt_box_autoadd_u_64(arr[1]),); }
+
+@protected (String,List<String>) dco_decode_record_string_list_string(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_String(arr[0]),dco_decode_list_String(arr[1]),); }
+
+@protected (String,String) dco_decode_record_string_string(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_String(arr[0]),dco_decode_String(arr[1]),); }
+
+@protected RetentionPolicy dco_decode_retention_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RetentionPolicy.values[raw as int]; }
+
+@protected ServerInfo dco_decode_server_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 13) throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
+                return ServerInfo(serverId: dco_decode_String(arr[0]),
+serverName: dco_decode_String(arr[1]),
+version: dco_decode_String(arr[2]),
+host: dco_decode_String(arr[3]),
+port: dco_decode_u_16(arr[4]),
+maxPayload: dco_decode_u_64(arr[5]),
+clientId: dco_decode_u_64(arr[6]),
+clientIp: dco_decode_String(arr[7]),
+connectUrls: dco_decode_list_String(arr[8]),
+authRequired: dco_decode_bool(arr[9]),
+tlsRequired: dco_decode_bool(arr[10]),
+lameDuckMode: dco_decode_bool(arr[11]),
+jetstreamEnabled: dco_decode_opt_box_autoadd_bool(arr[12]),); }
+
+@protected ServiceError dco_decode_service_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ServiceError(code: dco_decode_u_32(arr[0]),
+description: dco_decode_String(arr[1]),); }
+
+@protected ServiceInfo dco_decode_service_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return ServiceInfo(name: dco_decode_String(arr[0]),
+id: dco_decode_String(arr[1]),
+description: dco_decode_String(arr[2]),
+version: dco_decode_String(arr[3]),
+metadata: dco_decode_Map_String_String(arr[4]),
+endpoints: dco_decode_list_endpoint_info(arr[5]),); }
+
+@protected ServicePing dco_decode_service_ping(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return ServicePing(name: dco_decode_String(arr[0]),
+id: dco_decode_String(arr[1]),
+version: dco_decode_String(arr[2]),
+metadata: dco_decode_Map_String_String(arr[3]),); }
+
+@protected ServiceRequest dco_decode_service_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ServiceRequest(subject: dco_decode_String(arr[0]),
+payload: dco_decode_String(arr[1]),); }
+
+@protected ServiceResponse dco_decode_service_response(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ServiceResponse(payload: dco_decode_String(arr[0]),
+error: dco_decode_opt_box_autoadd_service_error(arr[1]),); }
+
+@protected ServiceStats dco_decode_service_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return ServiceStats(name: dco_decode_String(arr[0]),
+id: dco_decode_String(arr[1]),
+version: dco_decode_String(arr[2]),
+startedMs: dco_decode_i_64(arr[3]),
+endpoints: dco_decode_list_endpoint_stats(arr[4]),); }
+
+@protected StorageType dco_decode_storage_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return StorageType.values[raw as int]; }
+
+@protected StoredMessage dco_decode_stored_message(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return StoredMessage(subject: dco_decode_String(arr[0]),
+sequence: dco_decode_u_64(arr[1]),
+headers: dco_decode_Map_String_list_String(arr[2]),
+payload: dco_decode_String(arr[3]),
+bytes: dco_decode_list_prim_u_8_strict(arr[4]),
+decodeError: dco_decode_opt_String(arr[5]),
+timestampMs: dco_decode_i_64(arr[6]),); }
+
+@protected StreamConfig dco_decode_stream_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 15) throw Exception('unexpected arr length: expect 15 but see ${arr.length}');
+                return StreamConfig(name: dco_decode_String(arr[0]),
+subjects: dco_decode_list_String(arr[1]),
+description: dco_decode_opt_String(arr[2]),
+retention: dco_decode_retention_policy(arr[3]),
+storage: dco_decode_storage_type(arr[4]),
+discard: dco_decode_discard_policy(arr[5]),
+maxMessages: dco_decode_opt_box_autoadd_i_64(arr[6]),
+maxMessagesPerSubject: dco_decode_opt_box_autoadd_i_64(arr[7]),
+maxBytes: dco_decode_opt_box_autoadd_i_64(arr[8]),
+maxAgeMs: dco_decode_opt_box_autoadd_u_64(arr[9]),
+maxMessageSize: dco_decode_opt_box_autoadd_i_32(arr[10]),
+maxConsumers: dco_decode_opt_box_autoadd_i_32(arr[11]),
+numReplicas: dco_decode_opt_box_autoadd_u_32(arr[12]),
+duplicateWindowMs: dco_decode_opt_box_autoadd_u_64(arr[13]),
+allowDirect: dco_decode_bool(arr[14]),); }
+
+@protected StreamInfo dco_decode_stream_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return StreamInfo(config: dco_decode_stream_config(arr[0]),
+createdMs: dco_decode_i_64(arr[1]),
+state: dco_decode_stream_state(arr[2]),); }
+
+@protected StreamState dco_decode_stream_state(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return StreamState(messages: dco_decode_u_64(arr[0]),
+bytes: dco_decode_u_64(arr[1]),
+firstSequence: dco_decode_u_64(arr[2]),
+firstTimestampMs: dco_decode_i_64(arr[3]),
+lastSequence: dco_decode_u_64(arr[4]),
+lastTimestampMs: dco_decode_i_64(arr[5]),
+consumerCount: dco_decode_u_64(arr[6]),
+subjectsCount: dco_decode_u_64(arr[7]),
+deletedCount: dco_decode_opt_box_autoadd_u_64(arr[8]),); }
+
+@protected SubjectPermissions dco_decode_subject_permissions(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return SubjectPermissions(allow: dco_decode_list_String(arr[0]),
+deny: dco_decode_list_String(arr[1]),); }
+
+@protected int dco_decode_u_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected int dco_decode_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected BigInt dco_decode_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected XKeyPair dco_decode_x_key_pair(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return XKeyPair(publicKey: dco_decode_String(arr[0]),
+seed: dco_decode_String(arr[1]),); }
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_String(deserializer);
+        return AnyhowException(inner); }
+
+@protected Object sse_decode_DartOpaque(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_isize(deserializer);
+        return decodeDartOpaque(inner, generalizedFrbRustBinding); }
+
+@protected Map<String, String> sse_decode_Map_String_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_record_string_string(deserializer);
+        return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected Map<String, List<String>> sse_decode_Map_String_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_record_string_list_string(deserializer);
+        return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected RustStreamSink<DecodedMessage> sse_decode_StreamSink_decoded_message_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<JsMessage> sse_decode_StreamSink_js_message_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<KvCacheEvent> sse_decode_StreamSink_kv_cache_event_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<KvEntry> sse_decode_StreamSink_kv_entry_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<ObjectInfo> sse_decode_StreamSink_object_info_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<OutboxEvent> sse_decode_StreamSink_outbox_event_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected AccountInfo sse_decode_account_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_memory = sse_decode_u_64(deserializer);
+var var_storage = sse_decode_u_64(deserializer);
+var var_streams = sse_decode_u_64(deserializer);
+var var_consumers = sse_decode_u_64(deserializer);
+var var_domain = sse_decode_opt_String(deserializer);
+var var_limits = sse_decode_account_limits(deserializer);
+var var_apiRequests = sse_decode_u_64(deserializer);
+var var_apiErrors = sse_decode_u_64(deserializer);
+return AccountInfo(memory: var_memory, storage: var_storage, streams: var_streams, consumers: var_consumers, domain: var_domain, limits: var_limits, apiRequests: var_apiRequests, apiErrors: var_apiErrors); }
+
+@protected AccountLimits sse_decode_account_limits(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_maxMemory = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_maxStorage = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_maxStreams = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_maxConsumers = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_maxAckPending = sse_decode_i_64(deserializer);
+var var_memoryMaxStreamBytes = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_storageMaxStreamBytes = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_maxBytesRequired = sse_decode_bool(deserializer);
+return AccountLimits(maxMemory: var_maxMemory, maxStorage: var_maxStorage, maxStreams: var_maxStreams, maxConsumers: var_maxConsumers, maxAckPending: var_maxAckPending, memoryMaxStreamBytes: var_memoryMaxStreamBytes, storageMaxStreamBytes: var_storageMaxStreamBytes, maxBytesRequired: var_maxBytesRequired); }
+
+@protected AckPolicy sse_decode_ack_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return AckPolicy.values[inner]; }
+
+@protected AuthCredentials sse_decode_auth_credentials(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_token = sse_decode_opt_String(deserializer);
+var var_user = sse_decode_opt_String(deserializer);
+var var_pass = sse_decode_opt_String(deserializer);
+var var_jwt = sse_decode_opt_String(deserializer);
+var var_nkey = sse_decode_opt_String(deserializer);
+var var_signature = sse_decode_opt_String(deserializer);
+return AuthCredentials(token: var_token, user: var_user, pass: var_pass, jwt: var_jwt, nkey: var_nkey, signature: var_signature); }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected bool sse_decode_box_autoadd_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_bool(deserializer)); }
+
+@protected CachedKvValue sse_decode_box_autoadd_cached_kv_value(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_cached_kv_value(deserializer)); }
+
+@protected CompressionConfig sse_decode_box_autoadd_compression_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_compression_config(deserializer)); }
+
+@protected ConsumerConfig sse_decode_box_autoadd_consumer_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_consumer_config(deserializer)); }
+
+@protected DecodedPayload sse_decode_box_autoadd_decoded_payload(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_decoded_payload(deserializer)); }
+
+@protected int sse_decode_box_autoadd_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_i_32(deserializer)); }
+
+@protected PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_i_64(deserializer)); }
+
+@protected JetStreamOptions sse_decode_box_autoadd_jet_stream_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_jet_stream_options(deserializer)); }
+
+@protected JsMessage sse_decode_box_autoadd_js_message(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_js_message(deserializer)); }
+
+@protected JsPublishOptions sse_decode_box_autoadd_js_publish_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_js_publish_options(deserializer)); }
+
+@protected KvConfig sse_decode_box_autoadd_kv_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_kv_config(deserializer)); }
+
+@protected KvEntry sse_decode_box_autoadd_kv_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_kv_entry(deserializer)); }
+
+@protected KvRepublish sse_decode_box_autoadd_kv_republish(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_kv_republish(deserializer)); }
+
+@protected KvSource sse_decode_box_autoadd_kv_source(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_kv_source(deserializer)); }
+
+@protected KvWatchOptions sse_decode_box_autoadd_kv_watch_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_kv_watch_options(deserializer)); }
+
+@protected NatsConfig sse_decode_box_autoadd_nats_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_nats_config(deserializer)); }
+
+@protected ObjectLink sse_decode_box_autoadd_object_link(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_object_link(deserializer)); }
+
+@protected ObjectMeta sse_decode_box_autoadd_object_meta(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_object_meta(deserializer)); }
+
+@protected ObjectStoreConfig sse_decode_box_autoadd_object_store_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_object_store_config(deserializer)); }
+
+@protected OutboxConfig sse_decode_box_autoadd_outbox_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_outbox_config(deserializer)); }
+
+@protected ReconnectionConfig sse_decode_box_autoadd_reconnection_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_reconnection_config(deserializer)); }
+
+@protected ServiceError sse_decode_box_autoadd_service_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_service_error(deserializer)); }
+
+@protected StreamConfig sse_decode_box_autoadd_stream_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_stream_config(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_32(deserializer)); }
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_64(deserializer)); }
+
+@protected XKeyPair sse_decode_box_autoadd_x_key_pair(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_x_key_pair(deserializer)); }
+
+@protected CachedKvValue sse_decode_cached_kv_value(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_value = sse_decode_String(deserializer);
+var var_revision = sse_decode_u_64(deserializer);
+var var_stale = sse_decode_bool(deserializer);
+var var_pending = sse_decode_bool(deserializer);
+var var_syncedMs = sse_decode_opt_box_autoadd_i_64(deserializer);
+return CachedKvValue(value: var_value, revision: var_revision, stale: var_stale, pending: var_pending, syncedMs: var_syncedMs); }
+
+@protected CompressionAlgorithm sse_decode_compression_algorithm(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return CompressionAlgorithm.values[inner]; }
+
+@protected CompressionConfig sse_decode_compression_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_algorithm = sse_decode_compression_algorithm(deserializer);
+var var_minSize = sse_decode_u_64(deserializer);
+return CompressionConfig(algorithm: var_algorithm, minSize: var_minSize); }
+
+@protected ConnectionState sse_decode_connection_state(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ConnectionState.values[inner]; }
+
+@protected ConnectionStats sse_decode_connection_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_inMessages = sse_decode_u_64(deserializer);
+var var_outMessages = sse_decode_u_64(deserializer);
+var var_inBytes = sse_decode_u_64(deserializer);
+var var_outBytes = sse_decode_u_64(deserializer);
+var var_reconnects = sse_decode_u_64(deserializer);
+var var_state = sse_decode_connection_state(deserializer);
+return ConnectionStats(inMessages: var_inMessages, outMessages: var_outMessages, inBytes: var_inBytes, outBytes: var_outBytes, reconnects: var_reconnects, state: var_state); }
+
+@protected ConsumerConfig sse_decode_consumer_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_durableName = sse_decode_opt_String(deserializer);
+var var_description = sse_decode_opt_String(deserializer);
+var var_filterSubjects = sse_decode_list_String(deserializer);
+var var_ackPolicy = sse_decode_ack_policy(deserializer);
+var var_ackWaitMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_maxDeliver = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_backoffMs = sse_decode_list_prim_u_64_strict(deserializer);
+var var_maxAckPending = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_inactiveThresholdMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+return ConsumerConfig(durableName: var_durableName, description: var_description, filterSubjects: var_filterSubjects, ackPolicy: var_ackPolicy, ackWaitMs: var_ackWaitMs, maxDeliver: var_maxDeliver, backoffMs: var_backoffMs, maxAckPending: var_maxAckPending, inactiveThresholdMs: var_inactiveThresholdMs); }
+
+@protected ConsumerInfo sse_decode_consumer_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_stream = sse_decode_String(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_createdMs = sse_decode_i_64(deserializer);
+var var_config = sse_decode_consumer_config(deserializer);
+var var_deliveredStreamSequence = sse_decode_u_64(deserializer);
+var var_deliveredConsumerSequence = sse_decode_u_64(deserializer);
+var var_ackFloorStreamSequence = sse_decode_u_64(deserializer);
+var var_ackFloorConsumerSequence = sse_decode_u_64(deserializer);
+var var_numAckPending = sse_decode_u_64(deserializer);
+var var_numRedelivered = sse_decode_u_64(deserializer);
+var var_numWaiting = sse_decode_u_64(deserializer);
+var var_numPending = sse_decode_u_64(deserializer);
+return ConsumerInfo(stream: var_stream, name: var_name, createdMs: var_createdMs, config: var_config, deliveredStreamSequence: var_deliveredStreamSequence, deliveredConsumerSequence: var_deliveredConsumerSequence, ackFloorStreamSequence: var_ackFloorStreamSequence, ackFloorConsumerSequence: var_ackFloorConsumerSequence, numAckPending: var_numAckPending, numRedelivered: var_numRedelivered, numWaiting: var_numWaiting, numPending: var_numPending); }
+
+@protected ConsumerPauseState sse_decode_consumer_pause_state(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_paused = sse_decode_bool(deserializer);
+var var_pauseUntilMs = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_pauseRemainingMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+return ConsumerPauseState(paused: var_paused, pauseUntilMs: var_pauseUntilMs, pauseRemainingMs: var_pauseRemainingMs); }
+
+@protected Credentials sse_decode_credentials(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_jwt = sse_decode_String(deserializer);
+var var_publicKey = sse_decode_String(deserializer);
+var var_claims = sse_decode_jwt_claims(deserializer);
+return Credentials(jwt: var_jwt, publicKey: var_publicKey, claims: var_claims); }
+
+@protected DecodedMessage sse_decode_decoded_message(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_subject = sse_decode_String(deserializer);
+var var_reply = sse_decode_opt_String(deserializer);
+var var_headers = sse_decode_Map_String_list_String(deserializer);
+var var_payload = sse_decode_decoded_payload(deserializer);
+return DecodedMessage(subject: var_subject, reply: var_reply, headers: var_headers, payload: var_payload); }
+
+@protected DecodedPayload sse_decode_decoded_payload(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_codec = sse_decode_payload_codec(deserializer);
+var var_json = sse_decode_opt_String(deserializer);
+var var_bytes = sse_decode_list_prim_u_8_strict(deserializer);
+return DecodedPayload(codec: var_codec, json: var_json, bytes: var_bytes); }
+
+@protected DeliverPolicy sse_decode_deliver_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return DeliverPolicy.values[inner]; }
+
+@protected DiscardPolicy sse_decode_discard_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return DiscardPolicy.values[inner]; }
+
+@protected EndpointInfo sse_decode_endpoint_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_subject = sse_decode_String(deserializer);
+var var_queueGroup = sse_decode_String(deserializer);
+var var_metadata = sse_decode_Map_String_String(deserializer);
+return EndpointInfo(name: var_name, subject: var_subject, queueGroup: var_queueGroup, metadata: var_metadata); }
+
+@protected EndpointStats sse_decode_endpoint_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_subject = sse_decode_String(deserializer);
+var var_queueGroup = sse_decode_String(deserializer);
+var var_numRequests = sse_decode_u_64(deserializer);
+var var_numErrors = sse_decode_u_64(deserializer);
+var var_processingTimeNs = sse_decode_u_64(deserializer);
+var var_averageProcessingTimeNs = sse_decode_u_64(deserializer);
+var var_lastError = sse_decode_opt_String(deserializer);
+return EndpointStats(name: var_name, subject: var_subject, queueGroup: var_queueGroup, numRequests: var_numRequests, numErrors: var_numErrors, processingTimeNs: var_processingTimeNs, averageProcessingTimeNs: var_averageProcessingTimeNs, lastError: var_lastError); }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getPlatformInt64(); }
+
+@protected PlatformInt64 sse_decode_isize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getPlatformInt64(); }
+
+@protected JetStreamOptions sse_decode_jet_stream_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_domain = sse_decode_opt_String(deserializer);
+var var_apiPrefix = sse_decode_opt_String(deserializer);
+var var_requestTimeoutMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+return JetStreamOptions(domain: var_domain, apiPrefix: var_apiPrefix, requestTimeoutMs: var_requestTimeoutMs); }
+
+@protected JsMessage sse_decode_js_message(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_handle = sse_decode_String(deserializer);
+var var_subject = sse_decode_String(deserializer);
+var var_payload = sse_decode_String(deserializer);
+var var_bytes = sse_decode_list_prim_u_8_strict(deserializer);
+var var_decodeError = sse_decode_opt_String(deserializer);
+var var_stream = sse_decode_String(deserializer);
+var var_consumer = sse_decode_String(deserializer);
+var var_streamSequence = sse_decode_u_64(deserializer);
+var var_consumerSequence = sse_decode_u_64(deserializer);
+var var_delivered = sse_decode_u_64(deserializer);
+var var_pending = sse_decode_u_64(deserializer);
+var var_timestampMs = sse_decode_i_64(deserializer);
+return JsMessage(handle: var_handle, subject: var_subject, payload: var_payload, bytes: var_bytes, decodeError: var_decodeError, stream: var_stream, consumer: var_consumer, streamSequence: var_streamSequence, consumerSequence: var_consumerSequence, delivered: var_delivered, pending: var_pending, timestampMs: var_timestampMs); }
+
+@protected JsPublishOptions sse_decode_js_publish_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_messageId = sse_decode_opt_String(deserializer);
+var var_expectedStream = sse_decode_opt_String(deserializer);
+var var_expectedLastMessageId = sse_decode_opt_String(deserializer);
+var var_expectedLastSequence = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_expectedLastSubjectSequence = sse_decode_opt_box_autoadd_u_64(deserializer);
+return JsPublishOptions(messageId: var_messageId, expectedStream: var_expectedStream, expectedLastMessageId: var_expectedLastMessageId, expectedLastSequence: var_expectedLastSequence, expectedLastSubjectSequence: var_expectedLastSubjectSequence); }
+
+@protected JwtClaims sse_decode_jwt_claims(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_subject = sse_decode_String(deserializer);
+var var_name = sse_decode_opt_String(deserializer);
+var var_issuer = sse_decode_String(deserializer);
+var var_issuerAccount = sse_decode_opt_String(deserializer);
+var var_issuedAtMs = sse_decode_i_64(deserializer);
+var var_expiresMs = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_publish = sse_decode_subject_permissions(deserializer);
+var var_subscribe = sse_decode_subject_permissions(deserializer);
+var var_bearerToken = sse_decode_bool(deserializer);
+return JwtClaims(subject: var_subject, name: var_name, issuer: var_issuer, issuerAccount: var_issuerAccount, issuedAtMs: var_issuedAtMs, expiresMs: var_expiresMs, publish: var_publish, subscribe: var_subscribe, bearerToken: var_bearerToken); }
+
+@protected KvCacheEvent sse_decode_kv_cache_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_kind = sse_decode_kv_cache_event_kind(deserializer);
+var var_key = sse_decode_opt_String(deserializer);
+var var_revision = sse_decode_opt_box_autoadd_u_64(deserializer);
+return KvCacheEvent(kind: var_kind, key: var_key, revision: var_revision); }
+
+@protected KvCacheEventKind sse_decode_kv_cache_event_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return KvCacheEventKind.values[inner]; }
+
+@protected KvConfig sse_decode_kv_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_bucket = sse_decode_String(deserializer);
+var var_description = sse_decode_opt_String(deserializer);
+var var_history = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_maxAgeMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_maxBytes = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_maxValueSize = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_storage = sse_decode_storage_type(deserializer);
+var var_numReplicas = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_compression = sse_decode_opt_box_autoadd_bool(deserializer);
+var var_republish = sse_decode_opt_box_autoadd_kv_republish(deserializer);
+var var_mirror = sse_decode_opt_box_autoadd_kv_source(deserializer);
+var var_sources = sse_decode_opt_list_kv_source(deserializer);
+return KvConfig(bucket: var_bucket, description: var_description, history: var_history, maxAgeMs: var_maxAgeMs, maxBytes: var_maxBytes, maxValueSize: var_maxValueSize, storage: var_storage, numReplicas: var_numReplicas, compression: var_compression, republish: var_republish, mirror: var_mirror, sources: var_sources); }
+
+@protected KvEntry sse_decode_kv_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_bucket = sse_decode_String(deserializer);
+var var_key = sse_decode_String(deserializer);
+var var_value = sse_decode_String(deserializer);
+var var_revision = sse_decode_u_64(deserializer);
+var var_delta = sse_decode_u_64(deserializer);
+var var_createdMs = sse_decode_i_64(deserializer);
+var var_operation = sse_decode_kv_operation(deserializer);
+return KvEntry(bucket: var_bucket, key: var_key, value: var_value, revision: var_revision, delta: var_delta, createdMs: var_createdMs, operation: var_operation); }
+
+@protected KvOperation sse_decode_kv_operation(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return KvOperation.values[inner]; }
+
+@protected KvRepublish sse_decode_kv_republish(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_source = sse_decode_String(deserializer);
+var var_destination = sse_decode_String(deserializer);
+var var_headersOnly = sse_decode_bool(deserializer);
+return KvRepublish(source: var_source, destination: var_destination, headersOnly: var_headersOnly); }
+
+@protected KvSource sse_decode_kv_source(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_bucket = sse_decode_String(deserializer);
+var var_filterSubject = sse_decode_opt_String(deserializer);
+var var_domain = sse_decode_opt_String(deserializer);
+return KvSource(bucket: var_bucket, filterSubject: var_filterSubject, domain: var_domain); }
+
+@protected KvStatus sse_decode_kv_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_config = sse_decode_kv_config(deserializer);
+var var_values = sse_decode_u_64(deserializer);
+var var_bytes = sse_decode_u_64(deserializer);
+var var_createdMs = sse_decode_i_64(deserializer);
+return KvStatus(config: var_config, values: var_values, bytes: var_bytes, createdMs: var_createdMs); }
+
+@protected KvWatchOptions sse_decode_kv_watch_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_includeHistory = sse_decode_bool(deserializer);
+var var_updatesOnly = sse_decode_bool(deserializer);
+var var_metadataOnly = sse_decode_bool(deserializer);
+return KvWatchOptions(includeHistory: var_includeHistory, updatesOnly: var_updatesOnly, metadataOnly: var_metadataOnly); }
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ConsumerInfo> sse_decode_list_consumer_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ConsumerInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_consumer_info(deserializer)); }
+        return ans_;
+         }
+
+@protected List<EndpointInfo> sse_decode_list_endpoint_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <EndpointInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_endpoint_info(deserializer)); }
+        return ans_;
+         }
+
+@protected List<EndpointStats> sse_decode_list_endpoint_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <EndpointStats>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_endpoint_stats(deserializer)); }
+        return ans_;
+         }
+
+@protected List<JsMessage> sse_decode_list_js_message(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <JsMessage>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_js_message(deserializer)); }
+        return ans_;
+         }
+
+@protected List<KvEntry> sse_decode_list_kv_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <KvEntry>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_kv_entry(deserializer)); }
+        return ans_;
+         }
+
+@protected List<KvSource> sse_decode_list_kv_source(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <KvSource>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_kv_source(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ObjectInfo> sse_decode_list_object_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ObjectInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_object_info(deserializer)); }
+        return ans_;
+         }
+
+@protected Uint64List sse_decode_list_prim_u_64_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint64List(len_); }
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected List<RecipientKey> sse_decode_list_recipient_key(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <RecipientKey>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_recipient_key(deserializer)); }
+        return ans_;
+         }
+
+@protected List<(String,List<String>)> sse_decode_list_record_string_list_string(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(String,List<String>)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_string_list_string(deserializer)); }
+        return ans_;
+         }
+
+@protected List<(String,String)> sse_decode_list_record_string_string(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(String,String)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_string_string(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ServiceInfo> sse_decode_list_service_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ServiceInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_service_info(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ServicePing> sse_decode_list_service_ping(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ServicePing>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_service_ping(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ServiceStats> sse_decode_list_service_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ServiceStats>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_service_stats(deserializer)); }
+        return ans_;
+         }
+
+@protected List<StreamInfo> sse_decode_list_stream_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <StreamInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_stream_info(deserializer)); }
+        return ans_;
+         }
+
+@protected NatsConfig sse_decode_nats_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_host = sse_decode_String(deserializer);
+var var_port = sse_decode_u_16(deserializer);
+var var_token = sse_decode_opt_String(deserializer);
+var var_nkey = sse_decode_opt_String(deserializer);
+var var_creds = sse_decode_opt_String(deserializer);
+var var_user = sse_decode_opt_String(deserializer);
+var var_pass = sse_decode_opt_String(deserializer);
+var var_reconnection = sse_decode_opt_box_autoadd_reconnection_config(deserializer);
+var var_pingInterval = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_maxPingFails = sse_decode_opt_box_autoadd_u_32(deserializer);
+return NatsConfig(host: var_host, port: var_port, token: var_token, nkey: var_nkey, creds: var_creds, user: var_user, pass: var_pass, reconnection: var_reconnection, pingInterval: var_pingInterval, maxPingFails: var_maxPingFails); }
+
+@protected NatsError sse_decode_nats_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_kind = sse_decode_nats_error_kind(deserializer);
+var var_message = sse_decode_String(deserializer);
+var var_payloadSize = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_maxPayload = sse_decode_opt_box_autoadd_u_64(deserializer);
+return NatsError(kind: var_kind, message: var_message, payloadSize: var_payloadSize, maxPayload: var_maxPayload); }
+
+@protected NatsErrorKind sse_decode_nats_error_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return NatsErrorKind.values[inner]; }
+
+@protected NkeyKind sse_decode_nkey_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return NkeyKind.values[inner]; }
+
+@protected NkeyPair sse_decode_nkey_pair(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_publicKey = sse_decode_String(deserializer);
+var var_seed = sse_decode_String(deserializer);
+return NkeyPair(publicKey: var_publicKey, seed: var_seed); }
+
+@protected ObjectInfo sse_decode_object_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_bucket = sse_decode_String(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_description = sse_decode_opt_String(deserializer);
+var var_metadata = sse_decode_Map_String_String(deserializer);
+var var_size = sse_decode_u_64(deserializer);
+var var_chunks = sse_decode_u_64(deserializer);
+var var_modifiedMs = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_digest = sse_decode_opt_String(deserializer);
+var var_deleted = sse_decode_bool(deserializer);
+var var_link = sse_decode_opt_box_autoadd_object_link(deserializer);
+return ObjectInfo(bucket: var_bucket, name: var_name, description: var_description, metadata: var_metadata, size: var_size, chunks: var_chunks, modifiedMs: var_modifiedMs, digest: var_digest, deleted: var_deleted, link: var_link); }
+
+@protected ObjectLink sse_decode_object_link(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_bucket = sse_decode_String(deserializer);
+var var_name = sse_decode_opt_String(deserializer);
+return ObjectLink(bucket: var_bucket, name: var_name); }
+
+@protected ObjectMeta sse_decode_object_meta(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_description = sse_decode_opt_String(deserializer);
+var var_metadata = sse_decode_Map_String_String(deserializer);
+var var_chunkSize = sse_decode_opt_box_autoadd_u_32(deserializer);
+return ObjectMeta(name: var_name, description: var_description, metadata: var_metadata, chunkSize: var_chunkSize); }
+
+@protected ObjectStoreConfig sse_decode_object_store_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_bucket = sse_decode_String(deserializer);
+var var_description = sse_decode_opt_String(deserializer);
+var var_maxAgeMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_maxBytes = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_storage = sse_decode_storage_type(deserializer);
+var var_numReplicas = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_compression = sse_decode_bool(deserializer);
+return ObjectStoreConfig(bucket: var_bucket, description: var_description, maxAgeMs: var_maxAgeMs, maxBytes: var_maxBytes, storage: var_storage, numReplicas: var_numReplicas, compression: var_compression); }
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_bool(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected CachedKvValue? sse_decode_opt_box_autoadd_cached_kv_value(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_cached_kv_value(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected CompressionConfig? sse_decode_opt_box_autoadd_compression_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_compression_config(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected DecodedPayload? sse_decode_opt_box_autoadd_decoded_payload(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_decoded_payload(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_i_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_i_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected JsMessage? sse_decode_opt_box_autoadd_js_message(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_js_message(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected KvEntry? sse_decode_opt_box_autoadd_kv_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_kv_entry(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected KvRepublish? sse_decode_opt_box_autoadd_kv_republish(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_kv_republish(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected KvSource? sse_decode_opt_box_autoadd_kv_source(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_kv_source(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ObjectLink? sse_decode_opt_box_autoadd_object_link(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_object_link(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ReconnectionConfig? sse_decode_opt_box_autoadd_reconnection_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_reconnection_config(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ServiceError? sse_decode_opt_box_autoadd_service_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_service_error(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected XKeyPair? sse_decode_opt_box_autoadd_x_key_pair(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_x_key_pair(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected List<KvSource>? sse_decode_opt_list_kv_source(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_list_kv_source(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected OutboxConfig sse_decode_outbox_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_path = sse_decode_String(deserializer);
+var var_maxMessages = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_maxBytes = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_ttlMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+return OutboxConfig(path: var_path, maxMessages: var_maxMessages, maxBytes: var_maxBytes, ttlMs: var_ttlMs); }
+
+@protected OutboxEvent sse_decode_outbox_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_kind = sse_decode_outbox_event_kind(deserializer);
+var var_id = sse_decode_u_64(deserializer);
+var var_subject = sse_decode_String(deserializer);
+var var_queuedMs = sse_decode_i_64(deserializer);
+var var_error = sse_decode_opt_String(deserializer);
+return OutboxEvent(kind: var_kind, id: var_id, subject: var_subject, queuedMs: var_queuedMs, error: var_error); }
+
+@protected OutboxEventKind sse_decode_outbox_event_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return OutboxEventKind.values[inner]; }
+
+@protected OutboxStatus sse_decode_outbox_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_pendingMessages = sse_decode_u_64(deserializer);
+var var_pendingBytes = sse_decode_u_64(deserializer);
+return OutboxStatus(pendingMessages: var_pendingMessages, pendingBytes: var_pendingBytes); }
+
+@protected PayloadCodec sse_decode_payload_codec(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return PayloadCodec.values[inner]; }
+
+@protected PubAck sse_decode_pub_ack(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_stream = sse_decode_String(deserializer);
+var var_sequence = sse_decode_u_64(deserializer);
+var var_duplicate = sse_decode_bool(deserializer);
+var var_domain = sse_decode_opt_String(deserializer);
+return PubAck(stream: var_stream, sequence: var_sequence, duplicate: var_duplicate, domain: var_domain); }
+
+@protected RecipientKey sse_decode_recipient_key(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_subject = sse_decode_String(deserializer);
+var var_publicKey = sse_decode_String(deserializer);
+return RecipientKey(subject: var_subject, publicKey: var_publicKey); }
+
+@protected ReconnectionConfig sse_decode_reconnection_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_maxAttempts = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_delay = sse_decode_opt_box_autoadd_u_64(deserializer);
+return ReconnectionConfig(maxAttempts: var_maxAttempts, delay: var_delay); }
+
+@protected (String,List<String>) sse_decode_record_string_list_string(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_String(deserializer);
+var var_field1 = sse_decode_list_String(deserializer);
+return (var_field0, var_field1); }
+
+@protected (String,String) sse_decode_record_string_string(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_String(deserializer);
+var var_field1 = sse_decode_String(deserializer);
+return (var_field0, var_field1); }
+
+@protected RetentionPolicy sse_decode_retention_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return RetentionPolicy.values[inner]; }
+
+@protected ServerInfo sse_decode_server_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_serverId = sse_decode_String(deserializer);
+var var_serverName = sse_decode_String(deserializer);
+var var_version = sse_decode_String(deserializer);
+var var_host = sse_decode_String(deserializer);
+var var_port = sse_decode_u_16(deserializer);
+var var_maxPayload = sse_decode_u_64(deserializer);
+var var_clientId = sse_decode_u_64(deserializer);
+var var_clientIp = sse_decode_String(deserializer);
+var var_connectUrls = sse_decode_list_String(deserializer);
+var var_authRequired = sse_decode_bool(deserializer);
+var var_tlsRequired = sse_decode_bool(deserializer);
+var var_lameDuckMode = sse_decode_bool(deserializer);
+var var_jetstreamEnabled = sse_decode_opt_box_autoadd_bool(deserializer);
+return ServerInfo(serverId: var_serverId, serverName: var_serverName, version: var_version, host: var_host, port: var_port, maxPayload: var_maxPayload, clientId: var_clientId, clientIp: var_clientIp, connectUrls: var_connectUrls, authRequired: var_authRequired, tlsRequired: var_tlsRequired, lameDuckMode: var_lameDuckMode, jetstreamEnabled: var_jetstreamEnabled); }
+
+@protected ServiceError sse_decode_service_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_code = sse_decode_u_32(deserializer);
+var var_description = sse_decode_String(deserializer);
+return ServiceError(code: var_code, description: var_description); }
+
+@protected ServiceInfo sse_decode_service_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_id = sse_decode_String(deserializer);
+var var_description = sse_decode_String(deserializer);
+var var_version = sse_decode_String(deserializer);
+var var_metadata = sse_decode_Map_String_String(deserializer);
+var var_endpoints = sse_decode_list_endpoint_info(deserializer);
+return ServiceInfo(name: var_name, id: var_id, description: var_description, version: var_version, metadata: var_metadata, endpoints: var_endpoints); }
+
+@protected ServicePing sse_decode_service_ping(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_id = sse_decode_String(deserializer);
+var var_version = sse_decode_String(deserializer);
+var var_metadata = sse_decode_Map_String_String(deserializer);
+return ServicePing(name: var_name, id: var_id, version: var_version, metadata: var_metadata); }
+
+@protected ServiceRequest sse_decode_service_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_subject = sse_decode_String(deserializer);
+var var_payload = sse_decode_String(deserializer);
+return ServiceRequest(subject: var_subject, payload: var_payload); }
+
+@protected ServiceResponse sse_decode_service_response(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_payload = sse_decode_String(deserializer);
+var var_error = sse_decode_opt_box_autoadd_service_error(deserializer);
+return ServiceResponse(payload: var_payload, error: var_error); }
+
+@protected ServiceStats sse_decode_service_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_id = sse_decode_String(deserializer);
+var var_version = sse_decode_String(deserializer);
+var var_startedMs = sse_decode_i_64(deserializer);
+var var_endpoints = sse_decode_list_endpoint_stats(deserializer);
+return ServiceStats(name: var_name, id: var_id, version: var_version, startedMs: var_startedMs, endpoints: var_endpoints); }
+
+@protected StorageType sse_decode_storage_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return StorageType.values[inner]; }
+
+@protected StoredMessage sse_decode_stored_message(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_subject = sse_decode_String(deserializer);
+var var_sequence = sse_decode_u_64(deserializer);
+var var_headers = sse_decode_Map_String_list_String(deserializer);
+var var_payload = sse_decode_String(deserializer);
+var var_bytes = sse_decode_list_prim_u_8_strict(deserializer);
+var var_decodeError = sse_decode_opt_String(deserializer);
+var var_timestampMs = sse_decode_i_64(deserializer);
+return StoredMessage(subject: var_subject, sequence: var_sequence, headers: var_headers, payload: var_payload, bytes: var_bytes, decodeError: var_decodeError, timestampMs: var_timestampMs); }
+
+@protected StreamConfig sse_decode_stream_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_subjects = sse_decode_list_String(deserializer);
+var var_description = sse_decode_opt_String(deserializer);
+var var_retention = sse_decode_retention_policy(deserializer);
+var var_storage = sse_decode_storage_type(deserializer);
+var var_discard = sse_decode_discard_policy(deserializer);
+var var_maxMessages = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_maxMessagesPerSubject = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_maxBytes = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_maxAgeMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_maxMessageSize = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_maxConsumers = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_numReplicas = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_duplicateWindowMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_allowDirect = sse_decode_bool(deserializer);
+return StreamConfig(name: var_name, subjects: var_subjects, description: var_description, retention: var_retention, storage: var_storage, discard: var_discard, maxMessages: var_maxMessages, maxMessagesPerSubject: var_maxMessagesPerSubject, maxBytes: var_maxBytes, maxAgeMs: var_maxAgeMs, maxMessageSize: var_maxMessageSize, maxConsumers: var_maxConsumers, numReplicas: var_numReplicas, duplicateWindowMs: var_duplicateWindowMs, allowDirect: var_allowDirect); }
+
+@protected StreamInfo sse_decode_stream_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_config = sse_decode_stream_config(deserializer);
+var var_createdMs = sse_decode_i_64(deserializer);
+var var_state = sse_decode_stream_state(deserializer);
+return StreamInfo(config: var_config, createdMs: var_createdMs, state: var_state); }
+
+@protected StreamState sse_decode_stream_state(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_messages = sse_decode_u_64(deserializer);
+var var_bytes = sse_decode_u_64(deserializer);
+var var_firstSequence = sse_decode_u_64(deserializer);
+var var_firstTimestampMs = sse_decode_i_64(deserializer);
+var var_lastSequence = sse_decode_u_64(deserializer);
+var var_lastTimestampMs = sse_decode_i_64(deserializer);
+var var_consumerCount = sse_decode_u_64(deserializer);
+var var_subjectsCount = sse_decode_u_64(deserializer);
+var var_deletedCount = sse_decode_opt_box_autoadd_u_64(deserializer);
+return StreamState(messages: var_messages, bytes: var_bytes, firstSequence: var_firstSequence, firstTimestampMs: var_firstTimestampMs, lastSequence: var_lastSequence, lastTimestampMs: var_lastTimestampMs, consumerCount: var_consumerCount, subjectsCount: var_subjectsCount, deletedCount: var_deletedCount); }
+
+@protected SubjectPermissions sse_decode_subject_permissions(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_allow = sse_decode_list_String(deserializer);
+var var_deny = sse_decode_list_String(deserializer);
+return SubjectPermissions(allow: var_allow, deny: var_deny); }
+
+@protected int sse_decode_u_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint16(); }
+
+@protected int sse_decode_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint32(); }
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected XKeyPair sse_decode_x_key_pair(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_publicKey = sse_decode_String(deserializer);
+var var_seed = sse_decode_String(deserializer);
+return XKeyPair(publicKey: var_publicKey, seed: var_seed); }
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.message, serializer); }
+
+@protected void sse_encode_DartFn_Inputs_String_Output_String_AnyhowException(FutureOr<String> Function(String) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_String_Output_String_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(FutureOr<void> Function(String) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_String_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_String_String_Output_unit_AnyhowException(FutureOr<void> Function(String, String) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_String_String_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_String_bool_Output_unit_AnyhowException(FutureOr<void> Function(String, bool) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_String_bool_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs__Output_unit_AnyhowException(FutureOr<void> Function() self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs__Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_bool_Output_unit_AnyhowException(FutureOr<void> Function(bool) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_bool_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_js_message_Output_bool_AnyhowException(FutureOr<bool> Function(JsMessage) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_js_message_Output_bool_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_list_prim_u_8_strict_Output_auth_credentials_AnyhowException(FutureOr<AuthCredentials> Function(Uint8List) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_list_prim_u_8_strict_Output_auth_credentials_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_nats_error_Output_unit_AnyhowException(FutureOr<void> Function(NatsError) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_nats_error_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_service_request_Output_service_response_AnyhowException(FutureOr<ServiceResponse> Function(ServiceRequest) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_service_request_Output_service_response_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_u_64_u_64_Output_unit_AnyhowException(FutureOr<void> Function(BigInt, BigInt) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_u_64_u_64_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartOpaque(Object self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_isize(PlatformPointerUtil.ptrToPlatformInt64(encodeDartOpaque(self, portManager.dartHandlerPort, generalizedFrbRustBinding)), serializer); }
+
+@protected void sse_encode_Map_String_String(Map<String, String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_record_string_string(self.entries.map((e) => (e.key, e.value)).toList(), serializer); }
+
+@protected void sse_encode_Map_String_list_String(Map<String, List<String>> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_record_string_list_string(self.entries.map((e) => (e.key, e.value)).toList(), serializer); }
+
+@protected void sse_encode_StreamSink_decoded_message_Sse(RustStreamSink<DecodedMessage> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_decoded_message,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_js_message_Sse(RustStreamSink<JsMessage> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_js_message,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_kv_cache_event_Sse(RustStreamSink<KvCacheEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_kv_cache_event,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_kv_entry_Sse(RustStreamSink<KvEntry> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_kv_entry,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_object_info_Sse(RustStreamSink<ObjectInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_object_info,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_outbox_event_Sse(RustStreamSink<OutboxEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_outbox_event,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_account_info(AccountInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.memory, serializer);
+sse_encode_u_64(self.storage, serializer);
+sse_encode_u_64(self.streams, serializer);
+sse_encode_u_64(self.consumers, serializer);
+sse_encode_opt_String(self.domain, serializer);
+sse_encode_account_limits(self.limits, serializer);
+sse_encode_u_64(self.apiRequests, serializer);
+sse_encode_u_64(self.apiErrors, serializer);
+ }
+
+@protected void sse_encode_account_limits(AccountLimits self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_i_64(self.maxMemory, serializer);
+sse_encode_opt_box_autoadd_i_64(self.maxStorage, serializer);
+sse_encode_opt_box_autoadd_i_64(self.maxStreams, serializer);
+sse_encode_opt_box_autoadd_i_64(self.maxConsumers, serializer);
+sse_encode_i_64(self.maxAckPending, serializer);
+sse_encode_opt_box_autoadd_i_64(self.memoryMaxStreamBytes, serializer);
+sse_encode_opt_box_autoadd_i_64(self.storageMaxStreamBytes, serializer);
+sse_encode_bool(self.maxBytesRequired, serializer);
+ }
+
+@protected void sse_encode_ack_policy(AckPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_auth_credentials(AuthCredentials self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.token, serializer);
+sse_encode_opt_String(self.user, serializer);
+sse_encode_opt_String(self.pass, serializer);
+sse_encode_opt_String(self.jwt, serializer);
+sse_encode_opt_String(self.nkey, serializer);
+sse_encode_opt_String(self.signature, serializer);
+ }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self, serializer); }
+
+@protected void sse_encode_box_autoadd_cached_kv_value(CachedKvValue self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_cached_kv_value(self, serializer); }
+
+@protected void sse_encode_box_autoadd_compression_config(CompressionConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_compression_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_consumer_config(ConsumerConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_consumer_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_decoded_payload(DecodedPayload self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_decoded_payload(self, serializer); }
+
+@protected void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_jet_stream_options(JetStreamOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_jet_stream_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_js_message(JsMessage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_js_message(self, serializer); }
+
+@protected void sse_encode_box_autoadd_js_publish_options(JsPublishOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_js_publish_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_kv_config(KvConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_kv_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_kv_entry(KvEntry self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_kv_entry(self, serializer); }
+
+@protected void sse_encode_box_autoadd_kv_republish(KvRepublish self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_kv_republish(self, serializer); }
+
+@protected void sse_encode_box_autoadd_kv_source(KvSource self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_kv_source(self, serializer); }
+
+@protected void sse_encode_box_autoadd_kv_watch_options(KvWatchOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_kv_watch_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_nats_config(NatsConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_nats_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_object_link(ObjectLink self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_object_link(self, serializer); }
+
+@protected void sse_encode_box_autoadd_object_meta(ObjectMeta self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_object_meta(self, serializer); }
+
+@protected void sse_encode_box_autoadd_object_store_config(ObjectStoreConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_object_store_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_outbox_config(OutboxConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_outbox_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_reconnection_config(ReconnectionConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_reconnection_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_service_error(ServiceError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_service_error(self, serializer); }
+
+@protected void sse_encode_box_autoadd_stream_config(StreamConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_stream_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_x_key_pair(XKeyPair self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_x_key_pair(self, serializer); }
+
+@protected void sse_encode_cached_kv_value(CachedKvValue self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.value, serializer);
+sse_encode_u_64(self.revision, serializer);
+sse_encode_bool(self.stale, serializer);
+sse_encode_bool(self.pending, serializer);
+sse_encode_opt_box_autoadd_i_64(self.syncedMs, serializer);
+ }
+
+@protected void sse_encode_compression_algorithm(CompressionAlgorithm self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_compression_config(CompressionConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_compression_algorithm(self.algorithm, serializer);
+sse_encode_u_64(self.minSize, serializer);
+ }
+
+@protected void sse_encode_connection_state(ConnectionState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_connection_stats(ConnectionStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.inMessages, serializer);
+sse_encode_u_64(self.outMessages, serializer);
+sse_encode_u_64(self.inBytes, serializer);
+sse_encode_u_64(self.outBytes, serializer);
+sse_encode_u_64(self.reconnects, serializer);
+sse_encode_connection_state(self.state, serializer);
+ }
+
+@protected void sse_encode_consumer_config(ConsumerConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.durableName, serializer);
+sse_encode_opt_String(self.description, serializer);
+sse_encode_list_String(self.filterSubjects, serializer);
+sse_encode_ack_policy(self.ackPolicy, serializer);
+sse_encode_opt_box_autoadd_u_64(self.ackWaitMs, serializer);
+sse_encode_opt_box_autoadd_i_64(self.maxDeliver, serializer);
+sse_encode_list_prim_u_64_strict(self.backoffMs, serializer);
+sse_encode_opt_box_autoadd_i_64(self.maxAckPending, serializer);
+sse_encode_opt_box_autoadd_u_64(self.inactiveThresholdMs, serializer);
+ }
+
+@protected void sse_encode_consumer_info(ConsumerInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.stream, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_i_64(self.createdMs, serializer);
+sse_encode_consumer_config(self.config, serializer);
+sse_encode_u_64(self.deliveredStreamSequence, serializer);
+sse_encode_u_64(self.deliveredConsumerSequence, serializer);
+sse_encode_u_64(self.ackFloorStreamSequence, serializer);
+sse_encode_u_64(self.ackFloorConsumerSequence, serializer);
+sse_encode_u_64(self.numAckPending, serializer);
+sse_encode_u_64(self.numRedelivered, serializer);
+sse_encode_u_64(self.numWaiting, serializer);
+sse_encode_u_64(self.numPending, serializer);
+ }
+
+@protected void sse_encode_consumer_pause_state(ConsumerPauseState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.paused, serializer);
+sse_encode_opt_box_autoadd_i_64(self.pauseUntilMs, serializer);
+sse_encode_opt_box_autoadd_u_64(self.pauseRemainingMs, serializer);
+ }
+
+@protected void sse_encode_credentials(Credentials self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.jwt, serializer);
+sse_encode_String(self.publicKey, serializer);
+sse_encode_jwt_claims(self.claims, serializer);
+ }
+
+@protected void sse_encode_decoded_message(DecodedMessage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.subject, serializer);
+sse_encode_opt_String(self.reply, serializer);
+sse_encode_Map_String_list_String(self.headers, serializer);
+sse_encode_decoded_payload(self.payload, serializer);
+ }
+
+@protected void sse_encode_decoded_payload(DecodedPayload self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_payload_codec(self.codec, serializer);
+sse_encode_opt_String(self.json, serializer);
+sse_encode_list_prim_u_8_strict(self.bytes, serializer);
+ }
+
+@protected void sse_encode_deliver_policy(DeliverPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_discard_policy(DiscardPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_endpoint_info(EndpointInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.subject, serializer);
+sse_encode_String(self.queueGroup, serializer);
+sse_encode_Map_String_String(self.metadata, serializer);
+ }
+
+@protected void sse_encode_endpoint_stats(EndpointStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.subject, serializer);
+sse_encode_String(self.queueGroup, serializer);
+sse_encode_u_64(self.numRequests, serializer);
+sse_encode_u_64(self.numErrors, serializer);
+sse_encode_u_64(self.processingTimeNs, serializer);
+sse_encode_u_64(self.averageProcessingTimeNs, serializer);
+sse_encode_opt_String(self.lastError, serializer);
+ }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putPlatformInt64(self); }
+
+@protected void sse_encode_isize(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putPlatformInt64(self); }
+
+@protected void sse_encode_jet_stream_options(JetStreamOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.domain, serializer);
+sse_encode_opt_String(self.apiPrefix, serializer);
+sse_encode_opt_box_autoadd_u_64(self.requestTimeoutMs, serializer);
+ }
+
+@protected void sse_encode_js_message(JsMessage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.handle, serializer);
+sse_encode_String(self.subject, serializer);
+sse_encode_String(self.payload, serializer);
+sse_encode_list_prim_u_8_strict(self.bytes, serializer);
+sse_encode_opt_String(self.decodeError, serializer);
+sse_encode_String(self.stream, serializer);
+sse_encode_String(self.consumer, serializer);
+sse_encode_u_64(self.streamSequence, serializer);
+sse_encode_u_64(self.consumerSequence, serializer);
+sse_encode_u_64(self.delivered, serializer);
+sse_encode_u_64(self.pending, serializer);
+sse_encode_i_64(self.timestampMs, serializer);
+ }
+
+@protected void sse_encode_js_publish_options(JsPublishOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.messageId, serializer);
+sse_encode_opt_String(self.expectedStream, serializer);
+sse_encode_opt_String(self.expectedLastMessageId, serializer);
+sse_encode_opt_box_autoadd_u_64(self.expectedLastSequence, serializer);
+sse_encode_opt_box_autoadd_u_64(self.expectedLastSubjectSequence, serializer);
+ }
+
+@protected void sse_encode_jwt_claims(JwtClaims self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.subject, serializer);
+sse_encode_opt_String(self.name, serializer);
+sse_encode_String(self.issuer, serializer);
+sse_encode_opt_String(self.issuerAccount, serializer);
+sse_encode_i_64(self.issuedAtMs, serializer);
+sse_encode_opt_box_autoadd_i_64(self.expiresMs, serializer);
+sse_encode_subject_permissions(self.publish, serializer);
+sse_encode_subject_permissions(self.subscribe, serializer);
+sse_encode_bool(self.bearerToken, serializer);
+ }
+
+@protected void sse_encode_kv_cache_event(KvCacheEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_kv_cache_event_kind(self.kind, serializer);
+sse_encode_opt_String(self.key, serializer);
+sse_encode_opt_box_autoadd_u_64(self.revision, serializer);
+ }
+
+@protected void sse_encode_kv_cache_event_kind(KvCacheEventKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_kv_config(KvConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.bucket, serializer);
+sse_encode_opt_String(self.description, serializer);
+sse_encode_opt_box_autoadd_i_64(self.history, serializer);
+sse_encode_opt_box_autoadd_u_64(self.maxAgeMs, serializer);
+sse_encode_opt_box_autoadd_i_64(self.maxBytes, serializer);
+sse_encode_opt_box_autoadd_i_32(self.maxValueSize, serializer);
+sse_encode_storage_type(self.storage, serializer);
+sse_encode_opt_box_autoadd_u_32(self.numReplicas, serializer);
+sse_encode_opt_box_autoadd_bool(self.compression, serializer);
+sse_encode_opt_box_autoadd_kv_republish(self.republish, serializer);
+sse_encode_opt_box_autoadd_kv_source(self.mirror, serializer);
+sse_encode_opt_list_kv_source(self.sources, serializer);
+ }
+
+@protected void sse_encode_kv_entry(KvEntry self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.bucket, serializer);
+sse_encode_String(self.key, serializer);
+sse_encode_String(self.value, serializer);
+sse_encode_u_64(self.revision, serializer);
+sse_encode_u_64(self.delta, serializer);
+sse_encode_i_64(self.createdMs, serializer);
+sse_encode_kv_operation(self.operation, serializer);
+ }
+
+@protected void sse_encode_kv_operation(KvOperation self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_kv_republish(KvRepublish self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.source, serializer);
+sse_encode_String(self.destination, serializer);
+sse_encode_bool(self.headersOnly, serializer);
+ }
+
+@protected void sse_encode_kv_source(KvSource self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.bucket, serializer);
+sse_encode_opt_String(self.filterSubject, serializer);
+sse_encode_opt_String(self.domain, serializer);
+ }
+
+@protected void sse_encode_kv_status(KvStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_kv_config(self.config, serializer);
+sse_encode_u_64(self.values, serializer);
+sse_encode_u_64(self.bytes, serializer);
+sse_encode_i_64(self.createdMs, serializer);
+ }
+
+@protected void sse_encode_kv_watch_options(KvWatchOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.includeHistory, serializer);
+sse_encode_bool(self.updatesOnly, serializer);
+sse_encode_bool(self.metadataOnly, serializer);
+ }
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_String(item, serializer); } }
+
+@protected void sse_encode_list_consumer_info(List<ConsumerInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_consumer_info(item, serializer); } }
+
+@protected void sse_encode_list_endpoint_info(List<EndpointInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_endpoint_info(item, serializer); } }
+
+@protected void sse_encode_list_endpoint_stats(List<EndpointStats> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_endpoint_stats(item, serializer); } }
+
+@protected void sse_encode_list_js_message(List<JsMessage> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_js_message(item, serializer); } }
+
+@protected void sse_encode_list_kv_entry(List<KvEntry> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_kv_entry(item, serializer); } }
+
+@protected void sse_encode_list_kv_source(List<KvSource> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_kv_source(item, serializer); } }
+
+@protected void sse_encode_list_object_info(List<ObjectInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_object_info(item, serializer); } }
+
+@protected void sse_encode_list_prim_u_64_strict(Uint64List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint64List(self); }
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self is Uint8List ? self : Uint8List.fromList(self)); }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_list_recipient_key(List<RecipientKey> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_recipient_key(item, serializer); } }
+
+@protected void sse_encode_list_record_string_list_string(List<(String,List<String>)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_string_list_string(item, serializer); } }
+
+@protected void sse_encode_list_record_string_string(List<(String,String)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_string_string(item, serializer); } }
+
+@protected void sse_encode_list_service_info(List<ServiceInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_service_info(item, serializer); } }
+
+@protected void sse_encode_list_service_ping(List<ServicePing> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_service_ping(item, serializer); } }
+
+@protected void sse_encode_list_service_stats(List<ServiceStats> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_service_stats(item, serializer); } }
+
+@protected void sse_encode_list_stream_info(List<StreamInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_stream_info(item, serializer); } }
+
+@protected void sse_encode_nats_config(NatsConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.host, serializer);
+sse_encode_u_16(self.port, serializer);
+sse_encode_opt_String(self.token, serializer);
+sse_encode_opt_String(self.nkey, serializer);
+sse_encode_opt_String(self.creds, serializer);
+sse_encode_opt_String(self.user, serializer);
+sse_encode_opt_String(self.pass, serializer);
+sse_encode_opt_box_autoadd_reconnection_config(self.reconnection, serializer);
+sse_encode_opt_box_autoadd_u_64(self.pingInterval, serializer);
+sse_encode_opt_box_autoadd_u_32(self.maxPingFails, serializer);
+ }
+
+@protected void sse_encode_nats_error(NatsError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_nats_error_kind(self.kind, serializer);
+sse_encode_String(self.message, serializer);
+sse_encode_opt_box_autoadd_u_64(self.payloadSize, serializer);
+sse_encode_opt_box_autoadd_u_64(self.maxPayload, serializer);
+ }
+
+@protected void sse_encode_nats_error_kind(NatsErrorKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_nkey_kind(NkeyKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_nkey_pair(NkeyPair self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.publicKey, serializer);
+sse_encode_String(self.seed, serializer);
+ }
+
+@protected void sse_encode_object_info(ObjectInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.bucket, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_opt_String(self.description, serializer);
+sse_encode_Map_String_String(self.metadata, serializer);
+sse_encode_u_64(self.size, serializer);
+sse_encode_u_64(self.chunks, serializer);
+sse_encode_opt_box_autoadd_i_64(self.modifiedMs, serializer);
+sse_encode_opt_String(self.digest, serializer);
+sse_encode_bool(self.deleted, serializer);
+sse_encode_opt_box_autoadd_object_link(self.link, serializer);
+ }
+
+@protected void sse_encode_object_link(ObjectLink self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.bucket, serializer);
+sse_encode_opt_String(self.name, serializer);
+ }
+
+@protected void sse_encode_object_meta(ObjectMeta self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_opt_String(self.description, serializer);
+sse_encode_Map_String_String(self.metadata, serializer);
+sse_encode_opt_box_autoadd_u_32(self.chunkSize, serializer);
+ }
+
+@protected void sse_encode_object_store_config(ObjectStoreConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.bucket, serializer);
+sse_encode_opt_String(self.description, serializer);
+sse_encode_opt_box_autoadd_u_64(self.maxAgeMs, serializer);
+sse_encode_opt_box_autoadd_i_64(self.maxBytes, serializer);
+sse_encode_storage_type(self.storage, serializer);
+sse_encode_opt_box_autoadd_u_32(self.numReplicas, serializer);
+sse_encode_bool(self.compression, serializer);
+ }
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_bool(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_cached_kv_value(CachedKvValue? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_cached_kv_value(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_compression_config(CompressionConfig? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_compression_config(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_decoded_payload(DecodedPayload? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_decoded_payload(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_i_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_i_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_js_message(JsMessage? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_js_message(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_kv_entry(KvEntry? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_kv_entry(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_kv_republish(KvRepublish? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_kv_republish(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_kv_source(KvSource? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_kv_source(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_object_link(ObjectLink? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_object_link(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_reconnection_config(ReconnectionConfig? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_reconnection_config(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_service_error(ServiceError? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_service_error(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_x_key_pair(XKeyPair? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_x_key_pair(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_list_kv_source(List<KvSource>? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_list_kv_source(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_outbox_config(OutboxConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.path, serializer);
+sse_encode_opt_box_autoadd_u_64(self.maxMessages, serializer);
+sse_encode_opt_box_autoadd_u_64(self.maxBytes, serializer);
+sse_encode_opt_box_autoadd_u_64(self.ttlMs, serializer);
+ }
+
+@protected void sse_encode_outbox_event(OutboxEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_outbox_event_kind(self.kind, serializer);
+sse_encode_u_64(self.id, serializer);
+sse_encode_String(self.subject, serializer);
+sse_encode_i_64(self.queuedMs, serializer);
+sse_encode_opt_String(self.error, serializer);
+ }
+
+@protected void sse_encode_outbox_event_kind(OutboxEventKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_outbox_status(OutboxStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.pendingMessages, serializer);
+sse_encode_u_64(self.pendingBytes, serializer);
+ }
+
+@protected void sse_encode_payload_codec(PayloadCodec self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_pub_ack(PubAck self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.stream, serializer);
+sse_encode_u_64(self.sequence, serializer);
+sse_encode_bool(self.duplicate, serializer);
+sse_encode_opt_String(self.domain, serializer);
+ }
+
+@protected void sse_encode_recipient_key(RecipientKey self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.subject, serializer);
+sse_encode_String(self.publicKey, serializer);
+ }
+
+@protected void sse_encode_reconnection_config(ReconnectionConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_u_32(self.maxAttempts, serializer);
+sse_encode_opt_box_autoadd_u_64(self.delay, serializer);
+ }
+
+@protected void sse_encode_record_string_list_string((String,List<String>) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.$1, serializer);
+sse_encode_list_String(self.$2, serializer);
+ }
+
+@protected void sse_encode_record_string_string((String,String) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.$1, serializer);
+sse_encode_String(self.$2, serializer);
+ }
+
+@protected void sse_encode_retention_policy(RetentionPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_server_info(ServerInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.serverId, serializer);
+sse_encode_String(self.serverName, serializer);
+sse_encode_String(self.version, serializer);
+sse_encode_String(self.host, serializer);
+sse_encode_u_16(self.port, serializer);
+sse_encode_u_64(self.maxPayload, serializer);
+sse_encode_u_64(self.clientId, serializer);
+sse_encode_String(self.clientIp, serializer);
+sse_encode_list_String(self.connectUrls, serializer);
+sse_encode_bool(self.authRequired, serializer);
+sse_encode_bool(self.tlsRequired, serializer);
+sse_encode_bool(self.lameDuckMode, serializer);
+sse_encode_opt_box_autoadd_bool(self.jetstreamEnabled, serializer);
+ }
+
+@protected void sse_encode_service_error(ServiceError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.code, serializer);
+sse_encode_String(self.description, serializer);
+ }
+
+@protected void sse_encode_service_info(ServiceInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.description, serializer);
+sse_encode_String(self.version, serializer);
+sse_encode_Map_String_String(self.metadata, serializer);
+sse_encode_list_endpoint_info(self.endpoints, serializer);
+ }
+
+@protected void sse_encode_service_ping(ServicePing self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.version, serializer);
+sse_encode_Map_String_String(self.metadata, serializer);
+ }
+
+@protected void sse_encode_service_request(ServiceRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.subject, serializer);
+sse_encode_String(self.payload, serializer);
+ }
+
+@protected void sse_encode_service_response(ServiceResponse self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.payload, serializer);
+sse_encode_opt_box_autoadd_service_error(self.error, serializer);
+ }
+
+@protected void sse_encode_service_stats(ServiceStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.version, serializer);
+sse_encode_i_64(self.startedMs, serializer);
+sse_encode_list_endpoint_stats(self.endpoints, serializer);
+ }
+
+@protected void sse_encode_storage_type(StorageType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_stored_message(StoredMessage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.subject, serializer);
+sse_encode_u_64(self.sequence, serializer);
+sse_encode_Map_String_list_String(self.headers, serializer);
+sse_encode_String(self.payload, serializer);
+sse_encode_list_prim_u_8_strict(self.bytes, serializer);
+sse_encode_opt_String(self.decodeError, serializer);
+sse_encode_i_64(self.timestampMs, serializer);
+ }
+
+@protected void sse_encode_stream_config(StreamConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_list_String(self.subjects, serializer);
+sse_encode_opt_String(self.description, serializer);
+sse_encode_retention_policy(self.retention, serializer);
+sse_encode_storage_type(self.storage, serializer);
+sse_encode_discard_policy(self.discard, serializer);
+sse_encode_opt_box_autoadd_i_64(self.maxMessages, serializer);
+sse_encode_opt_box_autoadd_i_64(self.maxMessagesPerSubject, serializer);
+sse_encode_opt_box_autoadd_i_64(self.maxBytes, serializer);
+sse_encode_opt_box_autoadd_u_64(self.maxAgeMs, serializer);
+sse_encode_opt_box_autoadd_i_32(self.maxMessageSize, serializer);
+sse_encode_opt_box_autoadd_i_32(self.maxConsumers, serializer);
+sse_encode_opt_box_autoadd_u_32(self.numReplicas, serializer);
+sse_encode_opt_box_autoadd_u_64(self.duplicateWindowMs, serializer);
+sse_encode_bool(self.allowDirect, serializer);
+ }
+
+@protected void sse_encode_stream_info(StreamInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_stream_config(self.config, serializer);
+sse_encode_i_64(self.createdMs, serializer);
+sse_encode_stream_state(self.state, serializer);
+ }
+
+@protected void sse_encode_stream_state(StreamState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.messages, serializer);
+sse_encode_u_64(self.bytes, serializer);
+sse_encode_u_64(self.firstSequence, serializer);
+sse_encode_i_64(self.firstTimestampMs, serializer);
+sse_encode_u_64(self.lastSequence, serializer);
+sse_encode_i_64(self.lastTimestampMs, serializer);
+sse_encode_u_64(self.consumerCount, serializer);
+sse_encode_u_64(self.subjectsCount, serializer);
+sse_encode_opt_box_autoadd_u_64(self.deletedCount, serializer);
+ }
+
+@protected void sse_encode_subject_permissions(SubjectPermissions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_String(self.allow, serializer);
+sse_encode_list_String(self.deny, serializer);
+ }
+
+@protected void sse_encode_u_16(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint16(self); }
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint32(self); }
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_x_key_pair(XKeyPair self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.publicKey, serializer);
+sse_encode_String(self.seed, serializer);
+ }
+                }
+                
@@ -3,298 +3,993 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+
 // Static analysis wrongly picks the IO variant, thus ignore this
 // ignore_for_file: argument_type_not_assignable
 
+import 'api/codec.dart';
+import 'api/compression.dart';
+import 'api/consumer.dart';
+import 'api/credentials.dart';
+import 'api/encryption.dart';
+import 'api/error.dart';
+import 'api/jetstream.dart';
+import 'api/kv.dart';
+import 'api/kv_cache.dart';
 import 'api/nats.dart';
+import 'api/object_store.dart';
+import 'api/outbox.dart';
+import 'api/service.dart';
+import 'api/subject.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
 
-abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
-  RustLibApiImplPlatform({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
 
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw);
 
-  @protected
-  FutureOr<String> Function(String)
-      dco_decode_DartFn_Inputs_String_Output_String_AnyhowException(
-          dynamic raw);
 
-  @protected
-  FutureOr<void> Function(String)
-      dco_decode_DartFn_Inputs_String_Output_unit_AnyhowException(dynamic raw);
+                abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
+                  RustLibApiImplPlatform({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw);
+
+@protected FutureOr<String> Function(String) dco_decode_DartFn_Inputs_String_Output_String_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(String) dco_decode_DartFn_Inputs_String_Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(String, String) dco_decode_DartFn_Inputs_String_String_Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(String, bool) dco_decode_DartFn_Inputs_String_bool_Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function() dco_decode_DartFn_Inputs__Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(bool) dco_decode_DartFn_Inputs_bool_Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<bool> Function(JsMessage) dco_decode_DartFn_Inputs_js_message_Output_bool_AnyhowException(dynamic raw);
+
+@protected FutureOr<AuthCredentials> Function(Uint8List) dco_decode_DartFn_Inputs_list_prim_u_8_strict_Output_auth_credentials_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(NatsError) dco_decode_DartFn_Inputs_nats_error_Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<ServiceResponse> Function(ServiceRequest) dco_decode_DartFn_Inputs_service_request_Output_service_response_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(BigInt, BigInt) dco_decode_DartFn_Inputs_u_64_u_64_Output_unit_AnyhowException(dynamic raw);
+
+@protected Object dco_decode_DartOpaque(dynamic raw);
+
+@protected Map<String, String> dco_decode_Map_String_String(dynamic raw);
+
+@protected Map<String, List<String>> dco_decode_Map_String_list_String(dynamic raw);
+
+@protected RustStreamSink<DecodedMessage> dco_decode_StreamSink_decoded_message_Sse(dynamic raw);
+
+@protected RustStreamSink<JsMessage> dco_decode_StreamSink_js_message_Sse(dynamic raw);
+
+@protected RustStreamSink<KvCacheEvent> dco_decode_StreamSink_kv_cache_event_Sse(dynamic raw);
+
+@protected RustStreamSink<KvEntry> dco_decode_StreamSink_kv_entry_Sse(dynamic raw);
+
+@protected RustStreamSink<ObjectInfo> dco_decode_StreamSink_object_info_Sse(dynamic raw);
+
+@protected RustStreamSink<OutboxEvent> dco_decode_StreamSink_outbox_event_Sse(dynamic raw);
+
+@protected String dco_decode_String(dynamic raw);
+
+@protected AccountInfo dco_decode_account_info(dynamic raw);
+
+@protected AccountLimits dco_decode_account_limits(dynamic raw);
+
+@protected AckPolicy dco_decode_ack_policy(dynamic raw);
+
+@protected AuthCredentials dco_decode_auth_credentials(dynamic raw);
+
+@protected bool dco_decode_bool(dynamic raw);
+
+@protected bool dco_decode_box_autoadd_bool(dynamic raw);
+
+@protected CachedKvValue dco_decode_box_autoadd_cached_kv_value(dynamic raw);
+
+@protected CompressionConfig dco_decode_box_autoadd_compression_config(dynamic raw);
+
+@protected ConsumerConfig dco_decode_box_autoadd_consumer_config(dynamic raw);
+
+@protected DecodedPayload dco_decode_box_autoadd_decoded_payload(dynamic raw);
+
+@protected int dco_decode_box_autoadd_i_32(dynamic raw);
+
+@protected PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
+
+@protected JetStreamOptions dco_decode_box_autoadd_jet_stream_options(dynamic raw);
+
+@protected JsMessage dco_decode_box_autoadd_js_message(dynamic raw);
+
+@protected JsPublishOptions dco_decode_box_autoadd_js_publish_options(dynamic raw);
+
+@protected KvConfig dco_decode_box_autoadd_kv_config(dynamic raw);
+
+@protected KvEntry dco_decode_box_autoadd_kv_entry(dynamic raw);
+
+@protected KvRepublish dco_decode_box_autoadd_kv_republish(dynamic raw);
+
+@protected KvSource dco_decode_box_autoadd_kv_source(dynamic raw);
+
+@protected KvWatchOptions dco_decode_box_autoadd_kv_watch_options(dynamic raw);
+
+@protected NatsConfig dco_decode_box_autoadd_nats_config(dynamic raw);
+
+@protected ObjectLink dco_decode_box_autoadd_object_link(dynamic raw);
+
+@protected ObjectMeta dco_decode_box_autoadd_object_meta(dynamic raw);
+
+@protected ObjectStoreConfig dco_decode_box_autoadd_object_store_config(dynamic raw);
+
+@protected OutboxConfig dco_decode_box_autoadd_outbox_config(dynamic raw);
+
+@protected ReconnectionConfig dco_decode_box_autoadd_reconnection_config(dynamic raw);
+
+@protected ServiceError dco_decode_box_autoadd_service_error(dynamic raw);
+
+@protected StreamConfig dco_decode_box_autoadd_stream_config(dynamic raw);
+
+@protected int dco_decode_box_autoadd_u_32(dynamic raw);
+
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
+@protected XKeyPair dco_decode_box_autoadd_x_key_pair(dynamic raw);
+
+@protected CachedKvValue dco_decode_cached_kv_value(dynamic raw);
+
+@protected CompressionAlgorithm dco_decode_compression_algorithm(dynamic raw);
+
+@protected CompressionConfig dco_decode_compression_config(dynamic raw);
+
+@protected ConnectionState dco_decode_connection_state(dynamic raw);
+
+@protected ConnectionStats dco_decode_connection_stats(dynamic raw);
+
+@protected ConsumerConfig dco_decode_consumer_config(dynamic raw);
+
+@protected ConsumerInfo dco_decode_consumer_info(dynamic raw);
+
+@protected ConsumerPauseState dco_decode_consumer_pause_state(dynamic raw);
+
+@protected Credentials dco_decode_credentials(dynamic raw);
+
+@protected DecodedMessage dco_decode_decoded_message(dynamic raw);
+
+@protected DecodedPayload dco_decode_decoded_payload(dynamic raw);
+
+@protected DeliverPolicy dco_decode_deliver_policy(dynamic raw);
+
+@protected DiscardPolicy dco_decode_discard_policy(dynamic raw);
+
+@protected EndpointInfo dco_decode_endpoint_info(dynamic raw);
+
+@protected EndpointStats dco_decode_endpoint_stats(dynamic raw);
+
+@protected int dco_decode_i_32(dynamic raw);
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw);
+
+@protected PlatformInt64 dco_decode_isize(dynamic raw);
+
+@protected JetStreamOptions dco_decode_jet_stream_options(dynamic raw);
+
+@protected JsMessage dco_decode_js_message(dynamic raw);
+
+@protected JsPublishOptions dco_decode_js_publish_options(dynamic raw);
+
+@protected JwtClaims dco_decode_jwt_claims(dynamic raw);
+
+@protected KvCacheEvent dco_decode_kv_cache_event(dynamic raw);
+
+@protected KvCacheEventKind dco_decode_kv_cache_event_kind(dynamic raw);
+
+@protected KvConfig dco_decode_kv_config(dynamic raw);
+
+@protected KvEntry dco_decode_kv_entry(dynamic raw);
+
+@protected KvOperation dco_decode_kv_operation(dynamic raw);
+
+@protected KvRepublish dco_decode_kv_republish(dynamic raw);
+
+@protected KvSource dco_decode_kv_source(dynamic raw);
+
+@protected KvStatus dco_decode_kv_status(dynamic raw);
+
+@protected KvWatchOptions dco_decode_kv_watch_options(dynamic raw);
+
+@protected List<String> dco_decode_list_String(dynamic raw);
+
+@protected List<ConsumerInfo> dco_decode_list_consumer_info(dynamic raw);
+
+@protected List<EndpointInfo> dco_decode_list_endpoint_info(dynamic raw);
+
+@protected List<EndpointStats> dco_decode_list_endpoint_stats(dynamic raw);
+
+@protected List<JsMessage> dco_decode_list_js_message(dynamic raw);
+
+@protected List<KvEntry> dco_decode_list_kv_entry(dynamic raw);
+
+@protected List<KvSource> dco_decode_list_kv_source(dynamic raw);
+
+@protected List<ObjectInfo> dco_decode_list_object_info(dynamic raw);
+
+@protected Uint64List dco_decode_list_prim_u_64_strict(dynamic raw);
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+@protected List<RecipientKey> dco_decode_list_recipient_key(dynamic raw);
+
+@protected List<(String,List<String>)> dco_decode_list_record_string_list_string(dynamic raw);
+
+@protected List<(String,String)> dco_decode_list_record_string_string(dynamic raw);
+
+@protected List<ServiceInfo> dco_decode_list_service_info(dynamic raw);
+
+@protected List<ServicePing> dco_decode_list_service_ping(dynamic raw);
+
+@protected List<ServiceStats> dco_decode_list_service_stats(dynamic raw);
+
+@protected List<StreamInfo> dco_decode_list_stream_info(dynamic raw);
+
+@protected NatsConfig dco_decode_nats_config(dynamic raw);
+
+@protected NatsError dco_decode_nats_error(dynamic raw);
+
+@protected NatsErrorKind dco_decode_nats_error_kind(dynamic raw);
+
+@protected NkeyKind dco_decode_nkey_kind(dynamic raw);
+
+@protected NkeyPair dco_decode_nkey_pair(dynamic raw);
+
+@protected ObjectInfo dco_decode_object_info(dynamic raw);
+
+@protected ObjectLink dco_decode_object_link(dynamic raw);
+
+@protected ObjectMeta dco_decode_object_meta(dynamic raw);
+
+@protected ObjectStoreConfig dco_decode_object_store_config(dynamic raw);
+
+@protected String? dco_decode_opt_String(dynamic raw);
+
+@protected bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
+
+@protected CachedKvValue? dco_decode_opt_box_autoadd_cached_kv_value(dynamic raw);
+
+@protected CompressionConfig? dco_decode_opt_box_autoadd_compression_config(dynamic raw);
+
+@protected DecodedPayload? dco_decode_opt_box_autoadd_decoded_payload(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
+
+@protected PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+
+@protected JsMessage? dco_decode_opt_box_autoadd_js_message(dynamic raw);
+
+@protected KvEntry? dco_decode_opt_box_autoadd_kv_entry(dynamic raw);
+
+@protected KvRepublish? dco_decode_opt_box_autoadd_kv_republish(dynamic raw);
+
+@protected KvSource? dco_decode_opt_box_autoadd_kv_source(dynamic raw);
+
+@protected ObjectLink? dco_decode_opt_box_autoadd_object_link(dynamic raw);
+
+@protected ReconnectionConfig? dco_decode_opt_box_autoadd_reconnection_config(dynamic raw);
+
+@protected ServiceError? dco_decode_opt_box_autoadd_service_error(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+@protected XKeyPair? dco_decode_opt_box_autoadd_x_key_pair(dynamic raw);
+
+@protected List<KvSource>? dco_decode_opt_list_kv_source(dynamic raw);
+
+@protected OutboxConfig dco_decode_outbox_config(dynamic raw);
+
+@protected OutboxEvent dco_decode_outbox_event(dynamic raw);
+
+@protected OutboxEventKind dco_decode_outbox_event_kind(dynamic raw);
+
+@protected OutboxStatus dco_decode_outbox_status(dynamic raw);
+
+@protected PayloadCodec dco_decode_payload_codec(dynamic raw);
+
+@protected PubAck dco_decode_pub_ack(dynamic raw);
+
+@protected RecipientKey dco_decode_recipient_key(dynamic raw);
+
+@protected ReconnectionConfig dco_decode_reconnection_config(dynamic raw);
+
+@protected (String,List<String>) dco_decode_record_string_list_string(dynamic raw);
+
+@protected (String,String) dco_decode_record_string_string(dynamic raw);
+
+@protected RetentionPolicy dco_decode_retention_policy(dynamic raw);
+
+@protected ServerInfo dco_decode_server_info(dynamic raw);
+
+@protected ServiceError dco_decode_service_error(dynamic raw);
+
+@protected ServiceInfo dco_decode_service_info(dynamic raw);
+
+@protected ServicePing dco_decode_service_ping(dynamic raw);
+
+@protected ServiceRequest dco_decode_service_request(dynamic raw);
+
+@protected ServiceResponse dco_decode_service_response(dynamic raw);
+
+@protected ServiceStats dco_decode_service_stats(dynamic raw);
+
+@protected StorageType dco_decode_storage_type(dynamic raw);
+
+@protected StoredMessage dco_decode_stored_message(dynamic raw);
+
+@protected StreamConfig dco_decode_stream_config(dynamic raw);
+
+@protected StreamInfo dco_decode_stream_info(dynamic raw);
+
+@protected StreamState dco_decode_stream_state(dynamic raw);
+
+@protected SubjectPermissions dco_decode_subject_permissions(dynamic raw);
+
+@protected int dco_decode_u_16(dynamic raw);
+
+@protected int dco_decode_u_32(dynamic raw);
+
+@protected BigInt dco_decode_u_64(dynamic raw);
+
+@protected int dco_decode_u_8(dynamic raw);
+
+@protected void dco_decode_unit(dynamic raw);
+
+@protected BigInt dco_decode_usize(dynamic raw);
+
+@protected XKeyPair dco_decode_x_key_pair(dynamic raw);
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+@protected Object sse_decode_DartOpaque(SseDeserializer deserializer);
+
+@protected Map<String, String> sse_decode_Map_String_String(SseDeserializer deserializer);
+
+@protected Map<String, List<String>> sse_decode_Map_String_list_String(SseDeserializer deserializer);
+
+@protected RustStreamSink<DecodedMessage> sse_decode_StreamSink_decoded_message_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<JsMessage> sse_decode_StreamSink_js_message_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<KvCacheEvent> sse_decode_StreamSink_kv_cache_event_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<KvEntry> sse_decode_StreamSink_kv_entry_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<ObjectInfo> sse_decode_StreamSink_object_info_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<OutboxEvent> sse_decode_StreamSink_outbox_event_Sse(SseDeserializer deserializer);
+
+@protected String sse_decode_String(SseDeserializer deserializer);
+
+@protected AccountInfo sse_decode_account_info(SseDeserializer deserializer);
+
+@protected AccountLimits sse_decode_account_limits(SseDeserializer deserializer);
+
+@protected AckPolicy sse_decode_ack_policy(SseDeserializer deserializer);
+
+@protected AuthCredentials sse_decode_auth_credentials(SseDeserializer deserializer);
+
+@protected bool sse_decode_bool(SseDeserializer deserializer);
+
+@protected bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
+
+@protected CachedKvValue sse_decode_box_autoadd_cached_kv_value(SseDeserializer deserializer);
+
+@protected CompressionConfig sse_decode_box_autoadd_compression_config(SseDeserializer deserializer);
+
+@protected ConsumerConfig sse_decode_box_autoadd_consumer_config(SseDeserializer deserializer);
+
+@protected DecodedPayload sse_decode_box_autoadd_decoded_payload(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+
+@protected JetStreamOptions sse_decode_box_autoadd_jet_stream_options(SseDeserializer deserializer);
+
+@protected JsMessage sse_decode_box_autoadd_js_message(SseDeserializer deserializer);
+
+@protected JsPublishOptions sse_decode_box_autoadd_js_publish_options(SseDeserializer deserializer);
+
+@protected KvConfig sse_decode_box_autoadd_kv_config(SseDeserializer deserializer);
+
+@protected KvEntry sse_decode_box_autoadd_kv_entry(SseDeserializer deserializer);
+
+@protected KvRepublish sse_decode_box_autoadd_kv_republish(SseDeserializer deserializer);
+
+@protected KvSource sse_decode_box_autoadd_kv_source(SseDeserializer deserializer);
+
+@protected KvWatchOptions sse_decode_box_autoadd_kv_watch_options(SseDeserializer deserializer);
+
+@protected NatsConfig sse_decode_box_autoadd_nats_config(SseDeserializer deserializer);
+
+@protected ObjectLink sse_decode_box_autoadd_object_link(SseDeserializer deserializer);
+
+@protected ObjectMeta sse_decode_box_autoadd_object_meta(SseDeserializer deserializer);
+
+@protected ObjectStoreConfig sse_decode_box_autoadd_object_store_config(SseDeserializer deserializer);
+
+@protected OutboxConfig sse_decode_box_autoadd_outbox_config(SseDeserializer deserializer);
+
+@protected ReconnectionConfig sse_decode_box_autoadd_reconnection_config(SseDeserializer deserializer);
+
+@protected ServiceError sse_decode_box_autoadd_service_error(SseDeserializer deserializer);
+
+@protected StreamConfig sse_decode_box_autoadd_stream_config(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected XKeyPair sse_decode_box_autoadd_x_key_pair(SseDeserializer deserializer);
+
+@protected CachedKvValue sse_decode_cached_kv_value(SseDeserializer deserializer);
+
+@protected CompressionAlgorithm sse_decode_compression_algorithm(SseDeserializer deserializer);
+
+@protected CompressionConfig sse_decode_compression_config(SseDeserializer deserializer);
+
+@protected ConnectionState sse_decode_connection_state(SseDeserializer deserializer);
+
+@protected ConnectionStats sse_decode_connection_stats(SseDeserializer deserializer);
+
+@protected ConsumerConfig sse_decode_consumer_config(SseDeserializer deserializer);
+
+@protected ConsumerInfo sse_decode_consumer_info(SseDeserializer deserializer);
+
+@protected ConsumerPauseState sse_decode_consumer_pause_state(SseDeserializer deserializer);
+
+@protected Credentials sse_decode_credentials(SseDeserializer deserializer);
+
+@protected DecodedMessage sse_decode_decoded_message(SseDeserializer deserializer);
+
+@protected DecodedPayload sse_decode_decoded_payload(SseDeserializer deserializer);
+
+@protected DeliverPolicy sse_decode_deliver_policy(SseDeserializer deserializer);
+
+@protected DiscardPolicy sse_decode_discard_policy(SseDeserializer deserializer);
+
+@protected EndpointInfo sse_decode_endpoint_info(SseDeserializer deserializer);
+
+@protected EndpointStats sse_decode_endpoint_stats(SseDeserializer deserializer);
+
+@protected int sse_decode_i_32(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_isize(SseDeserializer deserializer);
+
+@protected JetStreamOptions sse_decode_jet_stream_options(SseDeserializer deserializer);
+
+@protected JsMessage sse_decode_js_message(SseDeserializer deserializer);
+
+@protected JsPublishOptions sse_decode_js_publish_options(SseDeserializer deserializer);
+
+@protected JwtClaims sse_decode_jwt_claims(SseDeserializer deserializer);
+
+@protected KvCacheEvent sse_decode_kv_cache_event(SseDeserializer deserializer);
+
+@protected KvCacheEventKind sse_decode_kv_cache_event_kind(SseDeserializer deserializer);
+
+@protected KvConfig sse_decode_kv_config(SseDeserializer deserializer);
+
+@protected KvEntry sse_decode_kv_entry(SseDeserializer deserializer);
+
+@protected KvOperation sse_decode_kv_operation(SseDeserializer deserializer);
+
+@protected KvRepublish sse_decode_kv_republish(SseDeserializer deserializer);
+
+@protected KvSource sse_decode_kv_source(SseDeserializer deserializer);
+
+@protected KvStatus sse_decode_kv_status(SseDeserializer deserializer);
+
+@protected KvWatchOptions sse_decode_kv_watch_options(SseDeserializer deserializer);
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+@protected List<ConsumerInfo> sse_decode_list_consumer_info(SseDeserializer deserializer);
+
+@protected List<EndpointInfo> sse_decode_list_endpoint_info(SseDeserializer deserializer);
+
+@protected List<EndpointStats> sse_decode_list_endpoint_stats(SseDeserializer deserializer);
+
+@protected List<JsMessage> sse_decode_list_js_message(SseDeserializer deserializer);
+
+@protected List<KvEntry> sse_decode_list_kv_entry(SseDeserializer deserializer);
+
+@protected List<KvSource> sse_decode_list_kv_source(SseDeserializer deserializer);
+
+@protected List<ObjectInfo> sse_decode_list_object_info(SseDeserializer deserializer);
+
+@protected Uint64List sse_decode_list_prim_u_64_strict(SseDeserializer deserializer);
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected List<RecipientKey> sse_decode_list_recipient_key(SseDeserializer deserializer);
+
+@protected List<(String,List<String>)> sse_decode_list_record_string_list_string(SseDeserializer deserializer);
+
+@protected List<(String,String)> sse_decode_list_record_string_string(SseDeserializer deserializer);
+
+@protected List<ServiceInfo> sse_decode_list_service_info(SseDeserializer deserializer);
+
+@protected List<ServicePing> sse_decode_list_service_ping(SseDeserializer deserializer);
+
+@protected List<ServiceStats> sse_decode_list_service_stats(SseDeserializer deserializer);
+
+@protected List<StreamInfo> sse_decode_list_stream_info(SseDeserializer deserializer);
+
+@protected NatsConfig sse_decode_nats_config(SseDeserializer deserializer);
+
+@protected NatsError sse_decode_nats_error(SseDeserializer deserializer);
+
+@protected NatsErrorKind sse_decode_nats_error_kind(SseDeserializer deserializer);
+
+@protected NkeyKind sse_decode_nkey_kind(SseDeserializer deserializer);
+
+@protected NkeyPair sse_decode_nkey_pair(SseDeserializer deserializer);
+
+@protected ObjectInfo sse_decode_object_info(SseDeserializer deserializer);
+
+@protected ObjectLink sse_decode_object_link(SseDeserializer deserializer);
+
+@protected ObjectMeta sse_decode_object_meta(SseDeserializer deserializer);
+
+@protected ObjectStoreConfig sse_decode_object_store_config(SseDeserializer deserializer);
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer);
+
+@protected bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+
+@protected CachedKvValue? sse_decode_opt_box_autoadd_cached_kv_value(SseDeserializer deserializer);
+
+@protected CompressionConfig? sse_decode_opt_box_autoadd_compression_config(SseDeserializer deserializer);
+
+@protected DecodedPayload? sse_decode_opt_box_autoadd_decoded_payload(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
+
+@protected PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+@protected JsMessage? sse_decode_opt_box_autoadd_js_message(SseDeserializer deserializer);
+
+@protected KvEntry? sse_decode_opt_box_autoadd_kv_entry(SseDeserializer deserializer);
+
+@protected KvRepublish? sse_decode_opt_box_autoadd_kv_republish(SseDeserializer deserializer);
+
+@protected KvSource? sse_decode_opt_box_autoadd_kv_source(SseDeserializer deserializer);
+
+@protected ObjectLink? sse_decode_opt_box_autoadd_object_link(SseDeserializer deserializer);
+
+@protected ReconnectionConfig? sse_decode_opt_box_autoadd_reconnection_config(SseDeserializer deserializer);
+
+@protected ServiceError? sse_decode_opt_box_autoadd_service_error(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected XKeyPair? sse_decode_opt_box_autoadd_x_key_pair(SseDeserializer deserializer);
+
+@protected List<KvSource>? sse_decode_opt_list_kv_source(SseDeserializer deserializer);
+
+@protected OutboxConfig sse_decode_outbox_config(SseDeserializer deserializer);
+
+@protected OutboxEvent sse_decode_outbox_event(SseDeserializer deserializer);
+
+@protected OutboxEventKind sse_decode_outbox_event_kind(SseDeserializer deserializer);
+
+@protected OutboxStatus sse_decode_outbox_status(SseDeserializer deserializer);
+
+@protected PayloadCodec sse_decode_payload_codec(SseDeserializer deserializer);
+
+@protected PubAck sse_decode_pub_ack(SseDeserializer deserializer);
+
+@protected RecipientKey sse_decode_recipient_key(SseDeserializer deserializer);
+
+@protected ReconnectionConfig sse_decode_reconnection_config(SseDeserializer deserializer);
+
+@protected (String,List<String>) sse_decode_record_string_list_string(SseDeserializer deserializer);
+
+@protected (String,String) sse_decode_record_string_string(SseDeserializer deserializer);
+
+@protected RetentionPolicy sse_decode_retention_policy(SseDeserializer deserializer);
+
+@protected ServerInfo sse_decode_server_info(SseDeserializer deserializer);
+
+@protected ServiceError sse_decode_service_error(SseDeserializer deserializer);
+
+@protected ServiceInfo sse_decode_service_info(SseDeserializer deserializer);
+
+@protected ServicePing sse_decode_service_ping(SseDeserializer deserializer);
+
+@protected ServiceRequest sse_decode_service_request(SseDeserializer deserializer);
+
+@protected ServiceResponse sse_decode_service_response(SseDeserializer deserializer);
+
+@protected ServiceStats sse_decode_service_stats(SseDeserializer deserializer);
+
+@protected StorageType sse_decode_storage_type(SseDeserializer deserializer);
+
+@protected StoredMessage sse_decode_stored_message(SseDeserializer deserializer);
+
+@protected StreamConfig sse_decode_stream_config(SseDeserializer deserializer);
+
+@protected StreamInfo sse_decode_stream_info(SseDeserializer deserializer);
+
+@protected StreamState sse_decode_stream_state(SseDeserializer deserializer);
+
+@protected SubjectPermissions sse_decode_subject_permissions(SseDeserializer deserializer);
+
+@protected int sse_decode_u_16(SseDeserializer deserializer);
+
+@protected int sse_decode_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_u_8(SseDeserializer deserializer);
+
+@protected void sse_decode_unit(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer);
+
+@protected XKeyPair sse_decode_x_key_pair(SseDeserializer deserializer);
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_String_Output_String_AnyhowException(FutureOr<String> Function(String) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(FutureOr<void> Function(String) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_String_String_Output_unit_AnyhowException(FutureOr<void> Function(String, String) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_String_bool_Output_unit_AnyhowException(FutureOr<void> Function(String, bool) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs__Output_unit_AnyhowException(FutureOr<void> Function() self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_bool_Output_unit_AnyhowException(FutureOr<void> Function(bool) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_js_message_Output_bool_AnyhowException(FutureOr<bool> Function(JsMessage) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_list_prim_u_8_strict_Output_auth_credentials_AnyhowException(FutureOr<AuthCredentials> Function(Uint8List) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_nats_error_Output_unit_AnyhowException(FutureOr<void> Function(NatsError) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_service_request_Output_service_response_AnyhowException(FutureOr<ServiceResponse> Function(ServiceRequest) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_u_64_u_64_Output_unit_AnyhowException(FutureOr<void> Function(BigInt, BigInt) self, SseSerializer serializer);
+
+@protected void sse_encode_DartOpaque(Object self, SseSerializer serializer);
+
+@protected void sse_encode_Map_String_String(Map<String, String> self, SseSerializer serializer);
+
+@protected void sse_encode_Map_String_list_String(Map<String, List<String>> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_decoded_message_Sse(RustStreamSink<DecodedMessage> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_js_message_Sse(RustStreamSink<JsMessage> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_kv_cache_event_Sse(RustStreamSink<KvCacheEvent> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_kv_entry_Sse(RustStreamSink<KvEntry> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_object_info_Sse(RustStreamSink<ObjectInfo> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_outbox_event_Sse(RustStreamSink<OutboxEvent> self, SseSerializer serializer);
+
+@protected void sse_encode_String(String self, SseSerializer serializer);
+
+@protected void sse_encode_account_info(AccountInfo self, SseSerializer serializer);
+
+@protected void sse_encode_account_limits(AccountLimits self, SseSerializer serializer);
+
+@protected void sse_encode_ack_policy(AckPolicy self, SseSerializer serializer);
+
+@protected void sse_encode_auth_credentials(AuthCredentials self, SseSerializer serializer);
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_cached_kv_value(CachedKvValue self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_compression_config(CompressionConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_consumer_config(ConsumerConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_decoded_payload(DecodedPayload self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_jet_stream_options(JetStreamOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_js_message(JsMessage self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_js_publish_options(JsPublishOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_kv_config(KvConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_kv_entry(KvEntry self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_kv_republish(KvRepublish self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_kv_source(KvSource self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_kv_watch_options(KvWatchOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_nats_config(NatsConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_object_link(ObjectLink self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_object_meta(ObjectMeta self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_object_store_config(ObjectStoreConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_outbox_config(OutboxConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_reconnection_config(ReconnectionConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_service_error(ServiceError self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_stream_config(StreamConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_x_key_pair(XKeyPair self, SseSerializer serializer);
+
+@protected void sse_encode_cached_kv_value(CachedKvValue self, SseSerializer serializer);
+
+@protected void sse_encode_compression_algorithm(CompressionAlgorithm self, SseSerializer serializer);
+
+@protected void sse_encode_compression_config(CompressionConfig self, SseSerializer serializer);
+
+@protected void sse_encode_connection_state(ConnectionState self, SseSerializer serializer);
+
+@protected void sse_encode_connection_stats(ConnectionStats self, SseSerializer serializer);
+
+@protected void sse_encode_consumer_config(ConsumerConfig self, SseSerializer serializer);
+
+@protected void sse_encode_consumer_info(ConsumerInfo self, SseSerializer serializer);
+
+@protected void sse_encode_consumer_pause_state(ConsumerPauseState self, SseSerializer serializer);
+
+@protected void sse_encode_credentials(Credentials self, SseSerializer serializer);
+
+@protected void sse_encode_decoded_message(DecodedMessage self, SseSerializer serializer);
+
+@protected void sse_encode_decoded_payload(DecodedPayload self, SseSerializer serializer);
+
+@protected void sse_encode_deliver_policy(DeliverPolicy self, SseSerializer serializer);
+
+@protected void sse_encode_discard_policy(DiscardPolicy self, SseSerializer serializer);
+
+@protected void sse_encode_endpoint_info(EndpointInfo self, SseSerializer serializer);
+
+@protected void sse_encode_endpoint_stats(EndpointStats self, SseSerializer serializer);
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_isize(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_jet_stream_options(JetStreamOptions self, SseSerializer serializer);
+
+@protected void sse_encode_js_message(JsMessage self, SseSerializer serializer);
+
+@protected void sse_encode_js_publish_options(JsPublishOptions self, SseSerializer serializer);
+
+@protected void sse_encode_jwt_claims(JwtClaims self, SseSerializer serializer);
+
+@protected void sse_encode_kv_cache_event(KvCacheEvent self, SseSerializer serializer);
+
+@protected void sse_encode_kv_cache_event_kind(KvCacheEventKind self, SseSerializer serializer);
+
+@protected void sse_encode_kv_config(KvConfig self, SseSerializer serializer);
+
+@protected void sse_encode_kv_entry(KvEntry self, SseSerializer serializer);
+
+@protected void sse_encode_kv_operation(KvOperation self, SseSerializer serializer);
+
+@protected void sse_encode_kv_republish(KvRepublish self, SseSerializer serializer);
+
+@protected void sse_encode_kv_source(KvSource self, SseSerializer serializer);
+
+@protected void sse_encode_kv_status(KvStatus self, SseSerializer serializer);
 
-  @protected
-  FutureOr<void> Function(String, String)
-      dco_decode_DartFn_Inputs_String_String_Output_unit_AnyhowException(
-          dynamic raw);
+@protected void sse_encode_kv_watch_options(KvWatchOptions self, SseSerializer serializer);
 
-  @protected
-  FutureOr<void> Function()
-      dco_decode_DartFn_Inputs__Output_unit_AnyhowException(dynamic raw);
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
-  @protected
-  FutureOr<void> Function(bool)
-      dco_decode_DartFn_Inputs_bool_Output_unit_AnyhowException(dynamic raw);
+@protected void sse_encode_list_consumer_info(List<ConsumerInfo> self, SseSerializer serializer);
 
-  @protected
-  Object dco_decode_DartOpaque(dynamic raw);
+@protected void sse_encode_list_endpoint_info(List<EndpointInfo> self, SseSerializer serializer);
 
-  @protected
-  String dco_decode_String(dynamic raw);
+@protected void sse_encode_list_endpoint_stats(List<EndpointStats> self, SseSerializer serializer);
 
-  @protected
-  bool dco_decode_bool(dynamic raw);
+@protected void sse_encode_list_js_message(List<JsMessage> self, SseSerializer serializer);
 
-  @protected
-  NatsConfig dco_decode_box_autoadd_nats_config(dynamic raw);
+@protected void sse_encode_list_kv_entry(List<KvEntry> self, SseSerializer serializer);
 
-  @protected
-  ReconnectionConfig dco_decode_box_autoadd_reconnection_config(dynamic raw);
+@protected void sse_encode_list_kv_source(List<KvSource> self, SseSerializer serializer);
 
-  @protected
-  int dco_decode_box_autoadd_u_32(dynamic raw);
+@protected void sse_encode_list_object_info(List<ObjectInfo> self, SseSerializer serializer);
 
-  @protected
-  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+@protected void sse_encode_list_prim_u_64_strict(Uint64List self, SseSerializer serializer);
 
-  @protected
-  PlatformInt64 dco_decode_isize(dynamic raw);
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
 
-  @protected
-  List<String> dco_decode_list_String(dynamic raw);
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
 
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+@protected void sse_encode_list_recipient_key(List<RecipientKey> self, SseSerializer serializer);
 
-  @protected
-  NatsConfig dco_decode_nats_config(dynamic raw);
+@protected void sse_encode_list_record_string_list_string(List<(String,List<String>)> self, SseSerializer serializer);
 
-  @protected
-  String? dco_decode_opt_String(dynamic raw);
+@protected void sse_encode_list_record_string_string(List<(String,String)> self, SseSerializer serializer);
 
-  @protected
-  ReconnectionConfig? dco_decode_opt_box_autoadd_reconnection_config(
-      dynamic raw);
+@protected void sse_encode_list_service_info(List<ServiceInfo> self, SseSerializer serializer);
 
-  @protected
-  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+@protected void sse_encode_list_service_ping(List<ServicePing> self, SseSerializer serializer);
 
-  @protected
-  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+@protected void sse_encode_list_service_stats(List<ServiceStats> self, SseSerializer serializer);
 
-  @protected
-  ReconnectionConfig dco_decode_reconnection_config(dynamic raw);
+@protected void sse_encode_list_stream_info(List<StreamInfo> self, SseSerializer serializer);
 
-  @protected
-  int dco_decode_u_16(dynamic raw);
+@protected void sse_encode_nats_config(NatsConfig self, SseSerializer serializer);
 
-  @protected
-  int dco_decode_u_32(dynamic raw);
+@protected void sse_encode_nats_error(NatsError self, SseSerializer serializer);
 
-  @protected
-  BigInt dco_decode_u_64(dynamic raw);
+@protected void sse_encode_nats_error_kind(NatsErrorKind self, SseSerializer serializer);
 
-  @protected
-  int dco_decode_u_8(dynamic raw);
+@protected void sse_encode_nkey_kind(NkeyKind self, SseSerializer serializer);
 
-  @protected
-  void dco_decode_unit(dynamic raw);
+@protected void sse_encode_nkey_pair(NkeyPair self, SseSerializer serializer);
 
-  @protected
-  BigInt dco_decode_usize(dynamic raw);
+@protected void sse_encode_object_info(ObjectInfo self, SseSerializer serializer);
 
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+@protected void sse_encode_object_link(ObjectLink self, SseSerializer serializer);
 
-  @protected
-  Object sse_decode_DartOpaque(SseDeserializer deserializer);
+@protected void sse_encode_object_meta(ObjectMeta self, SseSerializer serializer);
 
-  @protected
-  String sse_decode_String(SseDeserializer deserializer);
+@protected void sse_encode_object_store_config(ObjectStoreConfig self, SseSerializer serializer);
 
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer);
 
-  @protected
-  NatsConfig sse_decode_box_autoadd_nats_config(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
 
-  @protected
-  ReconnectionConfig sse_decode_box_autoadd_reconnection_config(
-      SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_cached_kv_value(CachedKvValue? self, SseSerializer serializer);
 
-  @protected
-  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_compression_config(CompressionConfig? self, SseSerializer serializer);
 
-  @protected
-  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_decoded_payload(DecodedPayload? self, SseSerializer serializer);
 
-  @protected
-  PlatformInt64 sse_decode_isize(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
 
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer);
 
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_js_message(JsMessage? self, SseSerializer serializer);
 
-  @protected
-  NatsConfig sse_decode_nats_config(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_kv_entry(KvEntry? self, SseSerializer serializer);
 
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_kv_republish(KvRepublish? self, SseSerializer serializer);
 
-  @protected
-  ReconnectionConfig? sse_decode_opt_box_autoadd_reconnection_config(
-      SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_kv_source(KvSource? self, SseSerializer serializer);
 
-  @protected
-  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_object_link(ObjectLink? self, SseSerializer serializer);
 
-  @protected
-  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_reconnection_config(ReconnectionConfig? self, SseSerializer serializer);
 
-  @protected
-  ReconnectionConfig sse_decode_reconnection_config(
-      SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_service_error(ServiceError? self, SseSerializer serializer);
 
-  @protected
-  int sse_decode_u_16(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
 
-  @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
 
-  @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_x_key_pair(XKeyPair? self, SseSerializer serializer);
 
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+@protected void sse_encode_opt_list_kv_source(List<KvSource>? self, SseSerializer serializer);
 
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+@protected void sse_encode_outbox_config(OutboxConfig self, SseSerializer serializer);
 
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
+@protected void sse_encode_outbox_event(OutboxEvent self, SseSerializer serializer);
 
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+@protected void sse_encode_outbox_event_kind(OutboxEventKind self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_AnyhowException(
-      AnyhowException self, SseSerializer serializer);
+@protected void sse_encode_outbox_status(OutboxStatus self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_DartFn_Inputs_String_Output_String_AnyhowException(
-      FutureOr<String> Function(String) self, SseSerializer serializer);
+@protected void sse_encode_payload_codec(PayloadCodec self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(
-      FutureOr<void> Function(String) self, SseSerializer serializer);
+@protected void sse_encode_pub_ack(PubAck self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_DartFn_Inputs_String_String_Output_unit_AnyhowException(
-      FutureOr<void> Function(String, String) self, SseSerializer serializer);
+@protected void sse_encode_recipient_key(RecipientKey self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_DartFn_Inputs__Output_unit_AnyhowException(
-      FutureOr<void> Function() self, SseSerializer serializer);
+@protected void sse_encode_reconnection_config(ReconnectionConfig self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_DartFn_Inputs_bool_Output_unit_AnyhowException(
-      FutureOr<void> Function(bool) self, SseSerializer serializer);
+@protected void sse_encode_record_string_list_string((String,List<String>) self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_DartOpaque(Object self, SseSerializer serializer);
+@protected void sse_encode_record_string_string((String,String) self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+@protected void sse_encode_retention_policy(RetentionPolicy self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+@protected void sse_encode_server_info(ServerInfo self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_box_autoadd_nats_config(
-      NatsConfig self, SseSerializer serializer);
+@protected void sse_encode_service_error(ServiceError self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_box_autoadd_reconnection_config(
-      ReconnectionConfig self, SseSerializer serializer);
+@protected void sse_encode_service_info(ServiceInfo self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+@protected void sse_encode_service_ping(ServicePing self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+@protected void sse_encode_service_request(ServiceRequest self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_isize(PlatformInt64 self, SseSerializer serializer);
+@protected void sse_encode_service_response(ServiceResponse self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+@protected void sse_encode_service_stats(ServiceStats self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-      Uint8List self, SseSerializer serializer);
+@protected void sse_encode_storage_type(StorageType self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_nats_config(NatsConfig self, SseSerializer serializer);
+@protected void sse_encode_stored_message(StoredMessage self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
+@protected void sse_encode_stream_config(StreamConfig self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_opt_box_autoadd_reconnection_config(
-      ReconnectionConfig? self, SseSerializer serializer);
+@protected void sse_encode_stream_info(StreamInfo self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+@protected void sse_encode_stream_state(StreamState self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+@protected void sse_encode_subject_permissions(SubjectPermissions self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_reconnection_config(
-      ReconnectionConfig self, SseSerializer serializer);
+@protected void sse_encode_u_16(int self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_u_16(int self, SseSerializer serializer);
+@protected void sse_encode_u_32(int self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_u_32(int self, SseSerializer serializer);
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+@protected void sse_encode_u_8(int self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
+@protected void sse_encode_unit(void self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer);
+@protected void sse_encode_x_key_pair(XKeyPair self, SseSerializer serializer);
+                }
+                
 
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
-}
 
 // Section: wire_class
 
 class RustLibWire implements BaseWire {
-  RustLibWire.fromExternalLibrary(ExternalLibrary lib);
-}
+            RustLibWire.fromExternalLibrary(ExternalLibrary lib);
 
-@JS('wasm_bindgen')
-external RustLibWasmModule get wasmModule;
+            
+        }
+        @JS('wasm_bindgen') external RustLibWasmModule get wasmModule;
 
-@JS()
-@anonymous
-extension type RustLibWasmModule._(JSObject _) implements JSObject {}
+        @JS() @anonymous extension type RustLibWasmModule._(JSObject _) implements JSObject {
+            
+        }
+        
\ No newline at end of file
@@ -0,0 +1,416 @@
+use crate::api::error::{NatsError, NatsErrorKind};
+use crate::api::nats::{
+    activate_subscription, check_payload_size, cleanup_subscription, compress_and_seal, get_client,
+    get_kv_store, get_kv_value, headers_to_map, is_subscription_active, put_kv_value, read_payload,
+};
+use crate::api::subject::{check_kv_key, check_subject};
+use crate::frb_generated::StreamSink;
+use async_nats::HeaderMap;
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio_stream::StreamExt;
+
+/// How a payload is encoded on the wire.
+///
+/// `Json`, `MessagePack` and `Cbor` take and return JSON text, transcoding it as needed.
+/// `Text` and `Protobuf` pass the payload bytes through unchanged.
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PayloadCodec {
+    Text,
+    Json,
+    MessagePack,
+    Cbor,
+    Protobuf,
+}
+
+/// A payload decoded according to its codec.
+///
+/// `json` holds the JSON text of structured payloads, and `bytes` the payload as received.
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug)]
+pub struct DecodedPayload {
+    pub codec: PayloadCodec,
+    pub json: Option<String>,
+    pub bytes: Vec<u8>,
+}
+
+/// A message whose payload was decoded using its `Content-Type` header.
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug)]
+pub struct DecodedMessage {
+    pub subject: String,
+    pub reply: Option<String>,
+    pub headers: HashMap<String, Vec<String>>,
+    pub payload: DecodedPayload,
+}
+
+impl PayloadCodec {
+    pub(crate) fn content_type(self) -> &'static str {
+        match self {
+            PayloadCodec::Text => "text/plain",
+            PayloadCodec::Json => "application/json",
+            PayloadCodec::MessagePack => "application/msgpack",
+            PayloadCodec::Cbor => "application/cbor",
+            PayloadCodec::Protobuf => "application/protobuf",
+        }
+    }
+
+    pub(crate) fn from_content_type(content_type: &str) -> Option<Self> {
+        // Ignore parameters such as `; charset=utf-8`
+        let mime = content_type.split(';').next()?.trim().to_ascii_lowercase();
+        match mime.as_str() {
+            "text/plain" => Some(PayloadCodec::Text),
+            "application/json" => Some(PayloadCodec::Json),
+            "application/msgpack" | "application/x-msgpack" | "application/vnd.msgpack" => {
+                Some(PayloadCodec::MessagePack)
+            }
+            "application/cbor" => Some(PayloadCodec::Cbor),
+            "application/protobuf"
+            | "application/x-protobuf"
+            | "application/vnd.google.protobuf" => Some(PayloadCodec::Protobuf),
+            _ => None,
+        }
+    }
+}
+
+/// Helper function to parse JSON text passed from Dart
+fn parse_json(payload: &[u8]) -> Result<serde_json::Value, String> {
+    serde_json::from_slice(payload).map_err(|e| format!("Invalid JSON payload: {}", e))
+}
+
+/// Encodes a payload passed from Dart with a codec.
+pub(crate) fn encode_payload(codec: PayloadCodec, payload: &[u8]) -> Result<Vec<u8>, String> {
+    match codec {
+        PayloadCodec::Text | PayloadCodec::Protobuf => Ok(payload.to_vec()),
+        PayloadCodec::Json => {
+            // Validate, but keep the JSON text as written
+            parse_json(payload)?;
+            Ok(payload.to_vec())
+        }
+        PayloadCodec::MessagePack => rmp_serde::to_vec_named(&parse_json(payload)?)
+            .map_err(|e| format!("Failed to encode MessagePack: {}", e)),
+        PayloadCodec::Cbor => {
+            let mut encoded = Vec::new();
+            ciborium::into_writer(&parse_json(payload)?, &mut encoded)
+                .map_err(|e| format!("Failed to encode CBOR: {}", e))?;
+            Ok(encoded)
+        }
+    }
+}
+
+/// Decodes a received payload with a codec.
+pub(crate) fn decode_payload(
+    codec: PayloadCodec,
+    payload: &[u8],
+) -> Result<DecodedPayload, String> {
+    let json = match codec {
+        PayloadCodec::Text | PayloadCodec::Protobuf => None,
+        PayloadCodec::Json => Some(parse_json(payload)?),
+        PayloadCodec::MessagePack => Some(
+            rmp_serde::from_slice(payload)
+                .map_err(|e| format!("Failed to decode MessagePack: {}", e))?,
+        ),
+        PayloadCodec::Cbor => Some(
+            ciborium::from_reader(payload).map_err(|e| format!("Failed to decode CBOR: {}", e))?,
+        ),
+    };
+
+    Ok(DecodedPayload {
+        codec,
+        json: json.map(|value: serde_json::Value| value.to_string()),
+        bytes: payload.to_vec(),
+    })
+}
+
+/// Helper function to encode, compress and encrypt a payload, with headers announcing each step
+async fn encode_for_client(
+    client_id: &str,
+    subject: &str,
+    codec: PayloadCodec,
+    payload: &[u8],
+) -> Result<(Vec<u8>, HeaderMap), NatsError> {
+    let (encoded, headers) =
+        compress_and_seal(client_id, subject, encode_payload(codec, payload)?).await?;
+
+    let mut headers = headers.unwrap_or_default();
+    headers.insert("Content-Type", codec.content_type());
+    check_payload_size(client_id, encoded.len(), Some(&headers)).await?;
+    Ok((encoded, headers))
+}
+
+/// Helper function to get the codec named by a `Content-Type` header
+fn header_codec(headers: Option<&HeaderMap>) -> Option<PayloadCodec> {
+    headers
+        .and_then(|headers| headers.get("Content-Type"))
+        .and_then(|value| PayloadCodec::from_content_type(value.as_str()))
+}
+
+/// Helper function to decode a message, using its `Content-Type` header when present
+async fn decode_message(
+    client_id: &str,
+    message: &async_nats::Message,
+    default_codec: PayloadCodec,
+) -> Result<DecodedMessage, String> {
+    let codec = header_codec(message.headers.as_ref()).unwrap_or(default_codec);
+
+    Ok(DecodedMessage {
+        subject: message.subject.to_string(),
+        reply: message.reply.as_ref().map(|reply| reply.to_string()),
+        headers: message
+            .headers
+            .as_ref()
+            .map(headers_to_map)
+            .unwrap_or_default(),
+        payload: decode_payload(
+            codec,
+            &read_payload(client_id, message.headers.as_ref(), &message.payload).await?,
+        )?,
+    })
+}
+
+/// Publishes a payload encoded with `codec`, setting its `Content-Type` header.
+#[flutter_rust_bridge::frb]
+pub async fn publish_encoded(
+    client_id: String,
+    subject: String,
+    payload: Vec<u8>,
+    codec: PayloadCodec,
+) -> Result<(), NatsError> {
+    check_subject(&subject, false)?;
+
+    let client = get_client(&client_id)
+        .await
+        .map_err(|e| NatsError::new(NatsErrorKind::ClientNotFound, e))?;
+    let (encoded, headers) = encode_for_client(&client_id, &subject, codec, &payload).await?;
+
+    client
+        .publish_with_headers(subject, headers, encoded.into())
+        .await
+        .map_err(|e| NatsError::new(NatsErrorKind::Other, format!("Failed to publish: {}", e)))
+}
+
+/// Sends a request encoded with `codec` and decodes the response by its `Content-Type`,
+/// falling back to the request's codec.
+#[flutter_rust_bridge::frb]
+pub async fn request_encoded(
+    client_id: String,
+    subject: String,
+    payload: Vec<u8>,
+    codec: PayloadCodec,
+    timeout_ms: u64,
+) -> Result<DecodedMessage, NatsError> {
+    check_subject(&subject, false)?;
+
+    let client = get_client(&client_id)
+        .await
+        .map_err(|e| NatsError::new(NatsErrorKind::ClientNotFound, e))?;
+    let (encoded, headers) = encode_for_client(&client_id, &subject, codec, &payload).await?;
+
+    let request = client.request_with_headers(subject, headers, encoded.into());
+    let response = tokio::time::timeout(Duration::from_millis(timeout_ms), request)
+        .await
+        .map_err(|_| NatsError::new(NatsErrorKind::TimedOut, "Request timed out"))??;
+
+    decode_message(&client_id, &response, codec)
+        .await
+        .map_err(NatsError::from)
+}
+
+/// Streams messages decoded by their `Content-Type` to Dart until stopped with `unsubscribe`.
+///
+/// Messages without a recognised `Content-Type` are decoded with `default_codec`.
+#[flutter_rust_bridge::frb]
+pub async fn subscribe_decoded(
+    client_id: String,
+    subject: String,
+    default_codec: PayloadCodec,
+    subscription_id: String,
+    sink: StreamSink<DecodedMessage>,
+) -> Result<(), String> {
+    check_subject(&subject, true)?;
+
+    let client = get_client(&client_id).await?;
+    let mut subscriber = client
+        .subscribe(subject.clone())
+        .await
+        .map_err(|e| format!("Failed to subscribe to '{}': {}", subject, e))?;
+
+    // Register the stream so it can be listed and stopped like a subscription
+    let sub_key = (client_id.clone(), subscription_id.clone());
+    if !activate_subscription(&sub_key).await {
+        return Err(format!(
+            "Subscription '{}' for client '{}' already exists",
+            subscription_id, client_id
+        ));
+    }
+
+    while is_subscription_active(&sub_key).await {
+        // Wait for the next message with a small timeout so stops are noticed
+        let message =
+            match tokio::time::timeout(Duration::from_millis(100), subscriber.next()).await {
+                Ok(Some(message)) => message,
+                Ok(None) => break,
+                Err(_) => continue,
+            };
+
+        let sent = match decode_message(&client_id, &message, default_codec).await {
+            Ok(message) => sink.add(message),
+            Err(e) => sink.add_error(e),
+        };
+
+        // The Dart side stopped listening
+        if sent.is_err() {
+            break;
+        }
+    }
+
+    let _ = subscriber.unsubscribe().await;
+    cleanup_subscription(&sub_key).await;
+    Ok(())
+}
+
+/// Puts a value encoded with `codec` in a KV bucket, setting its `Content-Type` header, and
+/// returns its revision.
+///
+/// The value is compressed and encrypted like other KV values. Buckets reached through a
+/// JetStream domain store no headers, so their values are read with the codec passed to
+/// `kv_get_decoded`.
+#[flutter_rust_bridge::frb]
+pub async fn kv_put_encoded(
+    client_id: String,
+    bucket: String,
+    key: String,
+    payload: Vec<u8>,
+    codec: PayloadCodec,
+) -> Result<u64, NatsError> {
+    check_kv_key(&key)?;
+
+    let store = get_kv_store(&client_id, &bucket).await?;
+    let encoded = encode_payload(codec, &payload)?;
+
+    put_kv_value(
+        &client_id,
+        &store,
+        &key,
+        encoded,
+        &[("Content-Type", codec.content_type())],
+        None,
+    )
+    .await
+}
+
+/// Gets a value from a KV bucket decoded by its `Content-Type`, falling back to `codec`, or
+/// `None` if the key has no value.
+#[flutter_rust_bridge::frb]
+pub async fn kv_get_decoded(
+    client_id: String,
+    bucket: String,
+    key: String,
+    codec: PayloadCodec,
+) -> Result<Option<DecodedPayload>, NatsError> {
+    check_kv_key(&key)?;
+
+    let store = get_kv_store(&client_id, &bucket).await?;
+
+    let Some((value, headers)) = get_kv_value(&client_id, &store, &key)
+        .await
+        .map_err(|e| NatsError::new(NatsErrorKind::Other, format!("Failed to get value: {}", e)))?
+    else {
+        return Ok(None);
+    };
+
+    let codec = header_codec(Some(&headers)).unwrap_or(codec);
+    decode_payload(codec, &value)
+        .map(Some)
+        .map_err(NatsError::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const JSON: &str = r#"{"name":"sensor","values":[1,2.5,null],"active":true}"#;
+
+    fn round_trip(codec: PayloadCodec) -> serde_json::Value {
+        let encoded = encode_payload(codec, JSON.as_bytes()).unwrap();
+        let decoded = decode_payload(codec, &encoded).unwrap();
+        assert_eq!(decoded.codec, codec);
+        assert_eq!(decoded.bytes, encoded);
+        serde_json::from_str(&decoded.json.unwrap()).unwrap()
+    }
+
+    #[test]
+    fn structured_codecs_round_trip_json() {
+        let expected: serde_json::Value = serde_json::from_str(JSON).unwrap();
+        for codec in [
+            PayloadCodec::Json,
+            PayloadCodec::MessagePack,
+            PayloadCodec::Cbor,
+        ] {
+            assert_eq!(round_trip(codec), expected, "{:?}", codec);
+        }
+    }
+
+    #[test]
+    fn json_is_kept_as_written() {
+        let payload = br#"{ "b": 1, "a": 2 }"#;
+        assert_eq!(
+            encode_payload(PayloadCodec::Json, payload).unwrap(),
+            payload.to_vec()
+        );
+    }
+
+    #[test]
+    fn binary_codecs_pass_bytes_through() {
+        let payload = [0u8, 159, 146, 150];
+        for codec in [PayloadCodec::Text, PayloadCodec::Protobuf] {
+            assert_eq!(encode_payload(codec, &payload).unwrap(), payload.to_vec());
+            let decoded = decode_payload(codec, &payload).unwrap();
+            assert_eq!(decoded.json, None);
+            assert_eq!(decoded.bytes, payload.to_vec());
+        }
+    }
+
+    #[test]
+    fn invalid_payloads_are_rejected() {
+        assert!(encode_payload(PayloadCodec::Json, b"not json").is_err());
+        assert!(encode_payload(PayloadCodec::MessagePack, b"{").is_err());
+        assert!(decode_payload(PayloadCodec::Json, b"{").is_err());
+        assert!(decode_payload(PayloadCodec::Cbor, &[0xff, 0x00]).is_err());
+    }
+
+    #[test]
+    fn content_types_map_to_codecs() {
+        for codec in [
+            PayloadCodec::Text,
+            PayloadCodec::Json,
+            PayloadCodec::MessagePack,
+            PayloadCodec::Cbor,
+            PayloadCodec::Protobuf,
+        ] {
+            assert_eq!(
+                PayloadCodec::from_content_type(codec.content_type()),
+                Some(codec)
+            );
+        }
+        assert_eq!(
+            PayloadCodec::from_content_type("Application/JSON; charset=utf-8"),
+            Some(PayloadCodec::Json)
+        );
+        assert_eq!(
+            PayloadCodec::from_content_type("application/x-msgpack"),
+            Some(PayloadCodec::MessagePack)
+        );
+        assert_eq!(PayloadCodec::from_content_type("image/png"), None);
+    }
+
+    #[test]
+    fn content_type_header_selects_codec() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(header_codec(Some(&headers)), None);
+        headers.insert("Content-Type", "application/cbor");
+        assert_eq!(header_codec(Some(&headers)), Some(PayloadCodec::Cbor));
+        assert_eq!(header_codec(None), None);
+    }
+}
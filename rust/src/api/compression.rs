@@ -0,0 +1,260 @@
+use crate::api::nats::{get_client, ClientId, Registry};
+use async_nats::HeaderMap;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompressionAlgorithm {
+    Gzip,
+    Zstd,
+    Lz4,
+}
+
+/// Compression applied to the payloads a client publishes, requests and puts in KV buckets.
+///
+/// Payloads smaller than `min_size` bytes are sent uncompressed.
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug)]
+pub struct CompressionConfig {
+    pub algorithm: CompressionAlgorithm,
+    pub min_size: u64,
+}
+
+// Store the compression settings of each client that enabled compression
+static COMPRESSION: Registry<ClientId, CompressionConfig> =
+    Lazy::new(|| Arc::new(RwLock::new(HashMap::new())));
+
+// Store the decompressed size limit of each client that changed the default
+static DECOMPRESSION_LIMITS: Registry<ClientId, u64> =
+    Lazy::new(|| Arc::new(RwLock::new(HashMap::new())));
+
+impl CompressionAlgorithm {
+    fn content_encoding(self) -> &'static str {
+        match self {
+            CompressionAlgorithm::Gzip => "gzip",
+            CompressionAlgorithm::Zstd => "zstd",
+            CompressionAlgorithm::Lz4 => "lz4",
+        }
+    }
+
+    fn from_content_encoding(content_encoding: &str) -> Option<Self> {
+        match content_encoding.trim().to_ascii_lowercase().as_str() {
+            "gzip" => Some(CompressionAlgorithm::Gzip),
+            "zstd" => Some(CompressionAlgorithm::Zstd),
+            "lz4" => Some(CompressionAlgorithm::Lz4),
+            _ => None,
+        }
+    }
+}
+
+/// Helper function to compress a payload
+fn compress(algorithm: CompressionAlgorithm, payload: &[u8]) -> Result<Vec<u8>, String> {
+    let compressed = match algorithm {
+        CompressionAlgorithm::Gzip => {
+            let mut encoder =
+                flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+            encoder.write_all(payload).and_then(|_| encoder.finish())
+        }
+        CompressionAlgorithm::Zstd => zstd::encode_all(payload, 0),
+        CompressionAlgorithm::Lz4 => {
+            let mut encoder = lz4_flex::frame::FrameEncoder::new(Vec::new());
+            encoder
+                .write_all(payload)
+                .and_then(|_| encoder.finish().map_err(std::io::Error::other))
+        }
+    };
+
+    compressed.map_err(|e| format!("Failed to compress payload: {}", e))
+}
+
+/// Helper function to read a decompressed payload, stopping one byte past `limit`
+fn read_limited(reader: impl Read, limit: u64) -> std::io::Result<Vec<u8>> {
+    let mut decompressed = Vec::new();
+    reader
+        .take(limit.saturating_add(1))
+        .read_to_end(&mut decompressed)?;
+    Ok(decompressed)
+}
+
+/// Helper function to decompress a payload, failing if it decompresses to more than `limit` bytes
+fn decompress(
+    algorithm: CompressionAlgorithm,
+    payload: &[u8],
+    limit: u64,
+) -> Result<Vec<u8>, String> {
+    let result = match algorithm {
+        CompressionAlgorithm::Gzip => read_limited(flate2::read::GzDecoder::new(payload), limit),
+        CompressionAlgorithm::Zstd => zstd::stream::read::Decoder::new(payload)
+            .and_then(|decoder| read_limited(decoder, limit)),
+        CompressionAlgorithm::Lz4 => {
+            read_limited(lz4_flex::frame::FrameDecoder::new(payload), limit)
+        }
+    };
+
+    let decompressed = result.map_err(|e| format!("Failed to decompress payload: {}", e))?;
+    if decompressed.len() as u64 > limit {
+        return Err(format!(
+            "Decompressed payload exceeds the limit of {} bytes",
+            limit
+        ));
+    }
+    Ok(decompressed)
+}
+
+/// Enables compression for a client, or disables it when `config` is `None`.
+///
+/// Received payloads carrying a `Content-Encoding` header are always decompressed.
+#[flutter_rust_bridge::frb]
+pub async fn set_compression(
+    client_id: String,
+    config: Option<CompressionConfig>,
+) -> Result<(), String> {
+    get_client(&client_id).await?;
+
+    let mut settings = COMPRESSION.write().await;
+    match config {
+        Some(config) => settings.insert(client_id, config),
+        None => settings.remove(&client_id),
+    };
+    Ok(())
+}
+
+/// Sets the largest size in bytes a received payload may decompress to, or restores the
+/// default of 64 MiB when `limit` is `None`.
+///
+/// Larger payloads are rejected instead of being decompressed, so a small compressed payload
+/// cannot exhaust the app's memory.
+#[flutter_rust_bridge::frb]
+pub async fn set_decompression_limit(client_id: String, limit: Option<u64>) -> Result<(), String> {
+    get_client(&client_id).await?;
+
+    let mut limits = DECOMPRESSION_LIMITS.write().await;
+    match limit {
+        Some(limit) => limits.insert(client_id, limit),
+        None => limits.remove(&client_id),
+    };
+    Ok(())
+}
+
+/// Compresses an outgoing payload with the client's settings.
+///
+/// Returns the payload to send and, when it was compressed, the headers announcing it.
+pub(crate) async fn compress_for_client(
+    client_id: &str,
+    payload: Vec<u8>,
+) -> Result<(Vec<u8>, Option<HeaderMap>), String> {
+    let config = COMPRESSION.read().await.get(client_id).cloned();
+    let Some(config) = config else {
+        return Ok((payload, None));
+    };
+    if (payload.len() as u64) < config.min_size {
+        return Ok((payload, None));
+    }
+
+    let compressed = compress(config.algorithm, &payload)?;
+    let mut headers = HeaderMap::new();
+    headers.insert("Content-Encoding", config.algorithm.content_encoding());
+    Ok((compressed, Some(headers)))
+}
+
+/// Decompresses a received payload according to its `Content-Encoding` header, within the
+/// client's decompression limit.
+pub(crate) async fn decompress_payload(
+    client_id: &str,
+    headers: Option<&HeaderMap>,
+    payload: &[u8],
+) -> Result<Vec<u8>, String> {
+    let encoding = headers.and_then(|headers| headers.get("Content-Encoding"));
+    let Some(encoding) = encoding else {
+        return Ok(payload.to_vec());
+    };
+
+    // Unless the client set its own limit, accept payloads of up to 64 MiB
+    let limit = DECOMPRESSION_LIMITS
+        .read()
+        .await
+        .get(client_id)
+        .copied()
+        .unwrap_or(64 * 1024 * 1024);
+    match CompressionAlgorithm::from_content_encoding(encoding.as_str()) {
+        Some(algorithm) => decompress(algorithm, payload, limit),
+        None => Err(format!(
+            "Unsupported Content-Encoding '{}'",
+            encoding.as_str()
+        )),
+    }
+}
+
+/// Stops compressing payloads of a disconnected client.
+pub(crate) async fn cleanup_client_compression(client_id: &str) {
+    COMPRESSION.write().await.remove(client_id);
+    DECOMPRESSION_LIMITS.write().await.remove(client_id);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALGORITHMS: [CompressionAlgorithm; 3] = [
+        CompressionAlgorithm::Gzip,
+        CompressionAlgorithm::Zstd,
+        CompressionAlgorithm::Lz4,
+    ];
+
+    #[test]
+    fn payloads_round_trip() {
+        let payload = "hello nats ".repeat(100).into_bytes();
+        for algorithm in ALGORITHMS {
+            let compressed = compress(algorithm, &payload).unwrap();
+            assert!(compressed.len() < payload.len(), "{:?}", algorithm);
+            assert_eq!(
+                decompress(algorithm, &compressed, payload.len() as u64).unwrap(),
+                payload
+            );
+        }
+    }
+
+    #[test]
+    fn empty_payloads_round_trip() {
+        for algorithm in ALGORITHMS {
+            let compressed = compress(algorithm, &[]).unwrap();
+            assert!(decompress(algorithm, &compressed, 0).unwrap().is_empty());
+        }
+    }
+
+    #[test]
+    fn payloads_over_the_limit_are_rejected() {
+        let payload = vec![0u8; 1024 * 1024];
+        for algorithm in ALGORITHMS {
+            let compressed = compress(algorithm, &payload).unwrap();
+            let error = decompress(algorithm, &compressed, 1024).unwrap_err();
+            assert!(error.contains("exceeds the limit"), "{}", error);
+        }
+    }
+
+    #[test]
+    fn invalid_payloads_are_rejected() {
+        for algorithm in ALGORITHMS {
+            assert!(decompress(algorithm, b"not compressed", 1024).is_err());
+        }
+    }
+
+    #[test]
+    fn content_encodings_map_to_algorithms() {
+        for algorithm in ALGORITHMS {
+            assert_eq!(
+                CompressionAlgorithm::from_content_encoding(algorithm.content_encoding()),
+                Some(algorithm)
+            );
+        }
+        assert_eq!(
+            CompressionAlgorithm::from_content_encoding(" GZIP "),
+            Some(CompressionAlgorithm::Gzip)
+        );
+        assert_eq!(CompressionAlgorithm::from_content_encoding("br"), None);
+    }
+}
@@ -0,0 +1,799 @@
+use crate::api::jetstream::{from_unix_millis, unix_millis};
+use crate::api::nats::{
+    activate_subscription, cleanup_subscription, get_jetstream, is_subscription_active,
+    read_payload, ClientId, SubscriptionId,
+};
+use crate::frb_generated::StreamSink;
+use async_nats::jetstream::consumer::{self, PullConsumer};
+use async_nats::jetstream::message::{AckKind, Acker};
+use async_nats::jetstream::response::Response;
+use flutter_rust_bridge::DartFnFuture;
+use serde::Deserialize;
+use serde_json::json;
+use std::time::Duration;
+use tokio_stream::{Stream, StreamExt};
+
+/// Where a consumer starts delivering messages from.
+///
+/// `ByStartSequence` and `ByStartTime` need the matching start sequence or start time.
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Copy, Debug)]
+pub enum DeliverPolicy {
+    All,
+    Last,
+    New,
+    LastPerSubject,
+    ByStartSequence,
+    ByStartTime,
+}
+
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Copy, Debug)]
+pub enum AckPolicy {
+    Explicit,
+    None,
+    All,
+}
+
+/// Configuration of a pull consumer.
+///
+/// Consumers without a `durable_name` are ephemeral and removed by the server once idle.
+/// Options left as `None` use the server defaults.
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug)]
+pub struct ConsumerConfig {
+    pub durable_name: Option<String>,
+    pub description: Option<String>,
+    pub filter_subjects: Vec<String>,
+    pub ack_policy: AckPolicy,
+    pub ack_wait_ms: Option<u64>,
+    pub max_deliver: Option<i64>,
+    pub backoff_ms: Vec<u64>,
+    pub max_ack_pending: Option<i64>,
+    pub inactive_threshold_ms: Option<u64>,
+}
+
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug)]
+pub struct ConsumerInfo {
+    pub stream: String,
+    pub name: String,
+    pub created_ms: i64,
+    pub config: ConsumerConfig,
+    pub delivered_stream_sequence: u64,
+    pub delivered_consumer_sequence: u64,
+    pub ack_floor_stream_sequence: u64,
+    pub ack_floor_consumer_sequence: u64,
+    pub num_ack_pending: u64,
+    pub num_redelivered: u64,
+    pub num_waiting: u64,
+    pub num_pending: u64,
+}
+
+/// The pause state of a consumer as reported by the server.
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug)]
+pub struct ConsumerPauseState {
+    pub paused: bool,
+    pub pause_until_ms: Option<i64>,
+    pub pause_remaining_ms: Option<u64>,
+}
+
+/// The raw pause state returned by the `CONSUMER.PAUSE` API
+#[derive(Deserialize)]
+struct PauseResponse {
+    paused: bool,
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pause_until: Option<time::OffsetDateTime>,
+    #[serde(default)]
+    pause_remaining: Option<u64>,
+}
+
+/// A message delivered by a JetStream consumer together with its delivery metadata.
+///
+/// The `handle` identifies this delivery when acknowledging it with `js_ack` and friends.
+/// `payload` is the text of `bytes`, with invalid UTF-8 sequences replaced. Payloads are
+/// decrypted and decompressed according to their headers; if that fails, `decode_error` says
+/// why and `bytes` holds the payload as received.
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug)]
+pub struct JsMessage {
+    pub handle: String,
+    pub subject: String,
+    pub payload: String,
+    pub bytes: Vec<u8>,
+    pub decode_error: Option<String>,
+    pub stream: String,
+    pub consumer: String,
+    pub stream_sequence: u64,
+    pub consumer_sequence: u64,
+    pub delivered: u64,
+    pub pending: u64,
+    pub timestamp_ms: i64,
+}
+
+/// Helper function to build a deliver policy together with its start position
+fn to_deliver_policy(
+    policy: DeliverPolicy,
+    start_sequence: Option<u64>,
+    start_time_ms: Option<i64>,
+) -> Result<consumer::DeliverPolicy, String> {
+    match policy {
+        DeliverPolicy::All => Ok(consumer::DeliverPolicy::All),
+        DeliverPolicy::Last => Ok(consumer::DeliverPolicy::Last),
+        DeliverPolicy::New => Ok(consumer::DeliverPolicy::New),
+        DeliverPolicy::LastPerSubject => Ok(consumer::DeliverPolicy::LastPerSubject),
+        DeliverPolicy::ByStartSequence => start_sequence
+            .map(|start_sequence| consumer::DeliverPolicy::ByStartSequence { start_sequence })
+            .ok_or_else(|| "Deliver policy by start sequence needs a start sequence".to_string()),
+        DeliverPolicy::ByStartTime => {
+            let start_time_ms = start_time_ms
+                .ok_or_else(|| "Deliver policy by start time needs a start time".to_string())?;
+            Ok(consumer::DeliverPolicy::ByStartTime {
+                start_time: from_unix_millis(start_time_ms)?,
+            })
+        }
+    }
+}
+
+impl From<AckPolicy> for consumer::AckPolicy {
+    fn from(policy: AckPolicy) -> Self {
+        match policy {
+            AckPolicy::Explicit => consumer::AckPolicy::Explicit,
+            AckPolicy::None => consumer::AckPolicy::None,
+            AckPolicy::All => consumer::AckPolicy::All,
+        }
+    }
+}
+
+impl From<consumer::AckPolicy> for AckPolicy {
+    fn from(policy: consumer::AckPolicy) -> Self {
+        match policy {
+            consumer::AckPolicy::Explicit => AckPolicy::Explicit,
+            consumer::AckPolicy::None => AckPolicy::None,
+            consumer::AckPolicy::All => AckPolicy::All,
+        }
+    }
+}
+
+impl From<ConsumerConfig> for consumer::pull::Config {
+    fn from(config: ConsumerConfig) -> Self {
+        let defaults = consumer::pull::Config::default();
+        consumer::pull::Config {
+            durable_name: config.durable_name,
+            description: config.description,
+            filter_subjects: config.filter_subjects,
+            ack_policy: config.ack_policy.into(),
+            ack_wait: config
+                .ack_wait_ms
+                .map(Duration::from_millis)
+                .unwrap_or(defaults.ack_wait),
+            max_deliver: config.max_deliver.unwrap_or(defaults.max_deliver),
+            backoff: config
+                .backoff_ms
+                .into_iter()
+                .map(Duration::from_millis)
+                .collect(),
+            max_ack_pending: config.max_ack_pending.unwrap_or(defaults.max_ack_pending),
+            inactive_threshold: config
+                .inactive_threshold_ms
+                .map(Duration::from_millis)
+                .unwrap_or(defaults.inactive_threshold),
+            ..defaults
+        }
+    }
+}
+
+/// Helper function to apply the exposed fields of a consumer configuration onto the current one,
+/// keeping the fields that are not exposed and the options left as `None`
+fn overlay_consumer_config(config: ConsumerConfig, current: consumer::Config) -> consumer::Config {
+    // A single filter subject is sent on its own, as a consumer may not use both forms
+    let (filter_subject, filter_subjects) = match config.filter_subjects.as_slice() {
+        [subject] => (subject.clone(), Vec::new()),
+        _ => (String::new(), config.filter_subjects),
+    };
+
+    consumer::Config {
+        durable_name: config.durable_name,
+        description: config.description,
+        filter_subject,
+        filter_subjects,
+        ack_policy: config.ack_policy.into(),
+        ack_wait: config
+            .ack_wait_ms
+            .map(Duration::from_millis)
+            .unwrap_or(current.ack_wait),
+        max_deliver: config.max_deliver.unwrap_or(current.max_deliver),
+        backoff: config
+            .backoff_ms
+            .into_iter()
+            .map(Duration::from_millis)
+            .collect(),
+        max_ack_pending: config.max_ack_pending.unwrap_or(current.max_ack_pending),
+        inactive_threshold: config
+            .inactive_threshold_ms
+            .map(Duration::from_millis)
+            .unwrap_or(current.inactive_threshold),
+        ..current
+    }
+}
+
+impl From<consumer::Config> for ConsumerConfig {
+    fn from(config: consumer::Config) -> Self {
+        // A single filter subject is reported separately from the list
+        let mut filter_subjects = config.filter_subjects;
+        if !config.filter_subject.is_empty() {
+            filter_subjects.push(config.filter_subject);
+        }
+        ConsumerConfig {
+            durable_name: config.durable_name,
+            description: config.description,
+            filter_subjects,
+            ack_policy: config.ack_policy.into(),
+            ack_wait_ms: Some(config.ack_wait.as_millis() as u64),
+            max_deliver: Some(config.max_deliver),
+            backoff_ms: config
+                .backoff
+                .iter()
+                .map(|delay| delay.as_millis() as u64)
+                .collect(),
+            max_ack_pending: Some(config.max_ack_pending),
+            inactive_threshold_ms: Some(config.inactive_threshold.as_millis() as u64),
+        }
+    }
+}
+
+impl From<consumer::Info> for ConsumerInfo {
+    fn from(info: consumer::Info) -> Self {
+        ConsumerInfo {
+            stream: info.stream_name,
+            name: info.name,
+            created_ms: unix_millis(info.created),
+            config: info.config.into(),
+            delivered_stream_sequence: info.delivered.stream_sequence,
+            delivered_consumer_sequence: info.delivered.consumer_sequence,
+            ack_floor_stream_sequence: info.ack_floor.stream_sequence,
+            ack_floor_consumer_sequence: info.ack_floor.consumer_sequence,
+            num_ack_pending: info.num_ack_pending as u64,
+            num_redelivered: info.num_redelivered as u64,
+            num_waiting: info.num_waiting as u64,
+            num_pending: info.num_pending,
+        }
+    }
+}
+
+/// Helper function to convert a delivered JetStream message, decrypting and decompressing its payload
+async fn to_js_message(
+    client_id: &str,
+    message: &async_nats::jetstream::Message,
+) -> Result<JsMessage, String> {
+    let info = message
+        .info()
+        .map_err(|e| format!("Invalid JetStream metadata: {}", e))?;
+
+    // A payload that cannot be decoded is still delivered, so it can be acknowledged
+    let (bytes, decode_error) =
+        match read_payload(client_id, message.headers.as_ref(), &message.payload).await {
+            Ok(bytes) => (bytes, None),
+            Err(e) => (message.payload.to_vec(), Some(e)),
+        };
+
+    Ok(JsMessage {
+        handle: message
+            .reply
+            .as_ref()
+            .map(|reply| reply.to_string())
+            .unwrap_or_default(),
+        subject: message.subject.to_string(),
+        payload: String::from_utf8_lossy(&bytes).into_owned(),
+        bytes,
+        decode_error,
+        stream: info.stream.to_string(),
+        consumer: info.consumer.to_string(),
+        stream_sequence: info.stream_sequence,
+        consumer_sequence: info.consumer_sequence,
+        delivered: info.delivered as u64,
+        pending: info.pending,
+        timestamp_ms: unix_millis(info.published),
+    })
+}
+
+/// Helper function to look up a pull consumer on a stream
+async fn get_pull_consumer(
+    client_id: &str,
+    stream: &str,
+    consumer: &str,
+) -> Result<PullConsumer, String> {
+    let (_, jetstream) = get_jetstream(client_id).await?;
+    jetstream
+        .get_consumer_from_stream(consumer, stream)
+        .await
+        .map_err(|e| format!("Failed to get consumer '{}': {}", consumer, e))
+}
+
+/// Creates a pull consumer on a stream, failing if one exists with a different configuration.
+#[flutter_rust_bridge::frb]
+pub async fn create_consumer(
+    client_id: String,
+    stream: String,
+    config: ConsumerConfig,
+) -> Result<ConsumerInfo, String> {
+    let (_, jetstream) = get_jetstream(&client_id).await?;
+
+    let consumer: PullConsumer = jetstream
+        .create_consumer_strict_on_stream(consumer::pull::Config::from(config), &stream)
+        .await
+        .map_err(|e| format!("Failed to create consumer: {}", e))?;
+
+    Ok(consumer.cached_info().clone().into())
+}
+
+/// Returns the configuration and delivery state of a consumer.
+#[flutter_rust_bridge::frb]
+pub async fn consumer_info(
+    client_id: String,
+    stream: String,
+    consumer: String,
+) -> Result<ConsumerInfo, String> {
+    let mut pull_consumer = get_pull_consumer(&client_id, &stream, &consumer).await?;
+    let info = pull_consumer
+        .info()
+        .await
+        .map_err(|e| format!("Failed to get consumer info: {}", e))?;
+
+    Ok(info.clone().into())
+}
+
+/// Updates the configuration of an existing durable consumer and returns its info.
+///
+/// Options left as `None` keep their current value, as do settings `ConsumerConfig` does not
+/// expose, such as the deliver policy.
+#[flutter_rust_bridge::frb]
+pub async fn update_consumer(
+    client_id: String,
+    stream: String,
+    config: ConsumerConfig,
+) -> Result<ConsumerInfo, String> {
+    let (_, jetstream) = get_jetstream(&client_id).await?;
+    let name = config
+        .durable_name
+        .clone()
+        .ok_or_else(|| "Updating a consumer requires its durable_name".to_string())?;
+    let current = get_pull_consumer(&client_id, &stream, &name)
+        .await?
+        .cached_info()
+        .config
+        .clone();
+
+    let consumer = jetstream
+        .update_consumer_on_stream(overlay_consumer_config(config, current), &stream)
+        .await
+        .map_err(|e| format!("Failed to update consumer: {}", e))?;
+
+    Ok(consumer.cached_info().clone().into())
+}
+
+/// Deletes a consumer from a stream.
+#[flutter_rust_bridge::frb]
+pub async fn delete_consumer(
+    client_id: String,
+    stream: String,
+    consumer: String,
+) -> Result<bool, String> {
+    let (_, jetstream) = get_jetstream(&client_id).await?;
+
+    let status = jetstream
+        .delete_consumer_from_stream(&consumer, &stream)
+        .await
+        .map_err(|e| format!("Failed to delete consumer '{}': {}", consumer, e))?;
+
+    Ok(status.success)
+}
+
+/// Returns the info of every consumer on a stream.
+#[flutter_rust_bridge::frb]
+pub async fn list_consumers(
+    client_id: String,
+    stream: String,
+) -> Result<Vec<ConsumerInfo>, String> {
+    let (_, jetstream) = get_jetstream(&client_id).await?;
+
+    let handle = jetstream
+        .get_stream_no_info(&stream)
+        .await
+        .map_err(|e| format!("Failed to get stream '{}': {}", stream, e))?;
+
+    let mut consumers = handle.consumers();
+    let mut infos = Vec::new();
+    while let Some(info) = consumers.next().await {
+        let info = info.map_err(|e| format!("Failed to list consumers: {}", e))?;
+        infos.push(info.into());
+    }
+
+    Ok(infos)
+}
+
+/// Helper function to send a pause request for a consumer
+async fn request_pause(
+    client_id: &str,
+    stream: &str,
+    consumer: &str,
+    payload: serde_json::Value,
+) -> Result<ConsumerPauseState, String> {
+    let (_, jetstream) = get_jetstream(client_id).await?;
+
+    let response: Response<PauseResponse> = jetstream
+        .request(format!("CONSUMER.PAUSE.{}.{}", stream, consumer), &payload)
+        .await
+        .map_err(|e| {
+            format!(
+                "Failed to change pause state of consumer '{}': {}",
+                consumer, e
+            )
+        })?;
+
+    match response {
+        Response::Ok(state) => Ok(ConsumerPauseState {
+            paused: state.paused,
+            pause_until_ms: state.pause_until.map(unix_millis),
+            pause_remaining_ms: state.pause_remaining.map(|nanos| nanos / 1_000_000),
+        }),
+        Response::Err { error } => Err(format!(
+            "Failed to change pause state of consumer '{}': {}",
+            consumer, error
+        )),
+    }
+}
+
+/// Pauses delivery of a consumer until the given time. Requires nats-server 2.11 or newer.
+#[flutter_rust_bridge::frb]
+pub async fn pause_consumer(
+    client_id: String,
+    stream: String,
+    consumer: String,
+    pause_until_ms: i64,
+) -> Result<ConsumerPauseState, String> {
+    let pause_until = from_unix_millis(pause_until_ms)?
+        .format(&time::format_description::well_known::Rfc3339)
+        .map_err(|e| format!("Invalid pause time: {}", e))?;
+
+    request_pause(
+        &client_id,
+        &stream,
+        &consumer,
+        json!({ "pause_until": pause_until }),
+    )
+    .await
+}
+
+/// Resumes delivery of a paused consumer. Requires nats-server 2.11 or newer.
+#[flutter_rust_bridge::frb]
+pub async fn resume_consumer(
+    client_id: String,
+    stream: String,
+    consumer: String,
+) -> Result<ConsumerPauseState, String> {
+    request_pause(&client_id, &stream, &consumer, json!({})).await
+}
+
+/// Fetches a batch of up to `batch` messages, waiting at most `expires_ms` for them to arrive.
+#[flutter_rust_bridge::frb]
+pub async fn consumer_fetch(
+    client_id: String,
+    stream: String,
+    consumer: String,
+    batch: u32,
+    expires_ms: u64,
+) -> Result<Vec<JsMessage>, String> {
+    let pull_consumer = get_pull_consumer(&client_id, &stream, &consumer).await?;
+
+    let mut messages = pull_consumer
+        .batch()
+        .max_messages(batch as usize)
+        .expires(Duration::from_millis(expires_ms))
+        .messages()
+        .await
+        .map_err(|e| format!("Failed to fetch messages: {}", e))?;
+
+    let mut result = Vec::new();
+    while let Some(message) = messages.next().await {
+        let message = message.map_err(|e| format!("Failed to fetch messages: {}", e))?;
+        result.push(to_js_message(&client_id, &message).await?);
+    }
+
+    Ok(result)
+}
+
+/// Returns the next message of a consumer, or `None` if none arrives within `timeout_ms`.
+#[flutter_rust_bridge::frb]
+pub async fn consumer_next(
+    client_id: String,
+    stream: String,
+    consumer: String,
+    timeout_ms: u64,
+) -> Result<Option<JsMessage>, String> {
+    let messages = consumer_fetch(client_id, stream, consumer, 1, timeout_ms).await?;
+    Ok(messages.into_iter().next())
+}
+
+/// Continuously delivers the messages of a consumer until it is stopped with `unsubscribe`.
+#[flutter_rust_bridge::frb]
+pub async fn consumer_messages(
+    client_id: String,
+    stream: String,
+    consumer: String,
+    subscription_id: String,
+    sink: StreamSink<JsMessage>,
+) -> Result<(), String> {
+    let pull_consumer = get_pull_consumer(&client_id, &stream, &consumer).await?;
+
+    // Register the stream so it can be listed and stopped like a subscription
+    let sub_key = (client_id.clone(), subscription_id.clone());
+    if !activate_subscription(&sub_key).await {
+        return Err(format!(
+            "Subscription '{}' for client '{}' already exists",
+            subscription_id, client_id
+        ));
+    }
+
+    match pull_consumer.messages().await {
+        Ok(messages) => {
+            forward_messages(&sub_key, messages, sink).await;
+            Ok(())
+        }
+        Err(e) => {
+            cleanup_subscription(&sub_key).await;
+            Err(format!("Failed to consume messages: {}", e))
+        }
+    }
+}
+
+/// Internal function to forward consumed messages to a Dart stream until stopped
+async fn forward_messages<E: std::fmt::Display>(
+    sub_key: &(ClientId, SubscriptionId),
+    mut messages: impl Stream<Item = Result<async_nats::jetstream::Message, E>> + Unpin,
+    sink: StreamSink<JsMessage>,
+) {
+    while is_subscription_active(sub_key).await {
+        // Wait for the next message with a small timeout so stops are noticed
+        let maybe_msg =
+            match tokio::time::timeout(Duration::from_millis(100), messages.next()).await {
+                Ok(Some(message)) => message,
+                Ok(None) => break,
+                Err(_) => continue,
+            };
+
+        let delivered = match maybe_msg {
+            Ok(message) => to_js_message(&sub_key.0, &message).await,
+            Err(e) => Err(format!("Failed to receive message: {}", e)),
+        };
+        let sent = match delivered {
+            Ok(message) => sink.add(message),
+            Err(e) => sink.add_error(e),
+        };
+
+        // The Dart side stopped listening
+        if sent.is_err() {
+            break;
+        }
+    }
+
+    cleanup_subscription(sub_key).await;
+}
+
+/// Delivers the messages of a consumer to a Dart handler until it is stopped with `unsubscribe`.
+///
+/// With `auto_ack` each message is acked once the handler returns `true`, and naked for
+/// prompt redelivery when it returns `false`.
+#[allow(clippy::too_many_arguments)]
+#[flutter_rust_bridge::frb]
+pub async fn consumer_subscribe(
+    client_id: String,
+    stream: String,
+    consumer: String,
+    subscription_id: String,
+    auto_ack: bool,
+    on_message: impl Fn(JsMessage) -> DartFnFuture<bool> + Send + 'static,
+    on_success: impl Fn(bool) -> DartFnFuture<()>,
+    on_error: impl Fn(String) -> DartFnFuture<()> + Send + 'static,
+    on_done: impl Fn() -> DartFnFuture<()> + Send + 'static,
+) {
+    let pull_consumer = match get_pull_consumer(&client_id, &stream, &consumer).await {
+        Ok(pull_consumer) => pull_consumer,
+        Err(e) => {
+            on_error(e).await;
+            return;
+        }
+    };
+
+    // Register the consumer so it can be listed and stopped like a subscription
+    let sub_key = (client_id.clone(), subscription_id.clone());
+    if !activate_subscription(&sub_key).await {
+        on_error(format!(
+            "Subscription '{}' for client '{}' already exists",
+            subscription_id, client_id
+        ))
+        .await;
+        return;
+    }
+
+    let messages = match pull_consumer.messages().await {
+        Ok(messages) => messages,
+        Err(e) => {
+            cleanup_subscription(&sub_key).await;
+            on_error(format!("Failed to consume messages: {}", e)).await;
+            return;
+        }
+    };
+
+    // Notify successful subscription
+    on_success(true).await;
+
+    // Spawn a task to handle this consumer
+    tokio::spawn(async move {
+        process_consumer_messages(sub_key, messages, auto_ack, on_message, on_error, on_done).await;
+    });
+}
+
+/// Internal function to process consumer messages
+async fn process_consumer_messages(
+    sub_key: (ClientId, SubscriptionId),
+    mut messages: consumer::pull::Stream,
+    auto_ack: bool,
+    on_message: impl Fn(JsMessage) -> DartFnFuture<bool>,
+    on_error: impl Fn(String) -> DartFnFuture<()>,
+    on_done: impl Fn() -> DartFnFuture<()>,
+) {
+    while is_subscription_active(&sub_key).await {
+        // Wait for the next message with a small timeout so stops are noticed
+        let message = match tokio::time::timeout(Duration::from_millis(100), messages.next()).await
+        {
+            Ok(Some(Ok(message))) => message,
+            Ok(Some(Err(e))) => {
+                on_error(format!("Failed to receive message: {}", e)).await;
+                continue;
+            }
+            Ok(None) => break,
+            Err(_) => continue,
+        };
+
+        let handled = match to_js_message(&sub_key.0, &message).await {
+            Ok(js_message) => on_message(js_message).await,
+            Err(e) => {
+                on_error(e).await;
+                false
+            }
+        };
+
+        if auto_ack {
+            let kind = if handled {
+                AckKind::Ack
+            } else {
+                AckKind::Nak(None)
+            };
+            if let Err(e) = message.ack_with(kind).await {
+                on_error(format!("Failed to acknowledge message: {}", e)).await;
+            }
+        }
+    }
+
+    // Consumer stopped, clean up
+    cleanup_subscription(&sub_key).await;
+
+    // Notify completion
+    on_done().await;
+}
+
+/// Helper function to create an acker for a delivered message handle
+async fn get_acker(client_id: &str, handle: String) -> Result<Acker, String> {
+    // Handles are the reply subjects JetStream expects acknowledgements on
+    if !handle.starts_with("$JS.ACK.") {
+        return Err(format!("Invalid message handle '{}'", handle));
+    }
+
+    let (_, jetstream) = get_jetstream(client_id).await?;
+    Ok(Acker::new(jetstream, Some(handle.into())))
+}
+
+/// Acknowledges a delivered message as processed.
+#[flutter_rust_bridge::frb]
+pub async fn js_ack(client_id: String, handle: String) -> Result<(), String> {
+    let acker = get_acker(&client_id, handle).await?;
+    acker
+        .ack()
+        .await
+        .map_err(|e| format!("Failed to ack message: {}", e))
+}
+
+/// Acknowledges a delivered message and waits for the server to confirm the ack.
+#[flutter_rust_bridge::frb]
+pub async fn js_double_ack(client_id: String, handle: String) -> Result<(), String> {
+    let acker = get_acker(&client_id, handle).await?;
+    acker
+        .double_ack()
+        .await
+        .map_err(|e| format!("Failed to ack message: {}", e))
+}
+
+/// Negatively acknowledges a delivered message so it is redelivered, optionally after a delay.
+#[flutter_rust_bridge::frb]
+pub async fn js_nak(
+    client_id: String,
+    handle: String,
+    delay_ms: Option<u64>,
+) -> Result<(), String> {
+    let acker = get_acker(&client_id, handle).await?;
+    acker
+        .ack_with(AckKind::Nak(delay_ms.map(Duration::from_millis)))
+        .await
+        .map_err(|e| format!("Failed to nak message: {}", e))
+}
+
+/// Signals that a delivered message is still being processed, resetting its ack wait.
+#[flutter_rust_bridge::frb]
+pub async fn js_in_progress(client_id: String, handle: String) -> Result<(), String> {
+    let acker = get_acker(&client_id, handle).await?;
+    acker
+        .ack_with(AckKind::Progress)
+        .await
+        .map_err(|e| format!("Failed to mark message in progress: {}", e))
+}
+
+/// Stops redelivery of a message without acknowledging it as processed.
+#[flutter_rust_bridge::frb]
+pub async fn js_term(client_id: String, handle: String) -> Result<(), String> {
+    let acker = get_acker(&client_id, handle).await?;
+    acker
+        .ack_with(AckKind::Term)
+        .await
+        .map_err(|e| format!("Failed to terminate message: {}", e))
+}
+
+/// Replays the history of a stream through an ordered consumer until stopped with `unsubscribe`.
+///
+/// Messages are delivered strictly in stream order; when a gap is detected the consumer is
+/// recreated from the last delivered sequence, so no message is skipped or repeated.
+#[allow(clippy::too_many_arguments)]
+#[flutter_rust_bridge::frb]
+pub async fn ordered_consume(
+    client_id: String,
+    stream: String,
+    filter_subjects: Vec<String>,
+    deliver_policy: DeliverPolicy,
+    start_sequence: Option<u64>,
+    start_time_ms: Option<i64>,
+    subscription_id: String,
+    sink: StreamSink<JsMessage>,
+) -> Result<(), String> {
+    let (_, jetstream) = get_jetstream(&client_id).await?;
+
+    let config = consumer::pull::OrderedConfig {
+        filter_subjects,
+        deliver_policy: to_deliver_policy(deliver_policy, start_sequence, start_time_ms)?,
+        ..Default::default()
+    };
+    let ordered_consumer = jetstream
+        .create_consumer_on_stream(config, &stream)
+        .await
+        .map_err(|e| format!("Failed to create ordered consumer: {}", e))?;
+
+    // Register the stream so it can be listed and stopped like a subscription
+    let sub_key = (client_id.clone(), subscription_id.clone());
+    if !activate_subscription(&sub_key).await {
+        return Err(format!(
+            "Subscription '{}' for client '{}' already exists",
+            subscription_id, client_id
+        ));
+    }
+
+    match ordered_consumer.messages().await {
+        Ok(messages) => {
+            forward_messages(&sub_key, messages, sink).await;
+            Ok(())
+        }
+        Err(e) => {
+            cleanup_subscription(&sub_key).await;
+            Err(format!("Failed to consume messages: {}", e))
+        }
+    }
+}
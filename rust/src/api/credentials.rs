@@ -0,0 +1,311 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use nkeys::KeyPair;
+use serde::Deserialize;
+
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Copy, Debug)]
+pub enum NkeyKind {
+    User,
+    Account,
+    Operator,
+}
+
+/// An nkey pair. The `seed` is the private part and must be kept secret.
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug)]
+pub struct NkeyPair {
+    pub public_key: String,
+    pub seed: String,
+}
+
+/// Subjects a user is allowed and denied to publish or subscribe to.
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug)]
+pub struct SubjectPermissions {
+    pub allow: Vec<String>,
+    pub deny: Vec<String>,
+}
+
+/// The claims of a NATS JWT.
+///
+/// Times are in milliseconds since the Unix epoch. A JWT without `expires_ms` never expires.
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug)]
+pub struct JwtClaims {
+    pub subject: String,
+    pub name: Option<String>,
+    pub issuer: String,
+    pub issuer_account: Option<String>,
+    pub issued_at_ms: i64,
+    pub expires_ms: Option<i64>,
+    pub publish: SubjectPermissions,
+    pub subscribe: SubjectPermissions,
+    pub bearer_token: bool,
+}
+
+/// The contents of a `.creds` file. The seed is not returned, only its public key.
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug)]
+pub struct Credentials {
+    pub jwt: String,
+    pub public_key: String,
+    pub claims: JwtClaims,
+}
+
+/// The JSON payload of a NATS JWT
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct RawClaims {
+    sub: String,
+    name: Option<String>,
+    iss: String,
+    iat: i64,
+    exp: Option<i64>,
+    nats: RawNatsClaims,
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct RawNatsClaims {
+    issuer_account: Option<String>,
+    #[serde(rename = "pub")]
+    publish: RawPermission,
+    #[serde(rename = "sub")]
+    subscribe: RawPermission,
+    bearer_token: bool,
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct RawPermission {
+    allow: Vec<String>,
+    deny: Vec<String>,
+}
+
+impl From<RawPermission> for SubjectPermissions {
+    fn from(permission: RawPermission) -> Self {
+        SubjectPermissions {
+            allow: permission.allow,
+            deny: permission.deny,
+        }
+    }
+}
+
+/// Helper function to parse the seed of an nkey pair
+fn key_pair_from_seed(seed: &str) -> Result<KeyPair, String> {
+    KeyPair::from_seed(seed.trim()).map_err(|e| format!("Invalid nkey seed: {}", e))
+}
+
+/// Creates a new nkey pair of the given kind.
+#[flutter_rust_bridge::frb]
+pub fn nkey_create(kind: NkeyKind) -> Result<NkeyPair, String> {
+    let key_pair = match kind {
+        NkeyKind::User => KeyPair::new_user(),
+        NkeyKind::Account => KeyPair::new_account(),
+        NkeyKind::Operator => KeyPair::new_operator(),
+    };
+
+    Ok(NkeyPair {
+        public_key: key_pair.public_key(),
+        seed: key_pair
+            .seed()
+            .map_err(|e| format!("Failed to export nkey seed: {}", e))?,
+    })
+}
+
+/// Derives the public key of an nkey seed.
+#[flutter_rust_bridge::frb]
+pub fn nkey_public_key(seed: String) -> Result<String, String> {
+    Ok(key_pair_from_seed(&seed)?.public_key())
+}
+
+/// Signs a nonce with an nkey seed, returning the signature encoded as the server expects it.
+#[flutter_rust_bridge::frb]
+pub fn nkey_sign(seed: String, nonce: Vec<u8>) -> Result<String, String> {
+    let signature = key_pair_from_seed(&seed)?
+        .sign(&nonce)
+        .map_err(|e| format!("Failed to sign nonce: {}", e))?;
+    Ok(URL_SAFE_NO_PAD.encode(signature))
+}
+
+/// Checks a signature returned by `nkey_sign` against the public key of the signer.
+#[flutter_rust_bridge::frb]
+pub fn nkey_verify(public_key: String, nonce: Vec<u8>, signature: String) -> Result<bool, String> {
+    let key_pair = KeyPair::from_public_key(public_key.trim())
+        .map_err(|e| format!("Invalid public nkey: {}", e))?;
+    let signature = URL_SAFE_NO_PAD
+        .decode(signature.trim().trim_end_matches('='))
+        .map_err(|e| format!("Invalid signature encoding: {}", e))?;
+
+    Ok(key_pair.verify(&nonce, &signature).is_ok())
+}
+
+/// Decodes the claims of a NATS JWT. The signature of the JWT is not checked.
+#[flutter_rust_bridge::frb]
+pub fn decode_jwt(jwt: String) -> Result<JwtClaims, String> {
+    let payload = jwt
+        .trim()
+        .split('.')
+        .nth(1)
+        .ok_or_else(|| "Invalid JWT: missing claims".to_string())?;
+    let payload = URL_SAFE_NO_PAD
+        .decode(payload.trim_end_matches('='))
+        .map_err(|e| format!("Invalid JWT: {}", e))?;
+    let claims: RawClaims =
+        serde_json::from_slice(&payload).map_err(|e| format!("Invalid JWT claims: {}", e))?;
+
+    Ok(JwtClaims {
+        subject: claims.sub,
+        name: claims.name,
+        issuer: claims.iss,
+        issuer_account: claims.nats.issuer_account,
+        issued_at_ms: claims.iat * 1000,
+        expires_ms: claims.exp.map(|exp| exp * 1000),
+        publish: claims.nats.publish.into(),
+        subscribe: claims.nats.subscribe.into(),
+        bearer_token: claims.nats.bearer_token,
+    })
+}
+
+/// Helper function to get the contents of the decorated blocks of a `.creds` file, in order
+fn creds_blocks(contents: &str) -> Vec<&str> {
+    let mut blocks = Vec::new();
+    let mut lines = contents.lines().map(str::trim);
+    while let Some(line) = lines.next() {
+        // Blocks look like `-----BEGIN NATS USER JWT-----`, the contents and an END line
+        if line.starts_with("---") && line.contains("BEGIN") {
+            if let Some(block) = lines.next().filter(|block| !block.is_empty()) {
+                blocks.push(block);
+            }
+        }
+    }
+    blocks
+}
+
+/// Parses the contents of a `.creds` file.
+#[flutter_rust_bridge::frb]
+pub fn parse_creds(contents: String) -> Result<Credentials, String> {
+    let blocks = creds_blocks(&contents);
+    let (jwt, seed) = match blocks.as_slice() {
+        [jwt, seed, ..] => (jwt.to_string(), seed),
+        _ => return Err("Invalid credentials: expected a JWT and an nkey seed".to_string()),
+    };
+
+    Ok(Credentials {
+        public_key: key_pair_from_seed(seed)?.public_key(),
+        claims: decode_jwt(jwt.clone())?,
+        jwt,
+    })
+}
+
+/// Reads and parses a `.creds` file.
+#[flutter_rust_bridge::frb]
+pub async fn parse_creds_file(path: String) -> Result<Credentials, String> {
+    let contents = tokio::fs::read_to_string(&path)
+        .await
+        .map_err(|e| format!("Failed to read '{}': {}", path, e))?;
+    parse_creds(contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn jwt(claims: &str) -> String {
+        format!(
+            "{}.{}.signature",
+            URL_SAFE_NO_PAD.encode(r#"{"typ":"JWT","alg":"ed25519-nkey"}"#),
+            URL_SAFE_NO_PAD.encode(claims)
+        )
+    }
+
+    #[test]
+    fn decode_jwt_reads_nats_claims() {
+        let claims = decode_jwt(jwt(r#"{
+            "sub": "UABC",
+            "name": "alice",
+            "iss": "AISSUER",
+            "iat": 1700000000,
+            "exp": 1800000000,
+            "nats": {
+                "issuer_account": "AACCOUNT",
+                "pub": {"allow": ["orders.>"], "deny": ["orders.secret"]},
+                "sub": {"allow": ["_INBOX.>"]},
+                "bearer_token": true
+            }
+        }"#))
+        .unwrap();
+
+        assert_eq!(claims.subject, "UABC");
+        assert_eq!(claims.name.as_deref(), Some("alice"));
+        assert_eq!(claims.issuer, "AISSUER");
+        assert_eq!(claims.issuer_account.as_deref(), Some("AACCOUNT"));
+        assert_eq!(claims.issued_at_ms, 1_700_000_000_000);
+        assert_eq!(claims.expires_ms, Some(1_800_000_000_000));
+        assert_eq!(claims.publish.allow, vec!["orders.>"]);
+        assert_eq!(claims.publish.deny, vec!["orders.secret"]);
+        assert_eq!(claims.subscribe.allow, vec!["_INBOX.>"]);
+        assert!(claims.subscribe.deny.is_empty());
+        assert!(claims.bearer_token);
+    }
+
+    #[test]
+    fn decode_jwt_defaults_missing_claims() {
+        let claims = decode_jwt(jwt(r#"{"sub": "UABC", "iss": "AISSUER"}"#)).unwrap();
+
+        assert_eq!(claims.name, None);
+        assert_eq!(claims.expires_ms, None);
+        assert!(claims.publish.allow.is_empty());
+        assert!(!claims.bearer_token);
+    }
+
+    #[test]
+    fn decode_jwt_rejects_malformed_tokens() {
+        assert!(decode_jwt("not-a-jwt".to_string()).is_err());
+        assert!(decode_jwt("header.!!!.signature".to_string()).is_err());
+        assert!(decode_jwt(jwt("not json")).is_err());
+    }
+
+    #[test]
+    fn parse_creds_reads_jwt_and_seed() {
+        let key_pair = nkey_create(NkeyKind::User).unwrap();
+        let token = jwt(r#"{"sub": "UABC", "iss": "AISSUER"}"#);
+        let contents = format!(
+            "-----BEGIN NATS USER JWT-----\n{}\n------END NATS USER JWT------\n\n\
+             ************************* IMPORTANT *************************\n\
+             NKEY Seed printed below can be used to sign and prove identity.\n\n\
+             -----BEGIN USER NKEY SEED-----\n{}\n------END USER NKEY SEED------\n",
+            token, key_pair.seed
+        );
+
+        let credentials = parse_creds(contents).unwrap();
+        assert_eq!(credentials.jwt, token);
+        assert_eq!(credentials.public_key, key_pair.public_key);
+        assert_eq!(credentials.claims.subject, "UABC");
+    }
+
+    #[test]
+    fn parse_creds_requires_jwt_and_seed() {
+        let contents = "-----BEGIN NATS USER JWT-----\nheader.claims.signature\n------END NATS USER JWT------\n";
+        assert!(parse_creds(contents.to_string()).is_err());
+        assert!(parse_creds(String::new()).is_err());
+    }
+
+    #[test]
+    fn signatures_verify_with_the_public_key() {
+        let key_pair = nkey_create(NkeyKind::User).unwrap();
+        let signature = nkey_sign(key_pair.seed.clone(), b"nonce".to_vec()).unwrap();
+
+        assert!(nkey_verify(
+            key_pair.public_key.clone(),
+            b"nonce".to_vec(),
+            signature.clone()
+        )
+        .unwrap());
+        assert!(!nkey_verify(key_pair.public_key, b"other".to_vec(), signature).unwrap());
+    }
+}
@@ -0,0 +1,232 @@
+use crate::api::nats::{get_client, ClientId, Registry};
+use crate::api::subject::matches_pattern;
+use async_nats::HeaderMap;
+use nkeys::XKey;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// A curve25519 xkey pair. The `seed` is the private part and must be kept secret.
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug)]
+pub struct XKeyPair {
+    pub public_key: String,
+    pub seed: String,
+}
+
+/// The public xkey payloads sent on subjects matching `subject` are encrypted for.
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug)]
+pub struct RecipientKey {
+    pub subject: String,
+    pub public_key: String,
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Default)]
+struct ClientKeys {
+    seed: Option<String>,
+    recipients: Vec<RecipientKey>,
+}
+
+// Store the xkey pair and recipient keys of each client that enabled encryption
+static ENCRYPTION_KEYS: Registry<ClientId, ClientKeys> =
+    Lazy::new(|| Arc::new(RwLock::new(HashMap::new())));
+
+/// Helper function to parse the seed of an xkey pair
+fn key_pair_from_seed(seed: &str) -> Result<XKey, String> {
+    XKey::from_seed(seed).map_err(|e| format!("Invalid xkey seed: {}", e))
+}
+
+/// Helper function to parse a public xkey
+fn public_key(public_key: &str) -> Result<XKey, String> {
+    XKey::from_public_key(public_key).map_err(|e| format!("Invalid public xkey: {}", e))
+}
+
+/// Generates a new xkey pair.
+#[flutter_rust_bridge::frb]
+pub fn xkey_generate() -> Result<XKeyPair, String> {
+    let key_pair = XKey::new();
+    Ok(XKeyPair {
+        public_key: key_pair.public_key(),
+        seed: key_pair
+            .seed()
+            .map_err(|e| format!("Failed to export xkey seed: {}", e))?,
+    })
+}
+
+/// Sets the xkey pair a client encrypts payloads with and decrypts received payloads with.
+///
+/// Returns the public key to hand to the other parties.
+#[flutter_rust_bridge::frb]
+pub async fn xkey_import(client_id: String, seed: String) -> Result<String, String> {
+    get_client(&client_id).await?;
+    let key_pair = key_pair_from_seed(&seed)?;
+
+    ENCRYPTION_KEYS
+        .write()
+        .await
+        .entry(client_id)
+        .or_default()
+        .seed = Some(seed);
+    Ok(key_pair.public_key())
+}
+
+/// Returns the xkey pair of a client, or `None` if it has not imported one.
+#[flutter_rust_bridge::frb]
+pub async fn xkey_export(client_id: String) -> Result<Option<XKeyPair>, String> {
+    get_client(&client_id).await?;
+
+    let keys = ENCRYPTION_KEYS.read().await;
+    let Some(seed) = keys.get(&client_id).and_then(|keys| keys.seed.clone()) else {
+        return Ok(None);
+    };
+    Ok(Some(XKeyPair {
+        public_key: key_pair_from_seed(&seed)?.public_key(),
+        seed,
+    }))
+}
+
+/// Encrypts the payloads a client sends on subjects matching `subject` for `public_key`.
+///
+/// `subject` may contain the `*` and `>` wildcards. When several recipient keys match a
+/// subject, the first one set is used. Setting a key for an existing subject replaces it.
+#[flutter_rust_bridge::frb]
+pub async fn set_recipient_key(
+    client_id: String,
+    subject: String,
+    public_key: String,
+) -> Result<(), String> {
+    get_client(&client_id).await?;
+    self::public_key(&public_key)?;
+
+    let mut keys = ENCRYPTION_KEYS.write().await;
+    let recipients = &mut keys.entry(client_id).or_default().recipients;
+    match recipients
+        .iter_mut()
+        .find(|recipient| recipient.subject == subject)
+    {
+        Some(recipient) => recipient.public_key = public_key,
+        None => recipients.push(RecipientKey {
+            subject,
+            public_key,
+        }),
+    }
+    Ok(())
+}
+
+/// Stops encrypting the payloads a client sends on `subject`.
+#[flutter_rust_bridge::frb]
+pub async fn remove_recipient_key(client_id: String, subject: String) -> Result<bool, String> {
+    let mut keys = ENCRYPTION_KEYS.write().await;
+    let Some(client_keys) = keys.get_mut(&client_id) else {
+        return Ok(false);
+    };
+
+    let count = client_keys.recipients.len();
+    client_keys
+        .recipients
+        .retain(|recipient| recipient.subject != subject);
+    Ok(client_keys.recipients.len() != count)
+}
+
+/// Lists the recipient keys of a client.
+#[flutter_rust_bridge::frb]
+pub async fn list_recipient_keys(client_id: String) -> Vec<RecipientKey> {
+    ENCRYPTION_KEYS
+        .read()
+        .await
+        .get(&client_id)
+        .map(|keys| keys.recipients.clone())
+        .unwrap_or_default()
+}
+
+/// Helper function to encrypt a payload for a recipient, announcing the sender in its headers
+async fn seal(
+    client_id: &str,
+    recipient: &str,
+    payload: Vec<u8>,
+    headers: Option<HeaderMap>,
+) -> Result<(Vec<u8>, Option<HeaderMap>), String> {
+    let seed = ENCRYPTION_KEYS
+        .read()
+        .await
+        .get(client_id)
+        .and_then(|keys| keys.seed.clone())
+        .ok_or_else(|| format!("Client '{}' has no xkey pair to encrypt with", client_id))?;
+    let sender = key_pair_from_seed(&seed)?;
+
+    let sealed = sender
+        .seal(&payload, &public_key(recipient)?)
+        .map_err(|e| format!("Failed to encrypt payload: {}", e))?;
+    let mut headers = headers.unwrap_or_default();
+    headers.insert("Xkey-Sender", sender.public_key().as_str());
+    Ok((sealed, Some(headers)))
+}
+
+/// Encrypts an outgoing payload if the client has a recipient key matching its subject.
+///
+/// Returns the payload to send and the headers to send it with.
+pub(crate) async fn seal_for_client(
+    client_id: &str,
+    subject: &str,
+    payload: Vec<u8>,
+    headers: Option<HeaderMap>,
+) -> Result<(Vec<u8>, Option<HeaderMap>), String> {
+    let recipient = ENCRYPTION_KEYS
+        .read()
+        .await
+        .get(client_id)
+        .and_then(|keys| {
+            keys.recipients
+                .iter()
+                .find(|recipient| matches_pattern(&recipient.subject, subject))
+                .map(|recipient| recipient.public_key.clone())
+        });
+    match recipient {
+        Some(recipient) => seal(client_id, &recipient, payload, headers).await,
+        None => Ok((payload, headers)),
+    }
+}
+
+/// Encrypts a reply for the sender of an encrypted request, leaving other replies as they are.
+pub(crate) async fn seal_reply(
+    client_id: &str,
+    request_headers: Option<&HeaderMap>,
+    payload: Vec<u8>,
+    headers: Option<HeaderMap>,
+) -> Result<(Vec<u8>, Option<HeaderMap>), String> {
+    let sender = request_headers.and_then(|headers| headers.get("Xkey-Sender"));
+    match sender {
+        Some(sender) => seal(client_id, sender.as_str(), payload, headers).await,
+        None => Ok((payload, headers)),
+    }
+}
+
+/// Decrypts a received payload carrying an `Xkey-Sender` header with the client's xkey pair.
+pub(crate) async fn open_for_client(
+    client_id: &str,
+    headers: Option<&HeaderMap>,
+    payload: &[u8],
+) -> Result<Vec<u8>, String> {
+    let sender = headers.and_then(|headers| headers.get("Xkey-Sender"));
+    let Some(sender) = sender else {
+        return Ok(payload.to_vec());
+    };
+
+    let seed = ENCRYPTION_KEYS
+        .read()
+        .await
+        .get(client_id)
+        .and_then(|keys| keys.seed.clone())
+        .ok_or_else(|| format!("Client '{}' has no xkey pair to decrypt with", client_id))?;
+    key_pair_from_seed(&seed)?
+        .open(payload, &public_key(sender.as_str())?)
+        .map_err(|e| format!("Failed to decrypt payload: {}", e))
+}
+
+/// Drops the keys of a disconnected client.
+pub(crate) async fn cleanup_client_encryption(client_id: &str) {
+    ENCRYPTION_KEYS.write().await.remove(client_id);
+}
@@ -0,0 +1,134 @@
+use async_nats::jetstream::context::{PublishError, PublishErrorKind};
+use async_nats::jetstream::kv::{CreateError, CreateErrorKind, UpdateError, UpdateErrorKind};
+use async_nats::{RequestError, RequestErrorKind};
+use std::fmt;
+
+/// The category of a [`NatsError`], letting Dart react to specific failures.
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NatsErrorKind {
+    ClientNotFound,
+    StreamNotFound,
+    StreamMismatch,
+    WrongLastMessageId,
+    WrongLastSequence,
+    WrongRevision,
+    TimedOut,
+    InvalidSubject,
+    PayloadTooLarge,
+    Other,
+}
+
+/// A typed error returned by APIs whose failures callers are expected to handle.
+///
+/// `payload_size` and `max_payload` are only set for `NatsErrorKind::PayloadTooLarge`.
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug)]
+pub struct NatsError {
+    pub kind: NatsErrorKind,
+    pub message: String,
+    pub payload_size: Option<u64>,
+    pub max_payload: Option<u64>,
+}
+
+impl NatsError {
+    pub(crate) fn new(kind: NatsErrorKind, message: impl Into<String>) -> Self {
+        NatsError {
+            kind,
+            message: message.into(),
+            payload_size: None,
+            max_payload: None,
+        }
+    }
+
+    pub(crate) fn payload_too_large(payload_size: u64, max_payload: u64) -> Self {
+        NatsError {
+            payload_size: Some(payload_size),
+            max_payload: Some(max_payload),
+            ..NatsError::new(
+                NatsErrorKind::PayloadTooLarge,
+                format!(
+                    "Payload of {} bytes exceeds the server's max_payload of {} bytes",
+                    payload_size, max_payload
+                ),
+            )
+        }
+    }
+}
+
+impl fmt::Display for NatsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for NatsError {}
+
+impl From<NatsError> for String {
+    fn from(error: NatsError) -> Self {
+        error.message
+    }
+}
+
+impl From<String> for NatsError {
+    fn from(message: String) -> Self {
+        NatsError::new(NatsErrorKind::Other, message)
+    }
+}
+
+impl From<RequestError> for NatsError {
+    fn from(error: RequestError) -> Self {
+        let kind = match error.kind() {
+            RequestErrorKind::TimedOut => NatsErrorKind::TimedOut,
+            RequestErrorKind::NoResponders | RequestErrorKind::Other => NatsErrorKind::Other,
+        };
+        NatsError::new(kind, format!("Request failed: {}", error))
+    }
+}
+
+impl From<PublishError> for NatsError {
+    fn from(error: PublishError) -> Self {
+        let kind = match error.kind() {
+            PublishErrorKind::StreamNotFound => NatsErrorKind::StreamNotFound,
+            PublishErrorKind::WrongLastMessageId => NatsErrorKind::WrongLastMessageId,
+            PublishErrorKind::WrongLastSequence => NatsErrorKind::WrongLastSequence,
+            PublishErrorKind::TimedOut => NatsErrorKind::TimedOut,
+            PublishErrorKind::BrokenPipe | PublishErrorKind::Other => {
+                // An unexpected stream is only reported through the server error code
+                let stream_mismatch = std::error::Error::source(&error)
+                    .and_then(|source| source.downcast_ref::<async_nats::jetstream::Error>())
+                    .is_some_and(|source| {
+                        source.error_code() == async_nats::jetstream::ErrorCode::STREAM_NOT_MATCH
+                    });
+                if stream_mismatch {
+                    NatsErrorKind::StreamMismatch
+                } else {
+                    NatsErrorKind::Other
+                }
+            }
+        };
+        NatsError::new(kind, format!("Failed to publish: {}", error))
+    }
+}
+
+impl From<UpdateError> for NatsError {
+    fn from(error: UpdateError) -> Self {
+        let kind = match error.kind() {
+            UpdateErrorKind::WrongLastRevision => NatsErrorKind::WrongRevision,
+            UpdateErrorKind::TimedOut => NatsErrorKind::TimedOut,
+            UpdateErrorKind::InvalidKey | UpdateErrorKind::Other => NatsErrorKind::Other,
+        };
+        NatsError::new(kind, format!("Failed to update key: {}", error))
+    }
+}
+
+impl From<CreateError> for NatsError {
+    fn from(error: CreateError) -> Self {
+        // Creating a key is an update expecting no previous revision
+        let kind = match error.kind() {
+            CreateErrorKind::AlreadyExists => NatsErrorKind::WrongRevision,
+            _ => NatsErrorKind::Other,
+        };
+        NatsError::new(kind, format!("Failed to create key: {}", error))
+    }
+}
@@ -0,0 +1,791 @@
+use crate::api::error::{NatsError, NatsErrorKind};
+use crate::api::jetstream::{unix_millis, StorageType};
+use crate::api::nats::{
+    activate_subscription, cache_kv_store, cleanup_subscription, evict_kv_store, get_jetstream,
+    is_subscription_active, open_kv_store, put_kv_value, read_payload, ClientId, SubscriptionId,
+};
+use crate::api::subject::{check_kv_key, check_subject};
+use crate::frb_generated::StreamSink;
+use async_nats::jetstream::consumer::{pull, DeliverPolicy};
+use async_nats::jetstream::stream::LastRawMessageErrorKind;
+use async_nats::jetstream::{kv, stream};
+use std::time::Duration;
+use tokio_stream::{Stream, StreamExt};
+
+/// Republishes entries stored in a bucket to another subject.
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug)]
+pub struct KvRepublish {
+    pub source: String,
+    pub destination: String,
+    pub headers_only: bool,
+}
+
+/// A bucket mirrored or sourced into another bucket.
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug)]
+pub struct KvSource {
+    pub bucket: String,
+    pub filter_subject: Option<String>,
+    pub domain: Option<String>,
+}
+
+/// Configuration of a JetStream Key-Value bucket.
+///
+/// Limits left as `None` use the server defaults, which means unlimited, and a history of one revision.
+/// When updating a bucket, fields left as `None` keep their current value instead.
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug)]
+pub struct KvConfig {
+    pub bucket: String,
+    pub description: Option<String>,
+    pub history: Option<i64>,
+    pub max_age_ms: Option<u64>,
+    pub max_bytes: Option<i64>,
+    pub max_value_size: Option<i32>,
+    pub storage: StorageType,
+    pub num_replicas: Option<u32>,
+    pub compression: Option<bool>,
+    pub republish: Option<KvRepublish>,
+    pub mirror: Option<KvSource>,
+    pub sources: Option<Vec<KvSource>>,
+}
+
+/// Configuration and state of a Key-Value bucket.
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug)]
+pub struct KvStatus {
+    pub config: KvConfig,
+    pub values: u64,
+    pub bytes: u64,
+    pub created_ms: i64,
+}
+
+/// The operation that produced a Key-Value entry.
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Copy, Debug)]
+pub enum KvOperation {
+    Put,
+    Delete,
+    Purge,
+}
+
+/// A Key-Value entry with its revision metadata.
+///
+/// `delta` is the distance from the latest revision of the key, so the latest entry has a delta of zero.
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug)]
+pub struct KvEntry {
+    pub bucket: String,
+    pub key: String,
+    pub value: String,
+    pub revision: u64,
+    pub delta: u64,
+    pub created_ms: i64,
+    pub operation: KvOperation,
+}
+
+/// Options for watching Key-Value entries.
+///
+/// By default a watch delivers the latest entry of each matching key, then every update.
+/// `include_history` delivers all retained revisions first instead, and `updates_only` skips
+/// existing entries. `metadata_only` delivers entries without their values.
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug)]
+pub struct KvWatchOptions {
+    pub include_history: bool,
+    pub updates_only: bool,
+    pub metadata_only: bool,
+}
+
+/// Helper function to get the name of the stream backing a bucket
+fn kv_stream_name(bucket: &str) -> String {
+    format!("KV_{}", bucket)
+}
+
+impl From<kv::Operation> for KvOperation {
+    fn from(operation: kv::Operation) -> Self {
+        match operation {
+            kv::Operation::Put => KvOperation::Put,
+            kv::Operation::Delete => KvOperation::Delete,
+            kv::Operation::Purge => KvOperation::Purge,
+        }
+    }
+}
+
+/// Helper function to build an entry from a message of a bucket's stream, decrypting and
+/// decompressing its value according to its headers
+#[allow(clippy::too_many_arguments)]
+async fn decode_kv_entry(
+    client_id: &str,
+    store: &kv::Store,
+    subject: &str,
+    headers: Option<&async_nats::HeaderMap>,
+    payload: &[u8],
+    revision: u64,
+    delta: u64,
+    created: time::OffsetDateTime,
+) -> Result<KvEntry, String> {
+    let operation = match headers
+        .and_then(|headers| headers.get("KV-Operation"))
+        .map(|value| value.as_str())
+    {
+        Some("DEL") => KvOperation::Delete,
+        Some("PURGE") => KvOperation::Purge,
+        _ => KvOperation::Put,
+    };
+    let key = subject
+        .strip_prefix(store.prefix.as_str())
+        .unwrap_or(subject)
+        .to_string();
+
+    // Markers and headers-only reads carry no value to decode
+    let value = if payload.is_empty() {
+        Vec::new()
+    } else {
+        read_payload(client_id, headers, payload).await?
+    };
+    let value = String::from_utf8(value).map_err(|e| format!("Invalid UTF-8 in value: {}", e))?;
+
+    Ok(KvEntry {
+        bucket: store.name.clone(),
+        key,
+        value,
+        revision,
+        delta,
+        created_ms: unix_millis(created),
+        operation,
+    })
+}
+
+/// Helper function to convert a message read from a bucket's stream into an entry
+pub(crate) async fn message_to_kv_entry(
+    client_id: &str,
+    store: &kv::Store,
+    message: &async_nats::jetstream::Message,
+) -> Result<KvEntry, String> {
+    let info = message
+        .info()
+        .map_err(|e| format!("Failed to parse message metadata: {}", e))?;
+
+    decode_kv_entry(
+        client_id,
+        store,
+        &message.subject,
+        message.headers.as_ref(),
+        &message.payload,
+        info.stream_sequence,
+        info.pending,
+        info.published,
+    )
+    .await
+}
+
+/// Helper function to read the latest entry of a key, or `None` if the key was never set
+pub(crate) async fn latest_entry(
+    client_id: &str,
+    store: &kv::Store,
+    key: &str,
+) -> Result<Option<KvEntry>, String> {
+    let subject = format!("{}{}", store.prefix, key);
+    let message = match store.stream.get_last_raw_message_by_subject(&subject).await {
+        Ok(message) => message,
+        Err(e) if e.kind() == LastRawMessageErrorKind::NoMessageFound => return Ok(None),
+        Err(e) => return Err(format!("Failed to get entry: {}", e)),
+    };
+
+    decode_kv_entry(
+        client_id,
+        store,
+        &message.subject,
+        Some(&message.headers),
+        &message.payload,
+        message.sequence,
+        0,
+        message.time,
+    )
+    .await
+    .map(Some)
+}
+
+/// Helper function to read the entries of the keys matching `filter` from a bucket's stream
+async fn read_entries(
+    client_id: &str,
+    store: &kv::Store,
+    filter: &str,
+    deliver_policy: DeliverPolicy,
+    headers_only: bool,
+) -> Result<Vec<KvEntry>, String> {
+    let entries_consumer = store
+        .stream
+        .create_consumer(pull::OrderedConfig {
+            filter_subject: format!("{}{}", store.prefix, filter),
+            headers_only,
+            deliver_policy,
+            ..Default::default()
+        })
+        .await
+        .map_err(|e| format!("Failed to read entries: {}", e))?;
+
+    let mut entries = Vec::new();
+    if entries_consumer.cached_info().num_pending == 0 {
+        return Ok(entries);
+    }
+
+    let mut messages = entries_consumer
+        .messages()
+        .await
+        .map_err(|e| format!("Failed to read entries: {}", e))?;
+    while let Some(message) = messages.next().await {
+        let message = message.map_err(|e| format!("Failed to read entries: {}", e))?;
+        let entry = message_to_kv_entry(client_id, store, &message).await?;
+        let done = entry.delta == 0;
+        entries.push(entry);
+        if done {
+            break;
+        }
+    }
+
+    Ok(entries)
+}
+
+/// Helper function to read the latest entry of every key matching `filter`
+async fn latest_entries(
+    client_id: &str,
+    store: &kv::Store,
+    filter: &str,
+    headers_only: bool,
+) -> Result<Vec<KvEntry>, String> {
+    read_entries(
+        client_id,
+        store,
+        filter,
+        DeliverPolicy::LastPerSubject,
+        headers_only,
+    )
+    .await
+}
+
+/// Helper function to get an existing KV store, never creating a missing bucket
+pub(crate) async fn get_store(client_id: &str, bucket: &str) -> Result<kv::Store, NatsError> {
+    open_kv_store(client_id, bucket).await
+}
+
+impl From<KvRepublish> for stream::Republish {
+    fn from(republish: KvRepublish) -> Self {
+        stream::Republish {
+            source: republish.source,
+            destination: republish.destination,
+            headers_only: republish.headers_only,
+        }
+    }
+}
+
+impl From<stream::Republish> for KvRepublish {
+    fn from(republish: stream::Republish) -> Self {
+        KvRepublish {
+            source: republish.source,
+            destination: republish.destination,
+            headers_only: republish.headers_only,
+        }
+    }
+}
+
+impl From<KvSource> for stream::Source {
+    fn from(source: KvSource) -> Self {
+        stream::Source {
+            name: kv_stream_name(&source.bucket),
+            filter_subject: source.filter_subject,
+            domain: source.domain,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<stream::Source> for KvSource {
+    fn from(source: stream::Source) -> Self {
+        KvSource {
+            bucket: source.name.trim_start_matches("KV_").to_string(),
+            filter_subject: source.filter_subject,
+            domain: source.domain,
+        }
+    }
+}
+
+impl From<KvConfig> for kv::Config {
+    fn from(config: KvConfig) -> Self {
+        let defaults = kv::Config::default();
+        kv::Config {
+            bucket: config.bucket,
+            description: config.description.unwrap_or_default(),
+            history: config.history.unwrap_or(defaults.history),
+            max_age: config
+                .max_age_ms
+                .map(Duration::from_millis)
+                .unwrap_or(defaults.max_age),
+            max_bytes: config.max_bytes.unwrap_or(defaults.max_bytes),
+            max_value_size: config.max_value_size.unwrap_or(defaults.max_value_size),
+            storage: config.storage.into(),
+            num_replicas: config
+                .num_replicas
+                .map(|replicas| replicas as usize)
+                .unwrap_or(defaults.num_replicas),
+            compression: config.compression.unwrap_or_default(),
+            republish: config.republish.map(Into::into),
+            mirror: config.mirror.map(Into::into),
+            sources: config
+                .sources
+                .map(|sources| sources.into_iter().map(Into::into).collect()),
+            ..defaults
+        }
+    }
+}
+
+impl From<stream::Config> for KvConfig {
+    fn from(config: stream::Config) -> Self {
+        KvConfig {
+            bucket: config.name.trim_start_matches("KV_").to_string(),
+            description: config.description,
+            history: Some(config.max_messages_per_subject),
+            max_age_ms: Some(config.max_age.as_millis() as u64),
+            max_bytes: Some(config.max_bytes),
+            max_value_size: Some(config.max_message_size),
+            storage: config.storage.into(),
+            num_replicas: Some(config.num_replicas as u32),
+            // Servers report uncompressed streams as an explicit `none`
+            compression: Some(matches!(config.compression, Some(stream::Compression::S2))),
+            republish: config.republish.map(Into::into),
+            mirror: config.mirror.map(Into::into),
+            sources: Some(
+                config
+                    .sources
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(Into::into)
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl From<stream::Info> for KvStatus {
+    fn from(info: stream::Info) -> Self {
+        KvStatus {
+            config: info.config.into(),
+            values: info.state.messages,
+            bytes: info.state.bytes,
+            created_ms: unix_millis(info.created),
+        }
+    }
+}
+
+/// Helper function to apply the fields of a bucket configuration onto the stream config `base`,
+/// keeping the fields of `base` that are not exposed or are left as `None`
+fn overlay_kv_config(config: KvConfig, base: stream::Config) -> stream::Config {
+    stream::Config {
+        description: config.description.or(base.description),
+        max_messages_per_subject: config.history.unwrap_or(base.max_messages_per_subject),
+        max_age: config
+            .max_age_ms
+            .map(Duration::from_millis)
+            .unwrap_or(base.max_age),
+        max_bytes: config.max_bytes.unwrap_or(base.max_bytes),
+        max_message_size: config.max_value_size.unwrap_or(base.max_message_size),
+        storage: config.storage.into(),
+        num_replicas: config
+            .num_replicas
+            .map(|replicas| replicas as usize)
+            .unwrap_or(base.num_replicas),
+        compression: config
+            .compression
+            .map(|compression| {
+                if compression {
+                    stream::Compression::S2
+                } else {
+                    stream::Compression::None
+                }
+            })
+            .or(base.compression),
+        republish: config.republish.map(Into::into).or(base.republish),
+        mirror: config.mirror.map(Into::into).or(base.mirror),
+        sources: config
+            .sources
+            .map(|sources| sources.into_iter().map(Into::into).collect())
+            .or(base.sources),
+        ..base
+    }
+}
+
+/// Creates a Key-Value bucket and returns its status.
+#[flutter_rust_bridge::frb]
+pub async fn kv_create_bucket(client_id: String, config: KvConfig) -> Result<KvStatus, String> {
+    let (_, jetstream) = get_jetstream(&client_id).await?;
+    let bucket = config.bucket.clone();
+
+    let store = jetstream
+        .create_key_value(kv::Config::from(config))
+        .await
+        .map_err(|e| format!("Failed to create KV bucket '{}': {}", bucket, e))?;
+
+    cache_kv_store(&client_id, &bucket, store).await;
+    kv_bucket_status(client_id, bucket).await
+}
+
+/// Updates the configuration of an existing Key-Value bucket and returns its status.
+///
+/// The storage type and mirror of a bucket cannot be changed once it is created.
+#[flutter_rust_bridge::frb]
+pub async fn kv_update_bucket(client_id: String, config: KvConfig) -> Result<KvStatus, String> {
+    let (_, jetstream) = get_jetstream(&client_id).await?;
+    let bucket = config.bucket.clone();
+
+    let mut stream = jetstream
+        .get_stream(kv_stream_name(&bucket))
+        .await
+        .map_err(|e| format!("Failed to get KV bucket '{}': {}", bucket, e))?;
+    let current = stream
+        .info()
+        .await
+        .map_err(|e| format!("Failed to get KV bucket status: {}", e))?
+        .config
+        .clone();
+
+    let info = jetstream
+        .update_stream(overlay_kv_config(config, current))
+        .await
+        .map_err(|e| format!("Failed to update KV bucket '{}': {}", bucket, e))?;
+
+    // The cached store holds the bucket's previous stream info
+    evict_kv_store(&client_id, &bucket).await;
+    Ok(info.into())
+}
+
+/// Deletes a Key-Value bucket and all of its entries.
+#[flutter_rust_bridge::frb]
+pub async fn kv_delete_bucket(client_id: String, bucket: String) -> Result<bool, String> {
+    let (_, jetstream) = get_jetstream(&client_id).await?;
+
+    let status = jetstream
+        .delete_key_value(&bucket)
+        .await
+        .map_err(|e| format!("Failed to delete KV bucket '{}': {}", bucket, e))?;
+
+    evict_kv_store(&client_id, &bucket).await;
+    Ok(status.success)
+}
+
+/// Returns the configuration and current state of a Key-Value bucket.
+#[flutter_rust_bridge::frb]
+pub async fn kv_bucket_status(client_id: String, bucket: String) -> Result<KvStatus, String> {
+    let (_, jetstream) = get_jetstream(&client_id).await?;
+
+    let mut stream = jetstream
+        .get_stream(kv_stream_name(&bucket))
+        .await
+        .map_err(|e| format!("Failed to get KV bucket '{}': {}", bucket, e))?;
+    let info = stream
+        .info()
+        .await
+        .map_err(|e| format!("Failed to get KV bucket status: {}", e))?;
+
+    Ok(info.clone().into())
+}
+
+/// Gets the latest entry for a key with its revision metadata, or `None` if the key was never set.
+///
+/// Deleted and purged keys return an entry with the matching operation and an empty value.
+/// Fails with `NatsErrorKind::StreamNotFound` if the bucket does not exist.
+#[flutter_rust_bridge::frb]
+pub async fn kv_entry(
+    client_id: String,
+    bucket: String,
+    key: String,
+) -> Result<Option<KvEntry>, NatsError> {
+    check_kv_key(&key)?;
+
+    let store = get_store(&client_id, &bucket).await?;
+
+    latest_entry(&client_id, &store, &key)
+        .await
+        .map_err(|e| NatsError::new(NatsErrorKind::Other, e))
+}
+
+/// Puts a value only if the key does not exist yet and returns its revision.
+///
+/// Fails with `NatsErrorKind::WrongRevision` if the key already has a value.
+#[flutter_rust_bridge::frb]
+pub async fn kv_create(
+    client_id: String,
+    bucket: String,
+    key: String,
+    value: String,
+) -> Result<u64, NatsError> {
+    check_kv_key(&key)?;
+
+    let store = get_store(&client_id, &bucket).await?;
+
+    put_kv_value(&client_id, &store, &key, value.into_bytes(), &[], Some(0)).await
+}
+
+/// Puts a value only if the latest revision of the key is `expected_revision` and returns the new revision.
+///
+/// Fails with `NatsErrorKind::WrongRevision` if the key was changed since that revision.
+#[flutter_rust_bridge::frb]
+pub async fn kv_update(
+    client_id: String,
+    bucket: String,
+    key: String,
+    value: String,
+    expected_revision: u64,
+) -> Result<u64, NatsError> {
+    check_kv_key(&key)?;
+
+    let store = get_store(&client_id, &bucket).await?;
+
+    put_kv_value(
+        &client_id,
+        &store,
+        &key,
+        value.into_bytes(),
+        &[],
+        Some(expected_revision),
+    )
+    .await
+}
+
+/// Streams changes to the keys matching `key` to Dart until stopped with `unsubscribe`.
+///
+/// `key` may contain the `*` and `>` wildcards, e.g. `settings.>`. The bucket must already exist.
+#[flutter_rust_bridge::frb]
+pub async fn kv_watch(
+    client_id: String,
+    bucket: String,
+    key: String,
+    options: KvWatchOptions,
+    subscription_id: String,
+    sink: StreamSink<KvEntry>,
+) -> Result<(), NatsError> {
+    check_subject(&key, true)?;
+
+    let deliver_policy = match (options.include_history, options.updates_only) {
+        (true, true) => {
+            return Err(NatsError::new(
+                NatsErrorKind::Other,
+                "include_history and updates_only cannot both be set",
+            ));
+        }
+        (true, false) => DeliverPolicy::All,
+        (false, true) => DeliverPolicy::New,
+        (false, false) => DeliverPolicy::LastPerSubject,
+    };
+
+    let store = get_store(&client_id, &bucket).await?;
+    let watch_consumer = store
+        .stream
+        .create_consumer(pull::OrderedConfig {
+            description: Some("kv watch consumer".to_string()),
+            filter_subject: format!("{}{}", store.prefix, key),
+            headers_only: options.metadata_only,
+            deliver_policy,
+            ..Default::default()
+        })
+        .await
+        .map_err(|e| {
+            NatsError::new(
+                NatsErrorKind::Other,
+                format!("Failed to watch '{}': {}", key, e),
+            )
+        })?;
+
+    // Register the watch so it can be listed and stopped like a subscription
+    let sub_key = (client_id.clone(), subscription_id.clone());
+    if !activate_subscription(&sub_key).await {
+        return Err(NatsError::new(
+            NatsErrorKind::Other,
+            format!(
+                "Subscription '{}' for client '{}' already exists",
+                subscription_id, client_id
+            ),
+        ));
+    }
+
+    match watch_consumer.messages().await {
+        Ok(messages) => {
+            forward_entries(&sub_key, &store, messages, sink).await;
+            Ok(())
+        }
+        Err(e) => {
+            cleanup_subscription(&sub_key).await;
+            Err(NatsError::new(
+                NatsErrorKind::Other,
+                format!("Failed to watch '{}': {}", key, e),
+            ))
+        }
+    }
+}
+
+/// Streams changes to all keys of a bucket to Dart until stopped with `unsubscribe`.
+#[flutter_rust_bridge::frb]
+pub async fn kv_watch_all(
+    client_id: String,
+    bucket: String,
+    options: KvWatchOptions,
+    subscription_id: String,
+    sink: StreamSink<KvEntry>,
+) -> Result<(), NatsError> {
+    kv_watch(
+        client_id,
+        bucket,
+        ">".to_string(),
+        options,
+        subscription_id,
+        sink,
+    )
+    .await
+}
+
+/// Internal function to forward watched entries to a Dart stream until stopped
+async fn forward_entries<E: std::fmt::Display>(
+    sub_key: &(ClientId, SubscriptionId),
+    store: &kv::Store,
+    mut messages: impl Stream<Item = Result<async_nats::jetstream::Message, E>> + Unpin,
+    sink: StreamSink<KvEntry>,
+) {
+    while is_subscription_active(sub_key).await {
+        // Wait for the next entry with a small timeout so stops are noticed
+        let maybe_msg =
+            match tokio::time::timeout(Duration::from_millis(100), messages.next()).await {
+                Ok(Some(message)) => message,
+                Ok(None) => break,
+                Err(_) => continue,
+            };
+
+        let entry = match maybe_msg {
+            Ok(message) => message_to_kv_entry(&sub_key.0, store, &message).await,
+            Err(e) => Err(format!("Failed to receive entry: {}", e)),
+        };
+        let sent = match entry {
+            Ok(entry) => sink.add(entry),
+            Err(e) => sink.add_error(e),
+        };
+
+        // The Dart side stopped listening
+        if sent.is_err() {
+            break;
+        }
+    }
+
+    cleanup_subscription(sub_key).await;
+}
+
+/// Lists the keys of a bucket that currently hold a value, sorted by name.
+///
+/// `filter` may contain the `*` and `>` wildcards and defaults to all keys. Use `offset` and
+/// `limit` to page through large buckets.
+#[flutter_rust_bridge::frb]
+pub async fn kv_keys(
+    client_id: String,
+    bucket: String,
+    filter: Option<String>,
+    offset: u64,
+    limit: Option<u64>,
+) -> Result<Vec<String>, NatsError> {
+    let filter = filter.unwrap_or_else(|| ">".to_string());
+    check_subject(&filter, true)?;
+
+    let store = get_store(&client_id, &bucket).await?;
+
+    let mut keys: Vec<String> = latest_entries(&client_id, &store, &filter, true)
+        .await?
+        .into_iter()
+        .filter(|entry| matches!(entry.operation, KvOperation::Put))
+        .map(|entry| entry.key)
+        .collect();
+    keys.sort();
+
+    Ok(keys
+        .into_iter()
+        .skip(offset as usize)
+        .take(limit.map_or(usize::MAX, |limit| limit as usize))
+        .collect())
+}
+
+/// Returns all retained revisions of a key, oldest first.
+///
+/// Fails with `NatsErrorKind::StreamNotFound` if the bucket does not exist.
+#[flutter_rust_bridge::frb]
+pub async fn kv_history(
+    client_id: String,
+    bucket: String,
+    key: String,
+) -> Result<Vec<KvEntry>, NatsError> {
+    check_kv_key(&key)?;
+
+    let store = get_store(&client_id, &bucket).await?;
+
+    read_entries(&client_id, &store, &key, DeliverPolicy::All, false)
+        .await
+        .map_err(|e| {
+            NatsError::new(
+                NatsErrorKind::Other,
+                format!("Failed to get history of '{}': {}", key, e),
+            )
+        })
+}
+
+/// Removes a key and all of its history, leaving a single purge marker.
+#[flutter_rust_bridge::frb]
+pub async fn kv_purge(client_id: String, bucket: String, key: String) -> Result<(), NatsError> {
+    check_kv_key(&key)?;
+
+    let store = get_store(&client_id, &bucket).await?;
+
+    store.purge(&key).await.map_err(|e| {
+        NatsError::new(
+            NatsErrorKind::Other,
+            format!("Failed to purge '{}': {}", key, e),
+        )
+    })
+}
+
+/// Removes the history of deleted and purged keys and returns how many keys were cleaned up.
+///
+/// Delete markers older than `older_than_ms` are removed entirely, newer ones are kept so
+/// watchers still see the deletion. Without `older_than_ms` all markers are removed.
+#[flutter_rust_bridge::frb]
+pub async fn kv_purge_deletes(
+    client_id: String,
+    bucket: String,
+    older_than_ms: Option<u64>,
+) -> Result<u64, NatsError> {
+    let store = get_store(&client_id, &bucket).await?;
+    let cutoff_ms = older_than_ms
+        .map(|older_than| unix_millis(time::OffsetDateTime::now_utc()) - older_than as i64);
+
+    let mut purged = 0;
+    for entry in latest_entries(&client_id, &store, ">", true).await? {
+        if matches!(entry.operation, KvOperation::Put) {
+            continue;
+        }
+
+        let keep = match cutoff_ms {
+            Some(cutoff_ms) if entry.created_ms > cutoff_ms => 1,
+            _ => 0,
+        };
+        store
+            .stream
+            .purge()
+            .filter(format!("{}{}", store.prefix, entry.key))
+            .keep(keep)
+            .await
+            .map_err(|e| {
+                NatsError::new(
+                    NatsErrorKind::Other,
+                    format!("Failed to purge '{}': {}", entry.key, e),
+                )
+            })?;
+        purged += 1;
+    }
+
+    Ok(purged)
+}
@@ -0,0 +1,517 @@
+use crate::api::error::{NatsError, NatsErrorKind};
+use crate::api::jetstream::unix_millis;
+use crate::api::kv::{get_store, latest_entry, message_to_kv_entry, KvOperation};
+use crate::api::nats::{check_payload_size, get_client, put_kv_value, ClientId, Registry};
+use crate::api::outbox::write_atomically;
+use crate::api::subject::check_kv_key;
+use crate::frb_generated::StreamSink;
+use async_nats::connection::State;
+use async_nats::jetstream::consumer::{pull, DeliverPolicy};
+use async_nats::jetstream::kv;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{Mutex, RwLock};
+use tokio_stream::StreamExt;
+
+/// A value read from the local replica of a bucket.
+///
+/// `stale` is set while the client is offline or the replica has not caught up with the
+/// server yet, and `pending` while a local write is waiting to be reconciled.
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug)]
+pub struct CachedKvValue {
+    pub value: String,
+    pub revision: u64,
+    pub stale: bool,
+    pub pending: bool,
+    pub synced_ms: Option<i64>,
+}
+
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Copy, Debug)]
+pub enum KvCacheEventKind {
+    Synced,
+    Reconciled,
+    Conflict,
+}
+
+/// A change to the state of a local replica.
+///
+/// `Synced` is sent once the replica caught up with the server. `Reconciled` and `Conflict`
+/// report the outcome of a write made offline; on conflict the server value wins.
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug)]
+pub struct KvCacheEvent {
+    pub kind: KvCacheEventKind,
+    pub key: Option<String>,
+    pub revision: Option<u64>,
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Clone, Default, Serialize, Deserialize)]
+struct CachedEntry {
+    value: String,
+    revision: u64,
+}
+
+/// A write made offline, to be applied if the key is still at `base_revision`
+#[derive(Clone, Serialize, Deserialize)]
+struct PendingWrite {
+    key: String,
+    value: String,
+    base_revision: u64,
+}
+
+/// The replica persisted to disk
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Default, Serialize, Deserialize)]
+struct Replica {
+    entries: HashMap<String, CachedEntry>,
+    pending: Vec<PendingWrite>,
+    synced_ms: Option<i64>,
+}
+
+struct KvCache {
+    path: String,
+    replica: Replica,
+    synced: bool,
+    sink: Option<StreamSink<KvCacheEvent>>,
+}
+
+// Store the local replica of each cached bucket per client
+static KV_CACHES: Registry<(ClientId, String), Arc<Mutex<KvCache>>> =
+    Lazy::new(|| Arc::new(RwLock::new(HashMap::new())));
+
+impl KvCache {
+    fn emit(&self, kind: KvCacheEventKind, key: Option<String>, revision: Option<u64>) {
+        if let Some(sink) = &self.sink {
+            let _ = sink.add(KvCacheEvent {
+                kind,
+                key,
+                revision,
+            });
+        }
+    }
+
+    async fn persist(&self) -> Result<(), String> {
+        let contents = serde_json::to_string(&self.replica)
+            .map_err(|e| format!("Failed to encode KV cache: {}", e))?;
+        write_atomically(&self.path, contents).await
+    }
+
+    /// Applies a write locally, merging it with an earlier write of the same key
+    fn queue_write(&mut self, key: String, value: String) {
+        let base_revision = self
+            .replica
+            .entries
+            .get(&key)
+            .map_or(0, |entry| entry.revision);
+
+        match self
+            .replica
+            .pending
+            .iter_mut()
+            .find(|write| write.key == key)
+        {
+            Some(write) => write.value = value.clone(),
+            None => self.replica.pending.push(PendingWrite {
+                key: key.clone(),
+                value: value.clone(),
+                base_revision,
+            }),
+        }
+
+        self.replica.entries.insert(
+            key,
+            CachedEntry {
+                value,
+                revision: base_revision,
+            },
+        );
+    }
+
+    /// Applies writes made offline with revision checks, stopping at the first failure to retry later
+    async fn reconcile(&mut self, client_id: &str, store: &kv::Store) -> Result<(), String> {
+        while let Some(write) = self.replica.pending.first().cloned() {
+            let result = put_kv_value(
+                client_id,
+                store,
+                &write.key,
+                write.value.clone().into_bytes(),
+                &[],
+                Some(write.base_revision),
+            )
+            .await;
+
+            match result {
+                Ok(revision) => {
+                    self.replica.pending.remove(0);
+                    if let Some(entry) = self.replica.entries.get_mut(&write.key) {
+                        entry.revision = revision;
+                    }
+                    self.emit(
+                        KvCacheEventKind::Reconciled,
+                        Some(write.key),
+                        Some(revision),
+                    );
+                }
+                Err(e) if e.kind == NatsErrorKind::WrongRevision => {
+                    // The server value wins over the local write
+                    self.replica.pending.remove(0);
+                    let revision = match latest_entry(client_id, store, &write.key).await {
+                        Ok(Some(entry)) if matches!(entry.operation, KvOperation::Put) => {
+                            self.apply(
+                                write.key.clone(),
+                                entry.value,
+                                entry.revision,
+                                KvOperation::Put,
+                            );
+                            Some(entry.revision)
+                        }
+                        Ok(_) => {
+                            self.replica.entries.remove(&write.key);
+                            None
+                        }
+                        Err(_) => None,
+                    };
+                    self.emit(KvCacheEventKind::Conflict, Some(write.key), revision);
+                }
+                Err(e) => {
+                    self.persist().await?;
+                    return Err(e.message);
+                }
+            }
+        }
+
+        self.persist().await
+    }
+
+    /// Applies an entry delivered by the watcher
+    fn apply(&mut self, key: String, value: String, revision: u64, operation: KvOperation) {
+        // A local write waiting to be reconciled shadows the server value
+        if self.replica.pending.iter().any(|write| write.key == key) {
+            return;
+        }
+
+        match operation {
+            KvOperation::Put => {
+                self.replica
+                    .entries
+                    .insert(key, CachedEntry { value, revision });
+            }
+            KvOperation::Delete | KvOperation::Purge => {
+                self.replica.entries.remove(&key);
+            }
+        }
+    }
+
+    async fn mark_synced(&mut self) {
+        self.synced = true;
+        self.replica.synced_ms = Some(unix_millis(time::OffsetDateTime::now_utc()));
+        let _ = self.persist().await;
+        self.emit(KvCacheEventKind::Synced, None, None);
+    }
+}
+
+/// Helper function to check whether a client is currently connected
+async fn is_online(client_id: &str) -> bool {
+    match get_client(client_id).await {
+        Ok(client) => client.connection_state() == State::Connected,
+        Err(_) => false,
+    }
+}
+
+/// Helper function to check that a cache is still the one registered for its bucket
+async fn is_current(cache_key: &(ClientId, String), cache: &Arc<Mutex<KvCache>>) -> bool {
+    KV_CACHES
+        .read()
+        .await
+        .get(cache_key)
+        .is_some_and(|current| Arc::ptr_eq(current, cache))
+}
+
+/// Internal function to keep a replica current until its cache is disabled
+async fn sync_replica(cache_key: (ClientId, String), cache: Arc<Mutex<KvCache>>) {
+    let (client_id, bucket) = cache_key.clone();
+
+    while is_current(&cache_key, &cache).await {
+        if !is_online(&client_id).await {
+            cache.lock().await.synced = false;
+            tokio::time::sleep(Duration::from_millis(500)).await;
+            continue;
+        }
+
+        // Watch the latest value of every key, then every update
+        let store = match get_store(&client_id, &bucket).await {
+            Ok(store) => store,
+            Err(_) => {
+                tokio::time::sleep(Duration::from_secs(2)).await;
+                continue;
+            }
+        };
+        let watch_consumer = match store
+            .stream
+            .create_consumer(pull::OrderedConfig {
+                description: Some("kv cache consumer".to_string()),
+                filter_subject: format!("{}>", store.prefix),
+                deliver_policy: DeliverPolicy::LastPerSubject,
+                ..Default::default()
+            })
+            .await
+        {
+            Ok(consumer) => consumer,
+            Err(_) => {
+                tokio::time::sleep(Duration::from_secs(2)).await;
+                continue;
+            }
+        };
+        if watch_consumer.cached_info().num_pending == 0 {
+            cache.lock().await.mark_synced().await;
+        }
+        let mut messages = match watch_consumer.messages().await {
+            Ok(messages) => messages,
+            Err(_) => {
+                tokio::time::sleep(Duration::from_secs(2)).await;
+                continue;
+            }
+        };
+
+        // Reconcile on a timer too, so a busy bucket does not hold back offline writes
+        let mut reconcile_interval = tokio::time::interval(Duration::from_millis(500));
+        while is_current(&cache_key, &cache).await {
+            let maybe_msg = tokio::select! {
+                message = messages.next() => match message {
+                    Some(Ok(message)) => message,
+                    Some(Err(_)) | None => break,
+                },
+                _ = reconcile_interval.tick() => {
+                    // Reconcile offline writes whenever the connection is back
+                    let online = is_online(&client_id).await;
+                    let mut cache = cache.lock().await;
+                    if !online {
+                        cache.synced = false;
+                    } else if !cache.replica.pending.is_empty() {
+                        let _ = cache.reconcile(&client_id, &store).await;
+                    }
+                    continue;
+                }
+            };
+
+            let Ok(entry) = message_to_kv_entry(&client_id, &store, &maybe_msg).await else {
+                continue;
+            };
+            let mut cache = cache.lock().await;
+            let caught_up = entry.delta == 0;
+            cache.apply(entry.key, entry.value, entry.revision, entry.operation);
+            if caught_up {
+                cache.mark_synced().await;
+            }
+        }
+    }
+}
+
+/// Keeps a local replica of a KV bucket, persisted at `path`, current through a watcher.
+///
+/// The replica left at `path` by a previous run is loaded first, so reads work offline
+/// right after the app starts. A replica file that cannot be decoded is moved to
+/// `<path>.corrupt`, keeping any offline writes in it, and the cache starts empty.
+#[flutter_rust_bridge::frb]
+pub async fn enable_kv_cache(
+    client_id: String,
+    bucket: String,
+    path: String,
+) -> Result<(), String> {
+    get_client(&client_id).await?;
+
+    let cache_key = (client_id.clone(), bucket.clone());
+    if KV_CACHES.read().await.contains_key(&cache_key) {
+        return Err(format!(
+            "KV cache for bucket '{}' is already enabled",
+            bucket
+        ));
+    }
+
+    let replica = match tokio::fs::read_to_string(&path).await {
+        Ok(contents) => match serde_json::from_str(&contents) {
+            Ok(replica) => replica,
+            Err(_) => {
+                // Keep the corrupt file out of the way of the next persist
+                let backup_path = format!("{}.corrupt", path);
+                tokio::fs::rename(&path, &backup_path)
+                    .await
+                    .map_err(|e| format!("Failed to back up KV cache '{}': {}", path, e))?;
+                Replica::default()
+            }
+        },
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Replica::default(),
+        Err(e) => return Err(format!("Failed to read KV cache '{}': {}", path, e)),
+    };
+
+    let cache = Arc::new(Mutex::new(KvCache {
+        path,
+        replica,
+        synced: false,
+        sink: None,
+    }));
+    KV_CACHES
+        .write()
+        .await
+        .insert(cache_key.clone(), cache.clone());
+
+    tokio::spawn(sync_replica(cache_key, cache));
+    Ok(())
+}
+
+/// Stops keeping the local replica of a bucket current. The replica file is kept.
+#[flutter_rust_bridge::frb]
+pub async fn disable_kv_cache(client_id: String, bucket: String) -> Result<(), String> {
+    KV_CACHES
+        .write()
+        .await
+        .remove(&(client_id, bucket.clone()))
+        .map(|_| ())
+        .ok_or_else(|| format!("KV cache for bucket '{}' is not enabled", bucket))
+}
+
+/// Streams sync and reconciliation events of a bucket's local replica to Dart.
+#[flutter_rust_bridge::frb]
+pub async fn kv_cache_events(
+    client_id: String,
+    bucket: String,
+    sink: StreamSink<KvCacheEvent>,
+) -> Result<(), String> {
+    let cache = get_cache(&client_id, &bucket)
+        .await
+        .ok_or_else(|| format!("KV cache for bucket '{}' is not enabled", bucket))?;
+
+    cache.lock().await.sink = Some(sink);
+    Ok(())
+}
+
+/// Reads a value from the local replica of a bucket, or `None` if the key has no value.
+#[flutter_rust_bridge::frb]
+pub async fn kv_cache_get(
+    client_id: String,
+    bucket: String,
+    key: String,
+) -> Result<Option<CachedKvValue>, String> {
+    let cache = get_cache(&client_id, &bucket)
+        .await
+        .ok_or_else(|| format!("KV cache for bucket '{}' is not enabled", bucket))?;
+    let online = is_online(&client_id).await;
+
+    let cache = cache.lock().await;
+    Ok(cache.replica.entries.get(&key).map(|entry| CachedKvValue {
+        value: entry.value.clone(),
+        revision: entry.revision,
+        stale: !online || !cache.synced,
+        pending: cache.replica.pending.iter().any(|write| write.key == key),
+        synced_ms: cache.replica.synced_ms,
+    }))
+}
+
+/// Writes a value through the local replica of a bucket.
+///
+/// Online, the value is written only if the key is still at the cached revision and the new
+/// revision is returned. Offline, the write is applied locally, persisted and `None` is
+/// returned; it is reconciled with the same revision check once the client reconnects.
+#[flutter_rust_bridge::frb]
+pub async fn kv_cache_put(
+    client_id: String,
+    bucket: String,
+    key: String,
+    value: String,
+) -> Result<Option<u64>, NatsError> {
+    check_kv_key(&key)?;
+
+    let cache = get_cache(&client_id, &bucket).await.ok_or_else(|| {
+        NatsError::new(
+            NatsErrorKind::Other,
+            format!("KV cache for bucket '{}' is not enabled", bucket),
+        )
+    })?;
+    let online = is_online(&client_id).await;
+    let mut cache = cache.lock().await;
+
+    let has_pending = cache.replica.pending.iter().any(|write| write.key == key);
+    if !online || has_pending {
+        check_payload_size(&client_id, value.len(), None).await?;
+        cache.queue_write(key, value);
+        cache
+            .persist()
+            .await
+            .map_err(|e| NatsError::new(NatsErrorKind::Other, e))?;
+        return Ok(None);
+    }
+
+    let store = get_store(&client_id, &bucket).await?;
+    let expected_revision = cache
+        .replica
+        .entries
+        .get(&key)
+        .map_or(0, |entry| entry.revision);
+    let revision = put_kv_value(
+        &client_id,
+        &store,
+        &key,
+        value.clone().into_bytes(),
+        &[],
+        Some(expected_revision),
+    )
+    .await?;
+
+    cache
+        .replica
+        .entries
+        .insert(key, CachedEntry { value, revision });
+    Ok(Some(revision))
+}
+
+/// Helper function to get the cache of a bucket, if enabled
+async fn get_cache(client_id: &str, bucket: &str) -> Option<Arc<Mutex<KvCache>>> {
+    KV_CACHES
+        .read()
+        .await
+        .get(&(client_id.to_string(), bucket.to_string()))
+        .cloned()
+}
+
+/// Serves a read from the local replica while the client is offline.
+///
+/// Returns `None` when the read should go to the server. A key missing from a replica that
+/// never synced is reported as unknown rather than not found.
+pub(crate) async fn offline_value(
+    client_id: &str,
+    bucket: &str,
+    key: &str,
+) -> Option<Result<Option<String>, String>> {
+    let cache = get_cache(client_id, bucket).await?;
+    if is_online(client_id).await {
+        return None;
+    }
+
+    let cache = cache.lock().await;
+    let value = cache
+        .replica
+        .entries
+        .get(key)
+        .map(|entry| entry.value.clone());
+    if value.is_none() && cache.replica.synced_ms.is_none() {
+        return Some(Err(format!(
+            "Value of key '{}' is unknown: the KV cache for bucket '{}' has not synced yet",
+            key, bucket
+        )));
+    }
+    Some(Ok(value))
+}
+
+/// Stops the replicas of a disconnected client, keeping their files for the next run.
+pub(crate) async fn cleanup_client_kv_caches(client_id: &str) {
+    KV_CACHES
+        .write()
+        .await
+        .retain(|(cid, _), _| cid != client_id);
+}
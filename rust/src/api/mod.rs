@@ -1 +1,14 @@
+pub mod codec;
+pub mod compression;
+pub mod consumer;
+pub mod credentials;
+pub mod encryption;
+pub mod error;
+pub mod jetstream;
+pub mod kv;
+pub mod kv_cache;
 pub mod nats;
+pub mod object_store;
+pub mod outbox;
+pub mod service;
+pub mod subject;
@@ -1,32 +1,52 @@
+use crate::api::compression::{compress_for_client, decompress_payload};
+use crate::api::encryption::{open_for_client, seal_for_client, seal_reply};
+use crate::api::error::{NatsError, NatsErrorKind};
+use crate::api::subject::{check_kv_key, check_subject};
 use anyhow::Result;
-use async_nats::{self, Client};
+use async_nats::jetstream::context::AccountErrorKind;
+use async_nats::jetstream::stream::LastRawMessageErrorKind;
+use async_nats::jetstream::ErrorCode;
+use async_nats::{self, Client, ConnectOptions};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
 use flutter_rust_bridge::DartFnFuture;
 use once_cell::sync::Lazy;
 use std::collections::HashMap;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::RwLock;
 use tokio_stream::StreamExt;
 
 /// Multiple clients support for NATS
-type ClientId = String;
-type SubscriptionId = String;
+pub(crate) type ClientId = String;
+pub(crate) type SubscriptionId = String;
+
+/// A shared, lock-protected registry keyed by `K`
+pub(crate) type Registry<K, V> = Lazy<Arc<RwLock<HashMap<K, V>>>>;
 
 // A thread-safe map of client IDs to NATS clients
-static NATS_CLIENTS: Lazy<Arc<RwLock<HashMap<ClientId, Client>>>> =
+static NATS_CLIENTS: Registry<ClientId, Client> =
+    Lazy::new(|| Arc::new(RwLock::new(HashMap::new())));
+
+// Store JetStream contexts per client, configured with the client's domain or API prefix
+static JETSTREAM_CONTEXTS: Registry<ClientId, async_nats::jetstream::Context> =
     Lazy::new(|| Arc::new(RwLock::new(HashMap::new())));
 
 // Store JetStream Key-Value contexts per client
-static KV_STORES: Lazy<Arc<RwLock<HashMap<(ClientId, String), async_nats::jetstream::kv::Store>>>> =
+static KV_STORES: Registry<(ClientId, String), async_nats::jetstream::kv::Store> =
+    Lazy::new(|| Arc::new(RwLock::new(HashMap::new())));
+
+// Store whether missing KV buckets are created on first use, per client (enabled by default)
+static KV_AUTO_CREATE: Registry<ClientId, bool> =
     Lazy::new(|| Arc::new(RwLock::new(HashMap::new())));
 
 // Store active subscriptions per client
-static SUBSCRIPTIONS: Lazy<
-    Arc<RwLock<HashMap<(ClientId, SubscriptionId), async_nats::Subscriber>>>,
-> = Lazy::new(|| Arc::new(RwLock::new(HashMap::new())));
+static SUBSCRIPTIONS: Registry<(ClientId, SubscriptionId), async_nats::Subscriber> =
+    Lazy::new(|| Arc::new(RwLock::new(HashMap::new())));
 
 // Store a flag for each subscription indicating if it should continue
-static SUBSCRIPTION_ACTIVE: Lazy<Arc<RwLock<HashMap<(ClientId, SubscriptionId), bool>>>> =
+static SUBSCRIPTION_ACTIVE: Registry<(ClientId, SubscriptionId), bool> =
     Lazy::new(|| Arc::new(RwLock::new(HashMap::new())));
 
 #[flutter_rust_bridge::frb(unignore)]
@@ -51,6 +71,95 @@ pub struct ReconnectionConfig {
     pub delay: Option<u64>,
 }
 
+/// Credentials returned by an auth callback. Only the fields the server expects need to be set.
+///
+/// `signature` is the server nonce signed with the user's nkey, as returned by `nkey_sign`.
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug, Default)]
+pub struct AuthCredentials {
+    pub token: Option<String>,
+    pub user: Option<String>,
+    pub pass: Option<String>,
+    pub jwt: Option<String>,
+    pub nkey: Option<String>,
+    pub signature: Option<String>,
+}
+
+/// The server a client is connected to, as announced by the server.
+///
+/// `jetstream_enabled` tells whether JetStream is available to the client's account, or is
+/// `None` when that could not be determined, e.g. because the check timed out or was denied.
+/// The cluster name the server announces is not included, as async-nats does not expose it.
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug)]
+pub struct ServerInfo {
+    pub server_id: String,
+    pub server_name: String,
+    pub version: String,
+    pub host: String,
+    pub port: u16,
+    pub max_payload: u64,
+    pub client_id: u64,
+    pub client_ip: String,
+    pub connect_urls: Vec<String>,
+    pub auth_required: bool,
+    pub tls_required: bool,
+    pub lame_duck_mode: bool,
+    pub jetstream_enabled: Option<bool>,
+}
+
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Copy, Debug)]
+pub enum ConnectionState {
+    Pending,
+    Connected,
+    Disconnected,
+}
+
+/// Traffic of a client since it was created. Bytes exclude the protocol overhead.
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug)]
+pub struct ConnectionStats {
+    pub in_messages: u64,
+    pub out_messages: u64,
+    pub in_bytes: u64,
+    pub out_bytes: u64,
+    pub reconnects: u64,
+    pub state: ConnectionState,
+}
+
+impl From<async_nats::connection::State> for ConnectionState {
+    fn from(state: async_nats::connection::State) -> Self {
+        match state {
+            async_nats::connection::State::Pending => ConnectionState::Pending,
+            async_nats::connection::State::Connected => ConnectionState::Connected,
+            async_nats::connection::State::Disconnected => ConnectionState::Disconnected,
+        }
+    }
+}
+
+impl TryFrom<AuthCredentials> for async_nats::Auth {
+    type Error = String;
+
+    fn try_from(credentials: AuthCredentials) -> Result<Self, Self::Error> {
+        let mut auth = async_nats::Auth::new();
+        auth.token = credentials.token;
+        auth.username = credentials.user;
+        auth.password = credentials.pass;
+        auth.jwt = credentials.jwt;
+        auth.nkey = credentials.nkey;
+        auth.signature = credentials
+            .signature
+            .map(|signature| {
+                URL_SAFE_NO_PAD
+                    .decode(signature.trim().trim_end_matches('='))
+                    .map_err(|e| format!("Invalid signature encoding: {}", e))
+            })
+            .transpose()?;
+        Ok(auth)
+    }
+}
+
 /// Initializes flutter_rust_bridge's default utilities.
 #[flutter_rust_bridge::frb(init)]
 pub fn init_app() {
@@ -58,7 +167,7 @@ pub fn init_app() {
 }
 
 /// Helper function to get a client by ID with proper error handling
-async fn get_client(client_id: &str) -> Result<Client, String> {
+pub(crate) async fn get_client(client_id: &str) -> Result<Client, String> {
     let clients = NATS_CLIENTS.read().await;
     clients
         .get(client_id)
@@ -66,6 +175,111 @@ async fn get_client(client_id: &str) -> Result<Client, String> {
         .ok_or_else(|| format!("Client with ID '{}' not found", client_id))
 }
 
+/// Helper function to convert message headers into a map of header names to their values
+pub(crate) fn headers_to_map(headers: &async_nats::HeaderMap) -> HashMap<String, Vec<String>> {
+    headers
+        .iter()
+        .map(|(name, values)| {
+            (
+                name.to_string(),
+                values.iter().map(|value| value.to_string()).collect(),
+            )
+        })
+        .collect()
+}
+
+/// Helper function to get the size of headers as they are encoded on the wire
+fn encoded_headers_len(headers: &async_nats::HeaderMap) -> usize {
+    // `NATS/1.0\r\n`, a `Name: value\r\n` line per value and a closing `\r\n`
+    let lines: usize = headers
+        .iter()
+        .map(|(name, values)| {
+            let name_len = name.to_string().len();
+            values
+                .iter()
+                .map(|value| name_len + value.as_str().len() + 4)
+                .sum::<usize>()
+        })
+        .sum();
+    "NATS/1.0\r\n".len() + lines + 2
+}
+
+/// Helper function to reject a message larger than the `max_payload` of the client's server.
+///
+/// The server counts the encoded headers towards the limit, so they are included in the size.
+pub(crate) async fn check_payload_size(
+    client_id: &str,
+    size: usize,
+    headers: Option<&async_nats::HeaderMap>,
+) -> Result<(), NatsError> {
+    let client = get_client(client_id)
+        .await
+        .map_err(|e| NatsError::new(NatsErrorKind::ClientNotFound, e))?;
+
+    let size = size + headers.map_or(0, encoded_headers_len);
+
+    // The limit is unknown until the server sent its info
+    let max_payload = client.server_info().max_payload;
+    if max_payload > 0 && size > max_payload {
+        return Err(NatsError::payload_too_large(
+            size as u64,
+            max_payload as u64,
+        ));
+    }
+    Ok(())
+}
+
+/// Helper function to compress and encrypt an outgoing payload with the client's settings
+pub(crate) async fn compress_and_seal(
+    client_id: &str,
+    subject: &str,
+    payload: Vec<u8>,
+) -> Result<(Vec<u8>, Option<async_nats::HeaderMap>), NatsError> {
+    let (payload, headers) = compress_for_client(client_id, payload).await?;
+    Ok(seal_for_client(client_id, subject, payload, headers).await?)
+}
+
+/// Helper function to compress and encrypt an outgoing payload with the client's settings,
+/// rejecting it if the result is larger than the server accepts
+pub(crate) async fn prepare_payload(
+    client_id: &str,
+    subject: &str,
+    payload: Vec<u8>,
+) -> Result<(Vec<u8>, Option<async_nats::HeaderMap>), NatsError> {
+    let (payload, headers) = compress_and_seal(client_id, subject, payload).await?;
+    check_payload_size(client_id, payload.len(), headers.as_ref()).await?;
+    Ok((payload, headers))
+}
+
+/// Helper function to compress a reply and encrypt it for the sender of an encrypted request
+async fn prepare_reply(
+    client_id: &str,
+    request_headers: Option<&async_nats::HeaderMap>,
+    payload: Vec<u8>,
+) -> Result<(Vec<u8>, Option<async_nats::HeaderMap>), NatsError> {
+    let (payload, headers) = compress_for_client(client_id, payload).await?;
+    let (payload, headers) = seal_reply(client_id, request_headers, payload, headers).await?;
+    check_payload_size(client_id, payload.len(), headers.as_ref()).await?;
+    Ok((payload, headers))
+}
+
+/// Returns the largest payload in bytes the server of a client accepts.
+#[flutter_rust_bridge::frb]
+pub async fn max_payload(client_id: String) -> Result<u64, String> {
+    let client = get_client(&client_id).await?;
+    Ok(client.server_info().max_payload as u64)
+}
+
+/// Helper function to decrypt and decompress a received payload according to its headers
+pub(crate) async fn read_payload(
+    client_id: &str,
+    headers: Option<&async_nats::HeaderMap>,
+    payload: &[u8],
+) -> Result<Vec<u8>, String> {
+    let opened = open_for_client(client_id, headers, payload).await?;
+    decompress_payload(client_id, headers, &opened).await
+}
+
 /// Connects to a NATS server with the specified client ID and calls appropriate callback based on result.
 #[flutter_rust_bridge::frb]
 pub async fn connect(
@@ -88,7 +302,65 @@ pub async fn connect(
     let end_point = format!("nats://{}:{}", config.host, config.port);
 
     // Connect to the NATS server (using the simple connect method)
-    match async_nats::connect(end_point).await {
+    connect_with_options(
+        client_id,
+        end_point,
+        ConnectOptions::new(),
+        on_success,
+        on_failure,
+    )
+    .await;
+}
+
+/// Connects to a NATS server, asking Dart for credentials on every connect and reconnect.
+///
+/// `auth_callback` receives the nonce sent by the server and returns the credentials to
+/// authenticate with, such as a fresh token or a user JWT with the nonce signed by its nkey.
+/// The credentials in `config` are not used.
+#[flutter_rust_bridge::frb]
+pub async fn connect_with_auth_callback(
+    client_id: String,
+    config: NatsConfig,
+    auth_callback: impl Fn(Vec<u8>) -> DartFnFuture<AuthCredentials> + Send + Sync + 'static,
+    on_success: impl Fn(bool) -> DartFnFuture<()>,
+    on_failure: impl Fn(String) -> DartFnFuture<()>,
+) {
+    // Check if this client ID already exists
+    {
+        let clients = NATS_CLIENTS.read().await;
+        if clients.contains_key(&client_id) {
+            drop(clients);
+            on_failure(format!("Client with ID '{}' already exists", client_id)).await;
+            return;
+        }
+    }
+
+    // Build the endpoint from config
+    let end_point = format!("nats://{}:{}", config.host, config.port);
+
+    // The Dart future is run on its own task, as the client needs a future it can share
+    let options = ConnectOptions::with_auth_callback(move |nonce| {
+        let credentials = tokio::spawn(auth_callback(nonce));
+        async move {
+            let credentials = credentials
+                .await
+                .map_err(|e| async_nats::AuthError::new(e.to_string()))?;
+            async_nats::Auth::try_from(credentials).map_err(async_nats::AuthError::new)
+        }
+    });
+
+    connect_with_options(client_id, end_point, options, on_success, on_failure).await;
+}
+
+/// Helper function to connect a client with options and store it
+async fn connect_with_options(
+    client_id: String,
+    end_point: String,
+    options: ConnectOptions,
+    on_success: impl Fn(bool) -> DartFnFuture<()>,
+    on_failure: impl Fn(String) -> DartFnFuture<()>,
+) {
+    match options.connect(end_point).await {
         Ok(client) => {
             // Store the new client
             {
@@ -136,11 +408,22 @@ async fn cleanup_client_subscriptions(client_id: &str) {
         active_map.retain(|(cid, _), _| cid != client_id);
     }
 
-    // Clean up KV stores for this client
+    // Clean up KV stores and the JetStream context for this client
     {
         let mut kv_stores = KV_STORES.write().await;
         kv_stores.retain(|(cid, _), _| cid != client_id);
     }
+    JETSTREAM_CONTEXTS.write().await.remove(client_id);
+    KV_AUTO_CREATE.write().await.remove(client_id);
+
+    // Stop services hosted by this client
+    crate::api::service::cleanup_client_services(client_id).await;
+
+    // Stop replaying the outbox and syncing KV caches, keeping their files on disk
+    crate::api::outbox::cleanup_client_outbox(client_id).await;
+    crate::api::kv_cache::cleanup_client_kv_caches(client_id).await;
+    crate::api::compression::cleanup_client_compression(client_id).await;
+    crate::api::encryption::cleanup_client_encryption(client_id).await;
 }
 
 /// Disconnects a specific client from the NATS server.
@@ -173,23 +456,74 @@ pub async fn send_request(
     subject: String,
     payload: String,
     timeout_ms: u64,
-) -> Result<String, String> {
+) -> Result<String, NatsError> {
+    check_subject(&subject, false)?;
+
     // Get the client
-    let client = get_client(&client_id).await?;
+    let client = get_client(&client_id)
+        .await
+        .map_err(|e| NatsError::new(NatsErrorKind::ClientNotFound, e))?;
 
-    // Create payload as bytes
-    let payload_bytes = payload.into_bytes();
+    // Create payload as bytes, compressed and encrypted if enabled for this client
+    let (payload_bytes, headers) =
+        prepare_payload(&client_id, &subject, payload.into_bytes()).await?;
     let timeout = Duration::from_millis(timeout_ms);
 
-    // Send request with timeout
-    let response = tokio::time::timeout(timeout, client.request(subject, payload_bytes.into()))
+    // Send request with timeout, with headers only if the payload needs them
+    let mut request = async_nats::Request::new().payload(payload_bytes.into());
+    if let Some(headers) = headers {
+        request = request.headers(headers);
+    }
+    let response = tokio::time::timeout(timeout, client.send_request(subject, request))
         .await
-        .map_err(|_| "Request timed out".to_string())?
-        .map_err(|e| e.to_string())?;
+        .map_err(|_| NatsError::new(NatsErrorKind::TimedOut, "Request timed out"))??;
 
     // Convert response payload to string
-    String::from_utf8(response.payload.to_vec())
-        .map_err(|e| format!("Invalid UTF-8 in response: {}", e))
+    let response_payload =
+        read_payload(&client_id, response.headers.as_ref(), &response.payload).await?;
+    String::from_utf8(response_payload).map_err(|e| {
+        NatsError::new(
+            NatsErrorKind::Other,
+            format!("Invalid UTF-8 in response: {}", e),
+        )
+    })
+}
+
+/// Helper function to send a request and gather every reply received before the timeout
+pub(crate) async fn request_many(
+    client_id: &str,
+    subject: String,
+    payload: String,
+    timeout_ms: u64,
+) -> Result<Vec<async_nats::Message>, String> {
+    // Get the client
+    let client = get_client(client_id).await?;
+
+    // Subscribe to a dedicated inbox before publishing so no reply is missed
+    let inbox = client.new_inbox();
+    let mut subscriber = client
+        .subscribe(inbox.clone())
+        .await
+        .map_err(|e| format!("Failed to subscribe: {}", e))?;
+    client
+        .publish_with_reply(subject, inbox, payload.into_bytes().into())
+        .await
+        .map_err(|e| e.to_string())?;
+
+    // Collect replies until the timeout elapses
+    let deadline = tokio::time::Instant::now() + Duration::from_millis(timeout_ms);
+    let mut replies = Vec::new();
+    while let Ok(Some(msg)) = tokio::time::timeout_at(deadline, subscriber.next()).await {
+        match msg.status {
+            // Nobody listens on the subject, so no reply will follow
+            Some(async_nats::StatusCode::NO_RESPONDERS) => break,
+            Some(_) => continue,
+            None => replies.push(msg),
+        }
+    }
+
+    let _ = subscriber.unsubscribe().await;
+    Ok(replies)
 }
 
 /// Sends a request to NATS server using the specified client and handles response via callbacks.
@@ -200,7 +534,7 @@ pub async fn _send_request_with_callbacks(
     payload: String,
     timeout_ms: u64,
     on_success: impl Fn(String) -> DartFnFuture<()>,
-    on_failure: impl Fn(String) -> DartFnFuture<()>,
+    on_failure: impl Fn(NatsError) -> DartFnFuture<()>,
 ) {
     // Use the send_request function and handle its result with callbacks
     match send_request(client_id, subject, payload, timeout_ms).await {
@@ -220,26 +554,65 @@ pub async fn publish(
     subject: String,
     payload: String,
     on_success: impl Fn(bool) -> DartFnFuture<()>,
-    on_failure: impl Fn(String) -> DartFnFuture<()>,
+    on_failure: impl Fn(NatsError) -> DartFnFuture<()>,
 ) {
+    if let Err(e) = check_subject(&subject, false) {
+        on_failure(e).await;
+        return;
+    }
+
     // Get the client
     match get_client(&client_id).await {
         Ok(client) => {
-            // Create payload as bytes
-            let payload_bytes = payload.into_bytes();
+            // Queue the message in the client's outbox while offline
+            match crate::api::outbox::enqueue_if_offline(&client_id, &client, &subject, &payload)
+                .await
+            {
+                Ok(true) => {
+                    on_success(true).await;
+                    return;
+                }
+                Ok(false) => {}
+                Err(e) => {
+                    on_failure(e).await;
+                    return;
+                }
+            }
+
+            // Compress and encrypt the payload if enabled for this client
+            let (payload_bytes, headers) =
+                match prepare_payload(&client_id, &subject, payload.into_bytes()).await {
+                    Ok(result) => result,
+                    Err(e) => {
+                        on_failure(e).await;
+                        return;
+                    }
+                };
 
             // Publish the message
-            match client.publish(subject, payload_bytes.into()).await {
+            let result = match headers {
+                Some(headers) => {
+                    client
+                        .publish_with_headers(subject, headers, payload_bytes.into())
+                        .await
+                }
+                None => client.publish(subject, payload_bytes.into()).await,
+            };
+            match result {
                 Ok(_) => {
                     on_success(true).await;
                 }
                 Err(e) => {
-                    on_failure(e.to_string()).await;
+                    on_failure(NatsError::new(
+                        NatsErrorKind::Other,
+                        format!("Failed to publish: {}", e),
+                    ))
+                    .await;
                 }
             }
         }
         Err(e) => {
-            on_failure(e).await;
+            on_failure(NatsError::new(NatsErrorKind::ClientNotFound, e)).await;
         }
     }
 }
@@ -254,6 +627,11 @@ pub async fn setup_responder(
     on_success: impl Fn(bool) -> DartFnFuture<()>,
     on_error: impl Fn(String) -> DartFnFuture<()> + Send + 'static,
 ) {
+    if let Err(e) = check_subject(&subject, true) {
+        on_error(e.to_string()).await;
+        return;
+    }
+
     // Get the client
     let client = match get_client(&client_id).await {
         Ok(client) => client,
@@ -321,8 +699,18 @@ async fn get_next_message(sub_key: &(ClientId, SubscriptionId)) -> Option<async_
     }
 }
 
+/// Helper function to mark a subscription as active, returns false if it already exists
+pub(crate) async fn activate_subscription(sub_key: &(ClientId, SubscriptionId)) -> bool {
+    let mut active_map = SUBSCRIPTION_ACTIVE.write().await;
+    if active_map.contains_key(sub_key) {
+        return false;
+    }
+    active_map.insert(sub_key.clone(), true);
+    true
+}
+
 /// Helper function to check if a subscription is active
-async fn is_subscription_active(sub_key: &(ClientId, SubscriptionId)) -> bool {
+pub(crate) async fn is_subscription_active(sub_key: &(ClientId, SubscriptionId)) -> bool {
     let active_map = SUBSCRIPTION_ACTIVE.read().await;
     active_map.get(sub_key).copied().unwrap_or(false)
 }
@@ -352,22 +740,40 @@ async fn process_responder_requests(
         // Process the message if we got one
         if let Some(msg) = maybe_msg {
             if let Some(reply_to) = msg.reply {
-                // Convert request payload to string
-                match String::from_utf8(msg.payload.to_vec()) {
+                // Decrypt, decompress and convert request payload to string
+                let request_payload = read_payload(&sub_key.0, msg.headers.as_ref(), &msg.payload)
+                    .await
+                    .and_then(|payload| {
+                        String::from_utf8(payload)
+                            .map_err(|e| format!("Invalid UTF-8 in request: {}", e))
+                    });
+                match request_payload {
                     Ok(request_payload) => {
                         // Call handler to get response
                         let response = process_request(request_payload).await;
 
-                        // Send response back
-                        match client.publish(reply_to, response.into_bytes().into()).await {
-                            Ok(_) => {} // Response sent successfully
-                            Err(e) => {
-                                on_error(format!("Failed to send response: {}", e)).await;
-                            }
+                        // Send response back, compressed if enabled for this client and
+                        // encrypted for the requester if the request was encrypted
+                        let reply =
+                            prepare_reply(&sub_key.0, msg.headers.as_ref(), response.into_bytes())
+                                .await;
+                        let result = match reply {
+                            Ok((payload, Some(headers))) => client
+                                .publish_with_headers(reply_to, headers, payload.into())
+                                .await
+                                .map_err(|e| e.to_string()),
+                            Ok((payload, None)) => client
+                                .publish(reply_to, payload.into())
+                                .await
+                                .map_err(|e| e.to_string()),
+                            Err(e) => Err(e.to_string()),
+                        };
+                        if let Err(e) = result {
+                            on_error(format!("Failed to send response: {}", e)).await;
                         }
                     }
                     Err(e) => {
-                        on_error(format!("Invalid UTF-8 in request: {}", e)).await;
+                        on_error(e).await;
                     }
                 }
             }
@@ -386,7 +792,7 @@ async fn process_responder_requests(
 }
 
 /// Helper function to clean up a subscription
-async fn cleanup_subscription(sub_key: &(ClientId, SubscriptionId)) {
+pub(crate) async fn cleanup_subscription(sub_key: &(ClientId, SubscriptionId)) {
     // Clean up
     {
         let mut subs = SUBSCRIPTIONS.write().await;
@@ -399,6 +805,7 @@ async fn cleanup_subscription(sub_key: &(ClientId, SubscriptionId)) {
 }
 
 /// Subscribes to a subject and receives messages via a callback using the specified client.
+#[allow(clippy::too_many_arguments)]
 #[flutter_rust_bridge::frb]
 pub async fn subscribe(
     client_id: String,
@@ -407,14 +814,19 @@ pub async fn subscribe(
     max_messages: u32,
     on_message: impl Fn(String, String) -> DartFnFuture<()> + Send + 'static,
     on_success: impl Fn(bool) -> DartFnFuture<()>,
-    on_error: impl Fn(String) -> DartFnFuture<()> + Send + 'static,
+    on_error: impl Fn(NatsError) -> DartFnFuture<()> + Send + 'static,
     on_done: impl Fn() -> DartFnFuture<()> + Send + 'static,
 ) {
+    if let Err(e) = check_subject(&subject, true) {
+        on_error(e).await;
+        return;
+    }
+
     // Get the client
     let client = match get_client(&client_id).await {
         Ok(client) => client,
         Err(e) => {
-            on_error(e).await;
+            on_error(NatsError::new(NatsErrorKind::ClientNotFound, e)).await;
             return;
         }
     };
@@ -427,9 +839,12 @@ pub async fn subscribe(
         let subs = SUBSCRIPTIONS.read().await;
         if subs.contains_key(&sub_key) {
             drop(subs);
-            on_error(format!(
-                "Subscription '{}' for client '{}' already exists",
-                subscription_id, client_id
+            on_error(NatsError::new(
+                NatsErrorKind::Other,
+                format!(
+                    "Subscription '{}' for client '{}' already exists",
+                    subscription_id, client_id
+                ),
             ))
             .await;
             return;
@@ -469,7 +884,11 @@ pub async fn subscribe(
             });
         }
         Err(e) => {
-            on_error(format!("Failed to subscribe: {}", e)).await;
+            on_error(NatsError::new(
+                NatsErrorKind::Other,
+                format!("Failed to subscribe: {}", e),
+            ))
+            .await;
         }
     }
 }
@@ -480,7 +899,7 @@ async fn process_subscription_messages(
     sub_key: (ClientId, SubscriptionId),
     max_messages: u32,
     on_message: impl Fn(String, String) -> DartFnFuture<()>,
-    on_error: impl Fn(String) -> DartFnFuture<()>,
+    on_error: impl Fn(NatsError) -> DartFnFuture<()>,
     on_done: impl Fn() -> DartFnFuture<()>,
 ) {
     let mut message_count = 0;
@@ -503,14 +922,20 @@ async fn process_subscription_messages(
         // Process the message if we got one
         match maybe_msg {
             Some(msg) => {
-                // Convert payload to string
-                match String::from_utf8(msg.payload.to_vec()) {
+                // Decrypt, decompress and convert payload to string
+                let payload = read_payload(&sub_key.0, msg.headers.as_ref(), &msg.payload)
+                    .await
+                    .and_then(|payload| {
+                        String::from_utf8(payload)
+                            .map_err(|e| format!("Invalid UTF-8 in message: {}", e))
+                    });
+                match payload {
                     Ok(payload) => {
                         on_message(subject.clone(), payload).await;
                         message_count += 1;
                     }
                     Err(e) => {
-                        on_error(format!("Invalid UTF-8 in message: {}", e)).await;
+                        on_error(e.into()).await;
                     }
                 }
             }
@@ -584,15 +1009,95 @@ pub async fn list_clients() -> Vec<String> {
     clients.keys().cloned().collect()
 }
 
+/// Returns information about the server a client is connected to.
+#[flutter_rust_bridge::frb]
+pub async fn server_info(client_id: String) -> Result<ServerInfo, String> {
+    let (client, jetstream) = get_jetstream(&client_id).await?;
+    let info = client.server_info();
+
+    // The server info does not say whether JetStream is enabled, so ask the JetStream API
+    let jetstream_enabled = match jetstream.query_account().await {
+        Ok(_) => Some(true),
+        Err(e) => match e.kind() {
+            // Nothing answers the JetStream API when the server runs without it
+            AccountErrorKind::JetStreamUnavailable => Some(false),
+            AccountErrorKind::JetStream(error)
+                if error.error_code() == ErrorCode::JETSTREAM_NOT_ENABLED
+                    || error.error_code() == ErrorCode::JETSTREAM_NOT_ENABLED_FOR_ACCOUNT =>
+            {
+                Some(false)
+            }
+            _ => None,
+        },
+    };
+
+    Ok(ServerInfo {
+        server_id: info.server_id,
+        server_name: info.server_name,
+        version: info.version,
+        host: info.host,
+        port: info.port,
+        max_payload: info.max_payload as u64,
+        client_id: info.client_id,
+        client_ip: info.client_ip,
+        connect_urls: info.connect_urls,
+        auth_required: info.auth_required,
+        tls_required: info.tls_required,
+        lame_duck_mode: info.lame_duck_mode,
+        jetstream_enabled,
+    })
+}
+
+/// Returns the traffic counters and connection state of a client.
+#[flutter_rust_bridge::frb]
+pub async fn connection_stats(client_id: String) -> Result<ConnectionStats, String> {
+    let client = get_client(&client_id).await?;
+    let statistics = client.statistics();
+
+    // The initial connect is counted as well
+    let connects = statistics.connects.load(Ordering::Relaxed);
+    Ok(ConnectionStats {
+        in_messages: statistics.in_messages.load(Ordering::Relaxed),
+        out_messages: statistics.out_messages.load(Ordering::Relaxed),
+        in_bytes: statistics.in_bytes.load(Ordering::Relaxed),
+        out_bytes: statistics.out_bytes.load(Ordering::Relaxed),
+        reconnects: connects.saturating_sub(1),
+        state: client.connection_state().into(),
+    })
+}
+
 /// Helper function to get a JetStream context for a client
-async fn get_jetstream(
+///
+/// Uses the context configured for the client, falling back to the default `$JS.API` context.
+pub(crate) async fn get_jetstream(
     client_id: &str,
 ) -> Result<(Client, async_nats::jetstream::Context), String> {
     let client = get_client(client_id).await?;
+
+    if let Some(jetstream) = JETSTREAM_CONTEXTS.read().await.get(client_id) {
+        return Ok((client, jetstream.clone()));
+    }
+
     let jetstream = async_nats::jetstream::new(client.clone());
     Ok((client, jetstream))
 }
 
+/// Helper function to replace the JetStream context used by a client
+///
+/// KV stores cached for the client were bound to the previous context, so they are dropped.
+pub(crate) async fn set_jetstream_context(
+    client_id: &str,
+    jetstream: async_nats::jetstream::Context,
+) {
+    JETSTREAM_CONTEXTS
+        .write()
+        .await
+        .insert(client_id.to_string(), jetstream);
+
+    let mut kv_stores = KV_STORES.write().await;
+    kv_stores.retain(|(cid, _), _| cid != client_id);
+}
+
 /// Helper function to get a KV store with error handling via callback
 async fn get_kv_store_with_callback<F>(
     client_id: &str,
@@ -600,13 +1105,13 @@ async fn get_kv_store_with_callback<F>(
     on_failure: &F,
 ) -> Option<async_nats::jetstream::kv::Store>
 where
-    F: Fn(String) -> DartFnFuture<()>,
+    F: Fn(NatsError) -> DartFnFuture<()>,
 {
     // Get the client and JetStream context
     let (_, jetstream) = match get_jetstream(client_id).await {
         Ok(result) => result,
         Err(e) => {
-            on_failure(e).await;
+            on_failure(NatsError::new(NatsErrorKind::ClientNotFound, e)).await;
             return None;
         }
     };
@@ -615,7 +1120,7 @@ where
     match get_or_create_kv_store(&jetstream, client_id, bucket_name).await {
         Ok(store) => Some(store),
         Err(e) => {
-            on_failure(format!("Failed to access KV bucket: {}", e)).await;
+            on_failure(e).await;
             None
         }
     }
@@ -629,35 +1134,64 @@ pub async fn kv_put(
     key: String,
     value: String,
     on_success: impl Fn(bool) -> DartFnFuture<()>,
-    on_failure: impl Fn(String) -> DartFnFuture<()>,
+    on_failure: impl Fn(NatsError) -> DartFnFuture<()>,
 ) {
+    if let Err(e) = check_kv_key(&key) {
+        on_failure(e).await;
+        return;
+    }
+
     // Get the KV store with error handling
     let store = match get_kv_store_with_callback(&client_id, &bucket_name, &on_failure).await {
         Some(store) => store,
         None => return,
     };
 
-    // Put the value
-    let bytes_value = bytes::Bytes::from(value.into_bytes());
-    match store.put(&key, bytes_value).await {
+    // Compress and encrypt the value if enabled for this client, then put it
+    let result = put_kv_value(&client_id, &store, &key, value.into_bytes(), &[], None).await;
+    match result {
         Ok(_) => {
             on_success(true).await;
         }
         Err(e) => {
-            on_failure(format!("Failed to store value: {}", e)).await;
+            on_failure(e).await;
         }
     }
 }
 
+/// Helper function to build the error reported for a key without a value
+fn key_not_found(key: &str) -> NatsError {
+    NatsError::new(NatsErrorKind::Other, format!("Key '{}' not found", key))
+}
+
 /// Gets a value from the key-value store using JetStream for the specified client.
+///
+/// `on_success` receives the value and whether it is stale, i.e. served from the bucket's
+/// local replica while the client is offline.
 #[flutter_rust_bridge::frb]
 pub async fn kv_get(
     client_id: String,
     bucket_name: String,
     key: String,
-    on_success: impl Fn(String) -> DartFnFuture<()>,
-    on_failure: impl Fn(String) -> DartFnFuture<()>,
+    on_success: impl Fn(String, bool) -> DartFnFuture<()>,
+    on_failure: impl Fn(NatsError) -> DartFnFuture<()>,
 ) {
+    if let Err(e) = check_kv_key(&key) {
+        on_failure(e).await;
+        return;
+    }
+
+    // Serve the value from the bucket's local replica while offline
+    if let Some(cached) = crate::api::kv_cache::offline_value(&client_id, &bucket_name, &key).await
+    {
+        match cached {
+            Ok(Some(value)) => on_success(value, true).await,
+            Ok(None) => on_failure(key_not_found(&key)).await,
+            Err(e) => on_failure(e.into()).await,
+        }
+        return;
+    }
+
     // Get the KV store with error handling
     let store = match get_kv_store_with_callback(&client_id, &bucket_name, &on_failure).await {
         Some(store) => store,
@@ -665,27 +1199,174 @@ pub async fn kv_get(
     };
 
     // Get the value
-    match store.get(&key).await {
-        Ok(Some(entry)) => {
+    match get_kv_value(&client_id, &store, &key).await {
+        Ok(Some((entry, _))) => {
             // Convert entry to string
-            match String::from_utf8(entry.to_vec()) {
+            match String::from_utf8(entry) {
                 Ok(value) => {
-                    on_success(value).await;
+                    on_success(value, false).await;
                 }
                 Err(e) => {
-                    on_failure(format!("Invalid UTF-8 in value: {}", e)).await;
+                    on_failure(NatsError::new(
+                        NatsErrorKind::Other,
+                        format!("Invalid UTF-8 in value: {}", e),
+                    ))
+                    .await;
                 }
             }
         }
         Ok(None) => {
-            on_failure(format!("Key '{}' not found", key)).await;
+            on_failure(key_not_found(&key)).await;
         }
         Err(e) => {
-            on_failure(format!("Failed to get value: {}", e)).await;
+            on_failure(NatsError::new(
+                NatsErrorKind::Other,
+                format!("Failed to get value: {}", e),
+            ))
+            .await;
+        }
+    }
+}
+
+/// Helper function to compress and encrypt a KV value with the client's settings and put it,
+/// along with `headers` such as its `Content-Type`. Returns the revision of the value.
+///
+/// With `expected_revision` the put only succeeds if the key is still at that revision, where
+/// 0 means the key has no value, and fails with `NatsErrorKind::WrongRevision` otherwise.
+/// Puts to buckets reached through a JetStream domain need the domain's API prefix, which is
+/// not exposed, so those values are stored uncompressed, without headers, and cannot be
+/// encrypted.
+pub(crate) async fn put_kv_value(
+    client_id: &str,
+    store: &async_nats::jetstream::kv::Store,
+    key: &str,
+    value: Vec<u8>,
+    headers: &[(&str, &str)],
+    expected_revision: Option<u64>,
+) -> Result<u64, NatsError> {
+    let subject = format!("{}{}", store.prefix, key);
+    if store.use_jetstream_prefix {
+        let sealed = seal_for_client(client_id, &subject, Vec::new(), None)
+            .await
+            .map_err(|e| NatsError::new(NatsErrorKind::Other, e))?;
+        if sealed.1.is_some() {
+            return Err(NatsError::new(
+                NatsErrorKind::Other,
+                format!(
+                    "Cannot encrypt values of bucket '{}' reached through a JetStream domain",
+                    store.name
+                ),
+            ));
+        }
+        check_payload_size(client_id, value.len(), None).await?;
+        return match expected_revision {
+            None => store.put(key, value.into()).await.map_err(|e| {
+                NatsError::new(NatsErrorKind::Other, format!("Failed to put key: {}", e))
+            }),
+            Some(0) => Ok(store.create(key, value.into()).await?),
+            Some(revision) => Ok(store.update(key, value.into(), revision).await?),
+        };
+    }
+
+    let (value, prepared_headers) = compress_and_seal(client_id, &subject, value).await?;
+    let mut prepared_headers = prepared_headers.unwrap_or_default();
+    for (name, header_value) in headers {
+        prepared_headers.insert(*name, *header_value);
+    }
+    let Some(expected_revision) = expected_revision else {
+        if prepared_headers.is_empty() {
+            check_payload_size(client_id, value.len(), None).await?;
+            return store.put(key, value.into()).await.map_err(|e| {
+                NatsError::new(NatsErrorKind::Other, format!("Failed to put key: {}", e))
+            });
+        }
+        check_payload_size(client_id, value.len(), Some(&prepared_headers)).await?;
+        return put_kv_with_headers(client_id, store, key, prepared_headers, value).await;
+    };
+
+    let mut expected = expected_revision;
+    loop {
+        let mut revision_headers = prepared_headers.clone();
+        revision_headers.insert(
+            "Nats-Expected-Last-Subject-Sequence",
+            expected.to_string().as_str(),
+        );
+        check_payload_size(client_id, value.len(), Some(&revision_headers)).await?;
+        match put_kv_with_headers(client_id, store, key, revision_headers, value.clone()).await {
+            Err(e) if e.kind == NatsErrorKind::WrongLastSequence => {
+                // A deleted or purged key has no value, so it can be created again
+                if expected_revision == 0 && expected == 0 {
+                    if let Ok(marker) = store.stream.get_last_raw_message_by_subject(&subject).await
+                    {
+                        let operation = marker.headers.get("KV-Operation").map(|v| v.as_str());
+                        if matches!(operation, Some("DEL") | Some("PURGE")) {
+                            expected = marker.sequence;
+                            continue;
+                        }
+                    }
+                }
+                let message = if expected_revision == 0 {
+                    format!("Key '{}' already exists", key)
+                } else {
+                    format!("Key '{}' is not at revision {}", key, expected_revision)
+                };
+                return Err(NatsError::new(NatsErrorKind::WrongRevision, message));
+            }
+            result => return result,
         }
     }
 }
 
+/// Helper function to put a KV value with headers, such as its `Content-Encoding`
+async fn put_kv_with_headers(
+    client_id: &str,
+    store: &async_nats::jetstream::kv::Store,
+    key: &str,
+    headers: async_nats::HeaderMap,
+    value: Vec<u8>,
+) -> Result<u64, NatsError> {
+    let (_, jetstream) = get_jetstream(client_id)
+        .await
+        .map_err(|e| NatsError::new(NatsErrorKind::ClientNotFound, e))?;
+    let subject = format!(
+        "{}{}",
+        store.put_prefix.as_ref().unwrap_or(&store.prefix),
+        key
+    );
+    let ack = jetstream
+        .publish_with_headers(subject, headers, value.into())
+        .await?
+        .await?;
+    Ok(ack.sequence)
+}
+
+/// Helper function to get the latest value of a key and its headers, decrypted and
+/// decompressed according to those headers
+pub(crate) async fn get_kv_value(
+    client_id: &str,
+    store: &async_nats::jetstream::kv::Store,
+    key: &str,
+) -> Result<Option<(Vec<u8>, async_nats::HeaderMap)>, String> {
+    let subject = format!("{}{}", store.prefix, key);
+    let message = match store.stream.get_last_raw_message_by_subject(&subject).await {
+        Ok(message) => message,
+        Err(e) if e.kind() == LastRawMessageErrorKind::NoMessageFound => return Ok(None),
+        Err(e) => return Err(e.to_string()),
+    };
+
+    // Deleted and purged keys have no value
+    let operation = message
+        .headers
+        .get("KV-Operation")
+        .map(|value| value.as_str());
+    if matches!(operation, Some("DEL") | Some("PURGE")) {
+        return Ok(None);
+    }
+
+    let value = read_payload(client_id, Some(&message.headers), &message.payload).await?;
+    Ok(Some((value, message.headers)))
+}
+
 /// Deletes a key from the key-value store using JetStream for the specified client.
 #[flutter_rust_bridge::frb]
 pub async fn kv_delete(
@@ -693,8 +1374,13 @@ pub async fn kv_delete(
     bucket_name: String,
     key: String,
     on_success: impl Fn(bool) -> DartFnFuture<()>,
-    on_failure: impl Fn(String) -> DartFnFuture<()>,
+    on_failure: impl Fn(NatsError) -> DartFnFuture<()>,
 ) {
+    if let Err(e) = check_kv_key(&key) {
+        on_failure(e).await;
+        return;
+    }
+
     // Get the KV store with error handling
     let store = match get_kv_store_with_callback(&client_id, &bucket_name, &on_failure).await {
         Some(store) => store,
@@ -707,41 +1393,78 @@ pub async fn kv_delete(
             on_success(true).await;
         }
         Err(e) => {
-            on_failure(format!("Failed to delete key: {}", e)).await;
+            on_failure(NatsError::new(
+                NatsErrorKind::Other,
+                format!("Failed to delete key: {}", e),
+            ))
+            .await;
         }
     }
 }
 
+/// Enables or disables creating missing KV buckets on first use in `kv_put`, `kv_get` and `kv_delete`.
+///
+/// With auto-creation disabled, buckets must be created with `kv_create_bucket` first.
+#[flutter_rust_bridge::frb]
+pub async fn set_kv_auto_create(client_id: String, enabled: bool) -> Result<(), String> {
+    get_client(&client_id).await?;
+    KV_AUTO_CREATE.write().await.insert(client_id, enabled);
+    Ok(())
+}
+
+/// Helper function to get a KV store for a client, creating it if auto-creation is enabled
+pub(crate) async fn get_kv_store(
+    client_id: &str,
+    bucket_name: &str,
+) -> Result<async_nats::jetstream::kv::Store, NatsError> {
+    let (_, jetstream) = get_jetstream(client_id)
+        .await
+        .map_err(|e| NatsError::new(NatsErrorKind::ClientNotFound, e))?;
+    get_or_create_kv_store(&jetstream, client_id, bucket_name).await
+}
+
+/// Helper function to cache a KV store for a client
+pub(crate) async fn cache_kv_store(
+    client_id: &str,
+    bucket_name: &str,
+    store: async_nats::jetstream::kv::Store,
+) {
+    let mut stores = KV_STORES.write().await;
+    stores.insert((client_id.to_string(), bucket_name.to_string()), store);
+}
+
+/// Helper function to drop a cached KV store for a client
+pub(crate) async fn evict_kv_store(client_id: &str, bucket_name: &str) {
+    let mut stores = KV_STORES.write().await;
+    stores.remove(&(client_id.to_string(), bucket_name.to_string()));
+}
+
 /// Helper function to get or create a KV store for a specific client.
 async fn get_or_create_kv_store(
     jetstream: &async_nats::jetstream::Context,
     client_id: &str,
     bucket_name: &str,
-) -> Result<async_nats::jetstream::kv::Store, String> {
+) -> Result<async_nats::jetstream::kv::Store, NatsError> {
     // Create a composite key for storing in the cache
     let cache_key = (client_id.to_string(), bucket_name.to_string());
 
-    // Check if we have this store cached
-    {
-        let stores = KV_STORES.read().await;
-        if let Some(store) = stores.get(&cache_key) {
-            return Ok(store.clone());
-        }
-    }
-
-    // Not cached, try to get from server
-    match jetstream.get_key_value(bucket_name).await {
-        Ok(store) => {
-            // Cache the store
-            {
-                let mut stores = KV_STORES.write().await;
-                stores.insert(cache_key, store.clone());
-            }
-            Ok(store)
-        }
-        Err(e) => {
-            // If error is "stream not found", try to create it
-            if e.to_string().contains("stream not found") {
+    match lookup_kv_store(jetstream, client_id, bucket_name).await {
+        Ok(Some(store)) => Ok(store),
+        Ok(None) => {
+            let auto_create = KV_AUTO_CREATE
+                .read()
+                .await
+                .get(client_id)
+                .copied()
+                .unwrap_or(true);
+
+            // The bucket does not exist, try to create it unless auto-creation is disabled
+            if !auto_create {
+                Err(NatsError::new(
+                    NatsErrorKind::StreamNotFound,
+                    format!("KV bucket '{}' not found", bucket_name),
+                ))
+            } else {
                 // Create KV bucket configuration
                 let config = async_nats::jetstream::kv::Config {
                     bucket: bucket_name.to_string(),
@@ -761,11 +1484,84 @@ async fn get_or_create_kv_store(
                         }
                         Ok(store)
                     }
-                    Err(create_err) => Err(format!("Failed to create KV bucket: {}", create_err)),
+                    Err(create_err) => Err(NatsError::new(
+                        NatsErrorKind::Other,
+                        format!("Failed to create KV bucket: {}", create_err),
+                    )),
                 }
-            } else {
-                Err(format!("Failed to access KV bucket: {}", e))
             }
         }
+        Err(e) => Err(NatsError::new(NatsErrorKind::Other, e)),
+    }
+}
+
+/// Helper function to get a cached or existing KV store, or `None` if the bucket does not exist
+async fn lookup_kv_store(
+    jetstream: &async_nats::jetstream::Context,
+    client_id: &str,
+    bucket_name: &str,
+) -> Result<Option<async_nats::jetstream::kv::Store>, String> {
+    // Create a composite key for storing in the cache
+    let cache_key = (client_id.to_string(), bucket_name.to_string());
+
+    // Check if we have this store cached
+    {
+        let stores = KV_STORES.read().await;
+        if let Some(store) = stores.get(&cache_key) {
+            return Ok(Some(store.clone()));
+        }
+    }
+
+    // Not cached, try to get from server
+    match jetstream.get_key_value(bucket_name).await {
+        Ok(store) => {
+            // Cache the store
+            {
+                let mut stores = KV_STORES.write().await;
+                stores.insert(cache_key, store.clone());
+            }
+            Ok(Some(store))
+        }
+        Err(e) if e.to_string().contains("stream not found") => Ok(None),
+        Err(e) => Err(format!("Failed to access KV bucket: {}", e)),
+    }
+}
+
+/// Helper function to get an existing KV store, never creating the bucket
+pub(crate) async fn open_kv_store(
+    client_id: &str,
+    bucket_name: &str,
+) -> Result<async_nats::jetstream::kv::Store, NatsError> {
+    let (_, jetstream) = get_jetstream(client_id)
+        .await
+        .map_err(|e| NatsError::new(NatsErrorKind::ClientNotFound, e))?;
+
+    lookup_kv_store(&jetstream, client_id, bucket_name)
+        .await
+        .map_err(|e| NatsError::new(NatsErrorKind::Other, e))?
+        .ok_or_else(|| {
+            NatsError::new(
+                NatsErrorKind::StreamNotFound,
+                format!("KV bucket '{}' not found", bucket_name),
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encoded_headers_len_matches_the_wire_format() {
+        let mut headers = async_nats::HeaderMap::new();
+        assert_eq!(encoded_headers_len(&headers), "NATS/1.0\r\n\r\n".len());
+
+        headers.insert("Content-Encoding", "gzip");
+        headers.append("Xkey-Sender", "a");
+        headers.append("Xkey-Sender", "bc");
+        assert_eq!(
+            encoded_headers_len(&headers),
+            "NATS/1.0\r\nContent-Encoding: gzip\r\nXkey-Sender: a\r\nXkey-Sender: bc\r\n\r\n".len()
+        );
     }
 }
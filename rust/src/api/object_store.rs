@@ -0,0 +1,503 @@
+use crate::api::jetstream::{unix_millis, StorageType};
+use crate::api::nats::{
+    activate_subscription, cleanup_subscription, get_jetstream, is_subscription_active,
+};
+use crate::frb_generated::StreamSink;
+use async_nats::jetstream::object_store;
+use flutter_rust_bridge::DartFnFuture;
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, ReadBuf};
+use tokio::sync::mpsc;
+use tokio_stream::StreamExt;
+
+/// Configuration of a JetStream Object Store bucket.
+///
+/// Limits left as `None` use the server defaults, which means unlimited.
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug)]
+pub struct ObjectStoreConfig {
+    pub bucket: String,
+    pub description: Option<String>,
+    pub max_age_ms: Option<u64>,
+    pub max_bytes: Option<i64>,
+    pub storage: StorageType,
+    pub num_replicas: Option<u32>,
+    pub compression: bool,
+}
+
+/// Metadata of an object being stored.
+///
+/// `chunk_size` defaults to 128KiB.
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug)]
+pub struct ObjectMeta {
+    pub name: String,
+    pub description: Option<String>,
+    pub metadata: HashMap<String, String>,
+    pub chunk_size: Option<u32>,
+}
+
+/// The target of an object link. A link without an object name links a whole bucket.
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug)]
+pub struct ObjectLink {
+    pub bucket: String,
+    pub name: Option<String>,
+}
+
+/// Information about an object stored in an Object Store bucket.
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug)]
+pub struct ObjectInfo {
+    pub bucket: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub metadata: HashMap<String, String>,
+    pub size: u64,
+    pub chunks: u64,
+    pub modified_ms: Option<i64>,
+    pub digest: Option<String>,
+    pub deleted: bool,
+    pub link: Option<ObjectLink>,
+}
+
+impl From<ObjectStoreConfig> for object_store::Config {
+    fn from(config: ObjectStoreConfig) -> Self {
+        let defaults = object_store::Config::default();
+
+        object_store::Config {
+            bucket: config.bucket,
+            description: config.description,
+            max_age: config
+                .max_age_ms
+                .map(Duration::from_millis)
+                .unwrap_or(defaults.max_age),
+            max_bytes: config.max_bytes.unwrap_or(defaults.max_bytes),
+            storage: config.storage.into(),
+            num_replicas: config
+                .num_replicas
+                .map(|replicas| replicas as usize)
+                .unwrap_or(defaults.num_replicas),
+            compression: config.compression,
+            ..defaults
+        }
+    }
+}
+
+impl From<ObjectMeta> for object_store::ObjectMetadata {
+    fn from(meta: ObjectMeta) -> Self {
+        object_store::ObjectMetadata {
+            name: meta.name,
+            description: meta.description,
+            chunk_size: meta.chunk_size.map(|size| size as usize),
+            metadata: meta.metadata,
+            headers: None,
+        }
+    }
+}
+
+impl From<object_store::ObjectInfo> for ObjectInfo {
+    fn from(info: object_store::ObjectInfo) -> Self {
+        ObjectInfo {
+            bucket: info.bucket,
+            name: info.name,
+            description: info.description,
+            metadata: info.metadata,
+            size: info.size as u64,
+            chunks: info.chunks as u64,
+            modified_ms: info.modified.map(unix_millis),
+            digest: info.digest,
+            deleted: info.deleted,
+            link: info
+                .options
+                .and_then(|options| options.link)
+                .map(|link| ObjectLink {
+                    bucket: link.bucket,
+                    name: link.name,
+                }),
+        }
+    }
+}
+
+/// Reader reporting how many bytes have been read so far
+struct ProgressReader<R> {
+    inner: R,
+    transferred: u64,
+    progress: mpsc::UnboundedSender<u64>,
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for ProgressReader<R> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        let before = buf.filled().len();
+        let result = Pin::new(&mut self.inner).poll_read(cx, buf);
+
+        let read = (buf.filled().len() - before) as u64;
+        if read > 0 {
+            self.transferred += read;
+            let _ = self.progress.send(self.transferred);
+        }
+        result
+    }
+}
+
+/// Helper function to get an Object Store bucket
+async fn get_object_store(
+    client_id: &str,
+    bucket: &str,
+) -> Result<object_store::ObjectStore, String> {
+    let (_, jetstream) = get_jetstream(client_id).await?;
+
+    jetstream
+        .get_object_store(bucket)
+        .await
+        .map_err(|e| format!("Failed to get object store '{}': {}", bucket, e))
+}
+
+/// Internal function to store an object, reporting progress as chunks are read
+async fn put_with_progress(
+    store: &object_store::ObjectStore,
+    meta: ObjectMeta,
+    reader: impl AsyncRead + Unpin,
+    total: u64,
+    on_progress: impl Fn(u64, u64) -> DartFnFuture<()>,
+) -> Result<ObjectInfo, String> {
+    let name = meta.name.clone();
+    let (progress, mut progress_rx) = mpsc::unbounded_channel();
+    let mut reader = ProgressReader {
+        inner: reader,
+        transferred: 0,
+        progress,
+    };
+
+    let result = {
+        let put = store.put(object_store::ObjectMetadata::from(meta), &mut reader);
+        tokio::pin!(put);
+
+        loop {
+            tokio::select! {
+                result = &mut put => break result,
+                Some(transferred) = progress_rx.recv() => on_progress(transferred, total).await,
+            }
+        }
+    };
+
+    // Report progress read just before the upload finished
+    let mut last = None;
+    while let Ok(transferred) = progress_rx.try_recv() {
+        last = Some(transferred);
+    }
+    if let Some(transferred) = last {
+        on_progress(transferred, total).await;
+    }
+
+    result
+        .map(ObjectInfo::from)
+        .map_err(|e| format!("Failed to put object '{}': {}", name, e))
+}
+
+/// Helper function to open an object for reading, following links
+async fn open_object(
+    store: &object_store::ObjectStore,
+    name: &str,
+) -> Result<object_store::Object, String> {
+    store
+        .get(name)
+        .await
+        .map_err(|e| format!("Failed to get object '{}': {}", name, e))
+}
+
+/// Internal function to read an object into a writer, reporting progress per chunk
+async fn get_with_progress(
+    mut object: object_store::Object,
+    name: &str,
+    writer: &mut (impl AsyncWrite + Unpin),
+    on_progress: impl Fn(u64, u64) -> DartFnFuture<()>,
+) -> Result<ObjectInfo, String> {
+    let info = ObjectInfo::from(object.info().clone());
+
+    // Empty objects have no chunks to wait for
+    if info.size == 0 {
+        return Ok(info);
+    }
+
+    let mut chunk = vec![0; 64 * 1024];
+    let mut transferred = 0;
+    loop {
+        let read = object
+            .read(&mut chunk)
+            .await
+            .map_err(|e| format!("Failed to read object '{}': {}", name, e))?;
+        if read == 0 {
+            break;
+        }
+
+        writer
+            .write_all(&chunk[..read])
+            .await
+            .map_err(|e| format!("Failed to write object '{}': {}", name, e))?;
+        transferred += read as u64;
+        on_progress(transferred, info.size).await;
+    }
+
+    writer
+        .flush()
+        .await
+        .map_err(|e| format!("Failed to write object '{}': {}", name, e))?;
+    Ok(info)
+}
+
+/// Creates an Object Store bucket.
+#[flutter_rust_bridge::frb]
+pub async fn object_store_create(
+    client_id: String,
+    config: ObjectStoreConfig,
+) -> Result<(), String> {
+    let (_, jetstream) = get_jetstream(&client_id).await?;
+    let bucket = config.bucket.clone();
+
+    jetstream
+        .create_object_store(object_store::Config::from(config))
+        .await
+        .map(|_| ())
+        .map_err(|e| format!("Failed to create object store '{}': {}", bucket, e))
+}
+
+/// Deletes an Object Store bucket and all of its objects.
+#[flutter_rust_bridge::frb]
+pub async fn object_store_delete(client_id: String, bucket: String) -> Result<(), String> {
+    let (_, jetstream) = get_jetstream(&client_id).await?;
+
+    jetstream
+        .delete_object_store(&bucket)
+        .await
+        .map_err(|e| format!("Failed to delete object store '{}': {}", bucket, e))
+}
+
+/// Stores an object from bytes, calling `on_progress` with the bytes sent and the total size.
+#[flutter_rust_bridge::frb]
+pub async fn object_put(
+    client_id: String,
+    bucket: String,
+    meta: ObjectMeta,
+    data: Vec<u8>,
+    on_progress: impl Fn(u64, u64) -> DartFnFuture<()>,
+) -> Result<ObjectInfo, String> {
+    let store = get_object_store(&client_id, &bucket).await?;
+    let total = data.len() as u64;
+
+    put_with_progress(&store, meta, data.as_slice(), total, on_progress).await
+}
+
+/// Stores an object streamed from a local file, calling `on_progress` with the bytes sent and the
+/// file size.
+#[flutter_rust_bridge::frb]
+pub async fn object_put_file(
+    client_id: String,
+    bucket: String,
+    meta: ObjectMeta,
+    path: String,
+    on_progress: impl Fn(u64, u64) -> DartFnFuture<()>,
+) -> Result<ObjectInfo, String> {
+    let store = get_object_store(&client_id, &bucket).await?;
+
+    let file = tokio::fs::File::open(&path)
+        .await
+        .map_err(|e| format!("Failed to open '{}': {}", path, e))?;
+    let total = file
+        .metadata()
+        .await
+        .map_err(|e| format!("Failed to read '{}': {}", path, e))?
+        .len();
+
+    put_with_progress(&store, meta, file, total, on_progress).await
+}
+
+/// Reads an object into memory, following links, calling `on_progress` with the bytes received
+/// and the object size.
+#[flutter_rust_bridge::frb]
+pub async fn object_get(
+    client_id: String,
+    bucket: String,
+    name: String,
+    on_progress: impl Fn(u64, u64) -> DartFnFuture<()>,
+) -> Result<Vec<u8>, String> {
+    let store = get_object_store(&client_id, &bucket).await?;
+
+    let object = open_object(&store, &name).await?;
+
+    let mut data = Vec::new();
+    get_with_progress(object, &name, &mut data, on_progress).await?;
+    Ok(data)
+}
+
+/// Streams an object into a local file chunk by chunk, following links, and returns its info.
+///
+/// The object is written to `<path>.tmp` first, so an existing file at `path` is only replaced
+/// once the whole object was received.
+#[flutter_rust_bridge::frb]
+pub async fn object_get_file(
+    client_id: String,
+    bucket: String,
+    name: String,
+    path: String,
+    on_progress: impl Fn(u64, u64) -> DartFnFuture<()>,
+) -> Result<ObjectInfo, String> {
+    let store = get_object_store(&client_id, &bucket).await?;
+
+    let object = open_object(&store, &name).await?;
+
+    let temp_path = format!("{}.tmp", path);
+    let mut file = tokio::fs::File::create(&temp_path)
+        .await
+        .map_err(|e| format!("Failed to create '{}': {}", temp_path, e))?;
+    let info = match get_with_progress(object, &name, &mut file, on_progress).await {
+        Ok(info) => info,
+        Err(e) => {
+            drop(file);
+            let _ = tokio::fs::remove_file(&temp_path).await;
+            return Err(e);
+        }
+    };
+    drop(file);
+
+    tokio::fs::rename(&temp_path, &path)
+        .await
+        .map_err(|e| format!("Failed to write '{}': {}", path, e))?;
+    Ok(info)
+}
+
+/// Gets the info of an object without reading its data.
+#[flutter_rust_bridge::frb]
+pub async fn object_info(
+    client_id: String,
+    bucket: String,
+    name: String,
+) -> Result<ObjectInfo, String> {
+    let store = get_object_store(&client_id, &bucket).await?;
+
+    store
+        .info(&name)
+        .await
+        .map(ObjectInfo::from)
+        .map_err(|e| format!("Failed to get info of object '{}': {}", name, e))
+}
+
+/// Deletes an object from a bucket.
+#[flutter_rust_bridge::frb]
+pub async fn object_delete(client_id: String, bucket: String, name: String) -> Result<(), String> {
+    let store = get_object_store(&client_id, &bucket).await?;
+
+    store
+        .delete(&name)
+        .await
+        .map_err(|e| format!("Failed to delete object '{}': {}", name, e))
+}
+
+/// Lists the objects stored in a bucket, excluding deleted ones.
+#[flutter_rust_bridge::frb]
+pub async fn object_list(client_id: String, bucket: String) -> Result<Vec<ObjectInfo>, String> {
+    let store = get_object_store(&client_id, &bucket).await?;
+
+    let mut list = store
+        .list()
+        .await
+        .map_err(|e| format!("Failed to list objects: {}", e))?;
+
+    let mut objects = Vec::new();
+    while let Some(info) = list.next().await {
+        let info = info.map_err(|e| format!("Failed to list objects: {}", e))?;
+        objects.push(info.into());
+    }
+
+    Ok(objects)
+}
+
+/// Streams changes to the objects of a bucket to Dart until stopped with `unsubscribe`.
+///
+/// With `include_history` the current info of every object is delivered first.
+#[flutter_rust_bridge::frb]
+pub async fn object_watch(
+    client_id: String,
+    bucket: String,
+    include_history: bool,
+    subscription_id: String,
+    sink: StreamSink<ObjectInfo>,
+) -> Result<(), String> {
+    let store = get_object_store(&client_id, &bucket).await?;
+
+    let watch = if include_history {
+        store.watch_with_history().await
+    } else {
+        store.watch().await
+    };
+    let mut watch = watch.map_err(|e| format!("Failed to watch object store: {}", e))?;
+
+    // Register the watch so it can be listed and stopped like a subscription
+    let sub_key = (client_id.clone(), subscription_id.clone());
+    if !activate_subscription(&sub_key).await {
+        return Err(format!(
+            "Subscription '{}' for client '{}' already exists",
+            subscription_id, client_id
+        ));
+    }
+
+    while is_subscription_active(&sub_key).await {
+        // Wait for the next change with a small timeout so stops are noticed
+        let maybe_info = match tokio::time::timeout(Duration::from_millis(100), watch.next()).await
+        {
+            Ok(Some(info)) => info,
+            Ok(None) => break,
+            Err(_) => continue,
+        };
+
+        let sent = match maybe_info {
+            Ok(info) => sink.add(info.into()),
+            Err(e) => sink.add_error(format!("Failed to receive object info: {}", e)),
+        };
+
+        // The Dart side stopped listening
+        if sent.is_err() {
+            break;
+        }
+    }
+
+    cleanup_subscription(&sub_key).await;
+    Ok(())
+}
+
+/// Adds a link named `name` to an object, which may live in another bucket.
+#[flutter_rust_bridge::frb]
+pub async fn object_add_link(
+    client_id: String,
+    bucket: String,
+    name: String,
+    target: ObjectLink,
+) -> Result<ObjectInfo, String> {
+    let store = get_object_store(&client_id, &bucket).await?;
+
+    let info = match target.name {
+        Some(target_name) => {
+            let target_store = if target.bucket == bucket {
+                store.clone()
+            } else {
+                get_object_store(&client_id, &target.bucket).await?
+            };
+            let target_info = target_store
+                .info(&target_name)
+                .await
+                .map_err(|e| format!("Failed to get info of object '{}': {}", target_name, e))?;
+            store.add_link(&name, &target_info).await
+        }
+        None => store.add_bucket_link(&name, &target.bucket).await,
+    };
+
+    info.map(ObjectInfo::from)
+        .map_err(|e| format!("Failed to add link '{}': {}", name, e))
+}
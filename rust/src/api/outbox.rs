@@ -0,0 +1,408 @@
+use crate::api::error::{NatsError, NatsErrorKind};
+use crate::api::jetstream::unix_millis;
+use crate::api::nats::{check_payload_size, get_client, prepare_payload, ClientId, Registry};
+use crate::frb_generated::StreamSink;
+use async_nats::connection::State;
+use async_nats::Client;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::{Mutex, RwLock};
+
+/// Configuration of a client's persistent outbox.
+///
+/// Messages are appended to the log at `path` while the client is offline. When the outbox
+/// holds `max_messages` or `max_bytes` of payload, further publishes are rejected. Messages
+/// still queued `ttl_ms` after they were published are dropped instead of being delivered.
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug)]
+pub struct OutboxConfig {
+    pub path: String,
+    pub max_messages: Option<u64>,
+    pub max_bytes: Option<u64>,
+    pub ttl_ms: Option<u64>,
+}
+
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Copy, Debug)]
+pub enum OutboxEventKind {
+    Queued,
+    Delivered,
+    Expired,
+    Failed,
+}
+
+/// A change to a message held in the outbox.
+///
+/// `error` tells why a `Failed` message was dropped and is `None` for other events.
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug)]
+pub struct OutboxEvent {
+    pub kind: OutboxEventKind,
+    pub id: u64,
+    pub subject: String,
+    pub queued_ms: i64,
+    pub error: Option<String>,
+}
+
+/// The number of messages and payload bytes waiting in an outbox.
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug)]
+pub struct OutboxStatus {
+    pub pending_messages: u64,
+    pub pending_bytes: u64,
+}
+
+/// A message persisted to the outbox log, one JSON object per line
+#[derive(Clone, Serialize, Deserialize)]
+struct QueuedMessage {
+    id: u64,
+    subject: String,
+    payload: String,
+    queued_ms: i64,
+    expires_ms: Option<i64>,
+}
+
+struct Outbox {
+    config: OutboxConfig,
+    messages: VecDeque<QueuedMessage>,
+    next_id: u64,
+    sink: Option<StreamSink<OutboxEvent>>,
+}
+
+// Store the outbox of each client that enabled one
+static OUTBOXES: Registry<ClientId, Arc<Mutex<Outbox>>> =
+    Lazy::new(|| Arc::new(RwLock::new(HashMap::new())));
+
+/// Helper function to get the current time in milliseconds since the Unix epoch
+fn now_ms() -> i64 {
+    unix_millis(time::OffsetDateTime::now_utc())
+}
+
+impl Outbox {
+    fn pending_bytes(&self) -> u64 {
+        self.messages
+            .iter()
+            .map(|message| message.payload.len() as u64)
+            .sum()
+    }
+
+    fn emit(&self, kind: OutboxEventKind, message: &QueuedMessage) {
+        self.emit_with_error(kind, message, None);
+    }
+
+    fn emit_with_error(
+        &self,
+        kind: OutboxEventKind,
+        message: &QueuedMessage,
+        error: Option<String>,
+    ) {
+        if let Some(sink) = &self.sink {
+            let _ = sink.add(OutboxEvent {
+                kind,
+                id: message.id,
+                subject: message.subject.clone(),
+                queued_ms: message.queued_ms,
+                error,
+            });
+        }
+    }
+
+    /// Rewrites the log with the messages still queued
+    async fn persist(&self) -> Result<(), String> {
+        let mut contents = String::new();
+        for message in &self.messages {
+            let line = serde_json::to_string(message)
+                .map_err(|e| format!("Failed to encode outbox message: {}", e))?;
+            contents.push_str(&line);
+            contents.push('\n');
+        }
+
+        write_atomically(&self.config.path, contents).await
+    }
+
+    /// Drops messages whose TTL has passed
+    async fn expire(&mut self) -> Result<(), String> {
+        let now = now_ms();
+        let (expired, kept): (Vec<_>, Vec<_>) = self
+            .messages
+            .drain(..)
+            .partition(|message| message.expires_ms.is_some_and(|expires| expires <= now));
+        self.messages = kept.into();
+
+        if expired.is_empty() {
+            return Ok(());
+        }
+        for message in &expired {
+            self.emit(OutboxEventKind::Expired, message);
+        }
+        self.persist().await
+    }
+
+    /// Publishes queued messages in order, keeping the ones the server did not receive.
+    ///
+    /// Payloads are compressed and encrypted with the client's current settings. A message
+    /// that cannot be prepared, e.g. because it is too large or has no key to encrypt it
+    /// with, would fail on every replay, so it is dropped with a `Failed` event instead of
+    /// holding back the messages behind it.
+    async fn replay(&mut self, client_id: &str, client: &Client) -> Result<(), String> {
+        if self.messages.is_empty() {
+            return Ok(());
+        }
+
+        let mut published = Vec::new();
+        let mut failed = false;
+        let mut result = Ok(());
+        while let Some(message) = self.messages.pop_front() {
+            let prepared = prepare_payload(
+                client_id,
+                &message.subject,
+                message.payload.clone().into_bytes(),
+            )
+            .await;
+            let (payload, headers) = match prepared {
+                Ok(prepared) => prepared,
+                Err(e) => {
+                    self.emit_with_error(OutboxEventKind::Failed, &message, Some(e.message));
+                    failed = true;
+                    continue;
+                }
+            };
+
+            let sent = match headers {
+                Some(headers) => {
+                    client
+                        .publish_with_headers(message.subject.clone(), headers, payload.into())
+                        .await
+                }
+                None => {
+                    client
+                        .publish(message.subject.clone(), payload.into())
+                        .await
+                }
+            };
+            if let Err(e) = sent {
+                self.messages.push_front(message);
+                result = Err(format!("Failed to replay outbox: {}", e));
+                break;
+            }
+            published.push(message);
+        }
+
+        if !published.is_empty() {
+            if let Err(e) = client.flush().await {
+                // Keep the published messages to send them again, delivery is at least once
+                for message in published.drain(..).rev() {
+                    self.messages.push_front(message);
+                }
+                result = Err(format!("Failed to replay outbox: {}", e));
+            }
+        }
+        for message in &published {
+            self.emit(OutboxEventKind::Delivered, message);
+        }
+
+        if !published.is_empty() || failed {
+            self.persist().await?;
+        }
+        result
+    }
+}
+
+/// Helper function to replace a file through a temporary file, so a kill never leaves it half written
+pub(crate) async fn write_atomically(path: &str, contents: String) -> Result<(), String> {
+    let temp_path = format!("{}.tmp", path);
+    tokio::fs::write(&temp_path, contents)
+        .await
+        .map_err(|e| format!("Failed to write '{}': {}", temp_path, e))?;
+    tokio::fs::rename(&temp_path, path)
+        .await
+        .map_err(|e| format!("Failed to write '{}': {}", path, e))
+}
+
+/// Helper function to read messages left in an outbox log by a previous run
+async fn load_messages(path: &str) -> Result<VecDeque<QueuedMessage>, String> {
+    let contents = match tokio::fs::read_to_string(path).await {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(VecDeque::new()),
+        Err(e) => return Err(format!("Failed to read outbox '{}': {}", path, e)),
+    };
+
+    // A line torn by the app being killed mid-write is skipped
+    Ok(contents
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect())
+}
+
+/// Enables a persistent outbox for a client.
+///
+/// While the client is disconnected, `publish` appends messages to the outbox instead of
+/// failing or buffering them in memory. Once the client is connected again they are replayed
+/// in order. Messages left in the log by a previous run are replayed as well. Delivery is
+/// at least once: messages replayed right before the app is killed may be sent again.
+#[flutter_rust_bridge::frb]
+pub async fn enable_outbox(client_id: String, config: OutboxConfig) -> Result<(), String> {
+    let client = get_client(&client_id).await?;
+
+    if OUTBOXES.read().await.contains_key(&client_id) {
+        return Err(format!(
+            "Outbox for client '{}' is already enabled",
+            client_id
+        ));
+    }
+
+    let messages = load_messages(&config.path).await?;
+    let outbox = Outbox {
+        next_id: messages.back().map_or(1, |last| last.id + 1),
+        messages,
+        config,
+        sink: None,
+    };
+
+    // Drop a torn last line, so the next append does not merge into it
+    outbox.persist().await?;
+    let outbox = Arc::new(Mutex::new(outbox));
+    OUTBOXES
+        .write()
+        .await
+        .insert(client_id.clone(), outbox.clone());
+
+    // Replay and expire messages in the background until the outbox is disabled
+    tokio::spawn(async move {
+        loop {
+            tokio::time::sleep(Duration::from_millis(500)).await;
+
+            let current = OUTBOXES.read().await.get(&client_id).cloned();
+            match current {
+                Some(current) if Arc::ptr_eq(&current, &outbox) => {}
+                _ => break,
+            }
+
+            let mut outbox = outbox.lock().await;
+            let _ = outbox.expire().await;
+            if client.connection_state() == State::Connected {
+                let _ = outbox.replay(&client_id, &client).await;
+            }
+        }
+    });
+
+    Ok(())
+}
+
+/// Disables the outbox of a client. Queued messages stay in the log unless `clear` is set.
+#[flutter_rust_bridge::frb]
+pub async fn disable_outbox(client_id: String, clear: bool) -> Result<(), String> {
+    let outbox = OUTBOXES
+        .write()
+        .await
+        .remove(&client_id)
+        .ok_or_else(|| format!("Outbox for client '{}' is not enabled", client_id))?;
+
+    if clear {
+        let mut outbox = outbox.lock().await;
+        outbox.messages.clear();
+        outbox.persist().await?;
+    }
+    Ok(())
+}
+
+/// Streams the queued, delivered, expired and failed events of a client's outbox to Dart.
+#[flutter_rust_bridge::frb]
+pub async fn outbox_events(client_id: String, sink: StreamSink<OutboxEvent>) -> Result<(), String> {
+    let outbox = get_outbox(&client_id)
+        .await
+        .ok_or_else(|| format!("Outbox for client '{}' is not enabled", client_id))?;
+
+    outbox.lock().await.sink = Some(sink);
+    Ok(())
+}
+
+/// Returns how many messages are waiting in a client's outbox.
+#[flutter_rust_bridge::frb]
+pub async fn outbox_status(client_id: String) -> Result<OutboxStatus, String> {
+    let outbox = get_outbox(&client_id)
+        .await
+        .ok_or_else(|| format!("Outbox for client '{}' is not enabled", client_id))?;
+
+    let outbox = outbox.lock().await;
+    Ok(OutboxStatus {
+        pending_messages: outbox.messages.len() as u64,
+        pending_bytes: outbox.pending_bytes(),
+    })
+}
+
+/// Helper function to get the outbox of a client, if enabled
+async fn get_outbox(client_id: &str) -> Option<Arc<Mutex<Outbox>>> {
+    OUTBOXES.read().await.get(client_id).cloned()
+}
+
+/// Appends a message to the client's outbox if it is offline or still replaying older messages.
+///
+/// Returns `false` when the message should be published directly.
+pub(crate) async fn enqueue_if_offline(
+    client_id: &str,
+    client: &Client,
+    subject: &str,
+    payload: &str,
+) -> Result<bool, NatsError> {
+    let Some(outbox) = get_outbox(client_id).await else {
+        return Ok(false);
+    };
+
+    // A message the server would reject must not block the messages queued behind it
+    check_payload_size(client_id, payload.len(), None).await?;
+    let mut outbox = outbox.lock().await;
+
+    // Keep ordering: once messages are queued, new ones wait behind them
+    if client.connection_state() == State::Connected && outbox.messages.is_empty() {
+        return Ok(false);
+    }
+
+    if outbox
+        .config
+        .max_messages
+        .is_some_and(|max| outbox.messages.len() as u64 >= max)
+        || outbox
+            .config
+            .max_bytes
+            .is_some_and(|max| outbox.pending_bytes() + payload.len() as u64 > max)
+    {
+        return Err(NatsError::new(NatsErrorKind::Other, "Outbox is full"));
+    }
+
+    let queued_ms = now_ms();
+    let message = QueuedMessage {
+        id: outbox.next_id,
+        subject: subject.to_string(),
+        payload: payload.to_string(),
+        queued_ms,
+        expires_ms: outbox.config.ttl_ms.map(|ttl| queued_ms + ttl as i64),
+    };
+
+    // Append to the log before accepting the message
+    let line = serde_json::to_string(&message)
+        .map_err(|e| format!("Failed to encode outbox message: {}", e))?;
+    let mut file = tokio::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&outbox.config.path)
+        .await
+        .map_err(|e| format!("Failed to open outbox '{}': {}", outbox.config.path, e))?;
+    file.write_all(format!("{}\n", line).as_bytes())
+        .await
+        .map_err(|e| format!("Failed to write outbox '{}': {}", outbox.config.path, e))?;
+
+    outbox.next_id += 1;
+    outbox.emit(OutboxEventKind::Queued, &message);
+    outbox.messages.push_back(message);
+    Ok(true)
+}
+
+/// Stops the outbox of a disconnected client, keeping its log for the next run.
+pub(crate) async fn cleanup_client_outbox(client_id: &str) {
+    OUTBOXES.write().await.remove(client_id);
+}
@@ -0,0 +1,551 @@
+use crate::api::jetstream::unix_millis;
+use crate::api::nats::{get_client, request_many, ClientId, Registry};
+use async_nats::service::{self, endpoint::Endpoint, EndpointBuilder, Service, ServiceExt};
+use flutter_rust_bridge::DartFnFuture;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::sync::RwLock;
+use tokio_stream::StreamExt;
+
+/// Services are identified by the instance ID generated when they are started
+type ServiceId = String;
+
+/// A running service together with the groups and endpoints registered on it
+struct ServiceEntry {
+    service: Service,
+    started_ms: i64,
+    // Group prefix to its optional custom queue group
+    groups: HashMap<String, Option<String>>,
+    // Endpoint name to the metadata it was registered with
+    endpoint_metadata: HashMap<String, HashMap<String, String>>,
+}
+
+// Store running services per client
+static SERVICES: Registry<(ClientId, ServiceId), ServiceEntry> =
+    Lazy::new(|| Arc::new(RwLock::new(HashMap::new())));
+
+/// A request received by a service endpoint.
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug)]
+pub struct ServiceRequest {
+    pub subject: String,
+    pub payload: String,
+}
+
+/// The reply produced by a service endpoint handler.
+///
+/// When `error` is set the payload is ignored and the error is sent back using the
+/// `Nats-Service-Error` headers, counting towards the endpoint's error stats.
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug)]
+pub struct ServiceResponse {
+    pub payload: String,
+    pub error: Option<ServiceError>,
+}
+
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug)]
+pub struct ServiceError {
+    pub code: u32,
+    pub description: String,
+}
+
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug)]
+pub struct ServicePing {
+    pub name: String,
+    pub id: String,
+    pub version: String,
+    pub metadata: HashMap<String, String>,
+}
+
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug)]
+pub struct EndpointInfo {
+    pub name: String,
+    pub subject: String,
+    pub queue_group: String,
+    pub metadata: HashMap<String, String>,
+}
+
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug)]
+pub struct ServiceInfo {
+    pub name: String,
+    pub id: String,
+    pub description: String,
+    pub version: String,
+    pub metadata: HashMap<String, String>,
+    pub endpoints: Vec<EndpointInfo>,
+}
+
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug)]
+pub struct EndpointStats {
+    pub name: String,
+    pub subject: String,
+    pub queue_group: String,
+    pub num_requests: u64,
+    pub num_errors: u64,
+    pub processing_time_ns: u64,
+    pub average_processing_time_ns: u64,
+    pub last_error: Option<String>,
+}
+
+#[flutter_rust_bridge::frb(unignore)]
+#[derive(Clone, Debug)]
+pub struct ServiceStats {
+    pub name: String,
+    pub id: String,
+    pub version: String,
+    pub started_ms: i64,
+    pub endpoints: Vec<EndpointStats>,
+}
+
+impl From<service::PingResponse> for ServicePing {
+    fn from(ping: service::PingResponse) -> Self {
+        ServicePing {
+            name: ping.name,
+            id: ping.id,
+            version: ping.version,
+            metadata: ping.metadata,
+        }
+    }
+}
+
+impl From<service::endpoint::Info> for EndpointInfo {
+    fn from(info: service::endpoint::Info) -> Self {
+        EndpointInfo {
+            name: info.name,
+            subject: info.subject,
+            queue_group: info.queue_group,
+            metadata: info.metadata,
+        }
+    }
+}
+
+impl From<service::Info> for ServiceInfo {
+    fn from(info: service::Info) -> Self {
+        ServiceInfo {
+            name: info.name,
+            id: info.id,
+            description: info.description,
+            version: info.version,
+            metadata: info.metadata,
+            endpoints: info.endpoints.into_iter().map(EndpointInfo::from).collect(),
+        }
+    }
+}
+
+impl From<service::Stats> for ServiceStats {
+    fn from(stats: service::Stats) -> Self {
+        ServiceStats {
+            name: stats.name,
+            id: stats.id,
+            version: stats.version,
+            started_ms: unix_millis(stats.started),
+            endpoints: stats
+                .endpoints
+                .into_iter()
+                .map(EndpointStats::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<service::endpoint::Stats> for EndpointStats {
+    fn from(stats: service::endpoint::Stats) -> Self {
+        EndpointStats {
+            name: stats.name,
+            subject: stats.subject,
+            queue_group: stats.queue_group,
+            num_requests: stats.requests as u64,
+            num_errors: stats.errors as u64,
+            processing_time_ns: stats.processing_time.as_nanos() as u64,
+            average_processing_time_ns: stats.average_processing_time.as_nanos() as u64,
+            last_error: stats.last_error.map(|e| format!("{}:{}", e.code, e.status)),
+        }
+    }
+}
+
+/// Starts a NATS micro service on the specified client and returns its instance ID.
+///
+/// The service answers `$SRV.PING`, `$SRV.INFO` and `$SRV.STATS` requests on its own,
+/// so it is discoverable with `nats micro ls` as soon as it is started.
+#[flutter_rust_bridge::frb]
+pub async fn add_service(
+    client_id: String,
+    name: String,
+    version: String,
+    description: Option<String>,
+    metadata: HashMap<String, String>,
+) -> Result<String, String> {
+    // Get the client
+    let client = get_client(&client_id).await?;
+
+    // Build and start the service
+    let mut builder = client.service_builder().metadata(metadata);
+    if let Some(description) = description {
+        builder = builder.description(description);
+    }
+    let service = builder
+        .start(name, version)
+        .await
+        .map_err(|e| format!("Failed to start service: {}", e))?;
+
+    let service_id = service.info().await.id;
+    let started_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or_default();
+
+    // Store the service
+    {
+        let mut services = SERVICES.write().await;
+        services.insert(
+            (client_id, service_id.clone()),
+            ServiceEntry {
+                service,
+                started_ms,
+                groups: HashMap::new(),
+                endpoint_metadata: HashMap::new(),
+            },
+        );
+    }
+
+    Ok(service_id)
+}
+
+/// Adds a group to a service and returns its full prefix, used as the group handle.
+///
+/// When `parent_group` is given the new group is nested under it. Without a custom
+/// `queue_group` the group inherits the queue group of its parent.
+#[flutter_rust_bridge::frb]
+pub async fn add_service_group(
+    client_id: String,
+    service_id: String,
+    parent_group: Option<String>,
+    prefix: String,
+    queue_group: Option<String>,
+) -> Result<String, String> {
+    if prefix.is_empty() {
+        return Err("Group prefix cannot be empty".to_string());
+    }
+
+    let mut services = SERVICES.write().await;
+    let entry = services
+        .get_mut(&(client_id, service_id.clone()))
+        .ok_or_else(|| format!("Service '{}' not found", service_id))?;
+
+    // Resolve the full prefix and the inherited queue group
+    let (full_prefix, queue_group) = match parent_group {
+        Some(parent) => {
+            let parent_queue_group = entry
+                .groups
+                .get(&parent)
+                .ok_or_else(|| format!("Group '{}' not found", parent))?;
+            (
+                format!("{}.{}", parent, prefix),
+                queue_group.or_else(|| parent_queue_group.clone()),
+            )
+        }
+        None => (prefix, queue_group),
+    };
+
+    entry.groups.insert(full_prefix.clone(), queue_group);
+    Ok(full_prefix)
+}
+
+/// Helper function to create an endpoint builder for a service or one of its groups
+async fn get_endpoint_builder(
+    sub_key: &(ClientId, ServiceId),
+    group: Option<&str>,
+) -> Result<EndpointBuilder, String> {
+    let services = SERVICES.read().await;
+    let entry = services
+        .get(sub_key)
+        .ok_or_else(|| format!("Service '{}' not found", sub_key.1))?;
+
+    match group {
+        Some(prefix) => match entry.groups.get(prefix) {
+            Some(Some(queue_group)) => Ok(entry
+                .service
+                .group_with_queue_group(prefix, queue_group)
+                .endpoint_builder()),
+            Some(None) => Ok(entry.service.group(prefix).endpoint_builder()),
+            None => Err(format!("Group '{}' not found", prefix)),
+        },
+        None => Ok(entry.service.endpoint_builder()),
+    }
+}
+
+/// Adds an endpoint to a service, optionally under one of its groups, and handles its requests.
+#[allow(clippy::too_many_arguments)]
+#[flutter_rust_bridge::frb]
+pub async fn add_service_endpoint(
+    client_id: String,
+    service_id: String,
+    group: Option<String>,
+    name: String,
+    subject: String,
+    metadata: HashMap<String, String>,
+    handler: impl Fn(ServiceRequest) -> DartFnFuture<ServiceResponse> + Send + 'static,
+    on_success: impl Fn(bool) -> DartFnFuture<()>,
+    on_error: impl Fn(String) -> DartFnFuture<()> + Send + 'static,
+) {
+    let service_key = (client_id, service_id);
+
+    // Get a builder for the service or group
+    let builder = match get_endpoint_builder(&service_key, group.as_deref()).await {
+        Ok(builder) => builder,
+        Err(e) => {
+            on_error(e).await;
+            return;
+        }
+    };
+
+    // Register the endpoint
+    match builder
+        .name(name.clone())
+        .metadata(metadata.clone())
+        .add(subject)
+        .await
+    {
+        Ok(endpoint) => {
+            {
+                let mut services = SERVICES.write().await;
+                if let Some(entry) = services.get_mut(&service_key) {
+                    entry.endpoint_metadata.insert(name, metadata);
+                }
+            }
+
+            // Notify successful setup
+            on_success(true).await;
+
+            // Spawn a task to handle this endpoint
+            tokio::spawn(async move {
+                process_endpoint_requests(endpoint, handler, on_error).await;
+            });
+        }
+        Err(e) => {
+            on_error(format!("Failed to add endpoint: {}", e)).await;
+        }
+    }
+}
+
+/// Internal function to process endpoint requests until the service is stopped
+async fn process_endpoint_requests(
+    mut endpoint: Endpoint,
+    handler: impl Fn(ServiceRequest) -> DartFnFuture<ServiceResponse>,
+    on_error: impl Fn(String) -> DartFnFuture<()>,
+) {
+    while let Some(request) = endpoint.next().await {
+        // Requests without a reply subject cannot be answered
+        if request.message.reply.is_none() {
+            continue;
+        }
+
+        // Call handler to get response
+        let response = match String::from_utf8(request.message.payload.to_vec()) {
+            Ok(payload) => {
+                let reply = handler(ServiceRequest {
+                    subject: request.message.subject.to_string(),
+                    payload,
+                })
+                .await;
+                match reply.error {
+                    Some(error) => Err(service::error::Error {
+                        status: error.description,
+                        code: error.code as usize,
+                    }),
+                    None => Ok(reply.payload.into_bytes().into()),
+                }
+            }
+            Err(e) => Err(service::error::Error {
+                status: format!("Invalid UTF-8 in request: {}", e),
+                code: 400,
+            }),
+        };
+
+        // Send response back, this also updates the endpoint stats
+        if let Err(e) = request.respond(response).await {
+            on_error(format!("Failed to send response: {}", e)).await;
+        }
+    }
+}
+
+/// Returns the info of a service started by the specified client.
+#[flutter_rust_bridge::frb]
+pub async fn service_info(client_id: String, service_id: String) -> Result<ServiceInfo, String> {
+    let services = SERVICES.read().await;
+    let entry = services
+        .get(&(client_id, service_id.clone()))
+        .ok_or_else(|| format!("Service '{}' not found", service_id))?;
+
+    let info = entry.service.info().await;
+    let endpoints = entry
+        .service
+        .stats()
+        .await
+        .into_values()
+        .map(|stats| EndpointInfo {
+            metadata: entry
+                .endpoint_metadata
+                .get(&stats.name)
+                .cloned()
+                .unwrap_or_default(),
+            name: stats.name,
+            subject: stats.subject,
+            queue_group: stats.queue_group,
+        })
+        .collect();
+
+    Ok(ServiceInfo {
+        name: info.name,
+        id: info.id,
+        description: info.description,
+        version: info.version,
+        metadata: info.metadata,
+        endpoints,
+    })
+}
+
+/// Returns the request, error and processing time stats of a service started by the specified client.
+#[flutter_rust_bridge::frb]
+pub async fn service_stats(client_id: String, service_id: String) -> Result<ServiceStats, String> {
+    let services = SERVICES.read().await;
+    let entry = services
+        .get(&(client_id, service_id.clone()))
+        .ok_or_else(|| format!("Service '{}' not found", service_id))?;
+
+    let info = entry.service.info().await;
+    let endpoints = entry
+        .service
+        .stats()
+        .await
+        .into_values()
+        .map(EndpointStats::from)
+        .collect();
+
+    Ok(ServiceStats {
+        name: info.name,
+        id: info.id,
+        version: info.version,
+        started_ms: entry.started_ms,
+        endpoints,
+    })
+}
+
+/// Resets the endpoint stats of a service started by the specified client.
+#[flutter_rust_bridge::frb]
+pub async fn reset_service_stats(client_id: String, service_id: String) -> Result<(), String> {
+    let mut services = SERVICES.write().await;
+    let entry = services
+        .get_mut(&(client_id, service_id.clone()))
+        .ok_or_else(|| format!("Service '{}' not found", service_id))?;
+
+    entry.service.reset().await;
+    Ok(())
+}
+
+/// Stops a service, unsubscribing all of its endpoints.
+#[flutter_rust_bridge::frb]
+pub async fn stop_service(client_id: String, service_id: String) -> Result<(), String> {
+    let entry = {
+        let mut services = SERVICES.write().await;
+        services
+            .remove(&(client_id, service_id.clone()))
+            .ok_or_else(|| format!("Service '{}' not found", service_id))?
+    };
+
+    entry
+        .service
+        .stop()
+        .await
+        .map_err(|e| format!("Failed to stop service: {}", e))
+}
+
+/// Returns a list of running service IDs for the specified client.
+#[flutter_rust_bridge::frb]
+pub async fn list_services(client_id: String) -> Vec<String> {
+    let services = SERVICES.read().await;
+    services
+        .keys()
+        .filter(|(cid, _)| cid == &client_id)
+        .map(|(_, id)| id.clone())
+        .collect()
+}
+
+/// Helper function to stop all services for a client
+pub(crate) async fn cleanup_client_services(client_id: &str) {
+    let entries: Vec<ServiceEntry> = {
+        let mut services = SERVICES.write().await;
+        let keys: Vec<(ClientId, ServiceId)> = services
+            .keys()
+            .filter(|(cid, _)| cid == client_id)
+            .cloned()
+            .collect();
+        keys.iter().filter_map(|key| services.remove(key)).collect()
+    };
+
+    for entry in entries {
+        let _ = entry.service.stop().await;
+    }
+}
+
+/// Helper function to send a discovery request and decode every reply received before the timeout
+async fn discover<T: serde::de::DeserializeOwned>(
+    client_id: &str,
+    verb: &str,
+    name: Option<String>,
+    timeout_ms: u64,
+) -> Result<Vec<T>, String> {
+    // Narrow the request down to a single service name when given
+    let subject = match name {
+        Some(name) => format!("$SRV.{}.{}", verb, name),
+        None => format!("$SRV.{}", verb),
+    };
+
+    // Replies that are empty or fail to decode come from something other than a service
+    let replies = request_many(client_id, subject, String::new(), timeout_ms).await?;
+    Ok(replies
+        .iter()
+        .filter(|msg| !msg.payload.is_empty())
+        .filter_map(|msg| serde_json::from_slice(&msg.payload).ok())
+        .collect())
+}
+
+/// Pings all micro services, or only those with the given name, and returns every reply.
+#[flutter_rust_bridge::frb]
+pub async fn ping_services(
+    client_id: String,
+    name: Option<String>,
+    timeout_ms: u64,
+) -> Result<Vec<ServicePing>, String> {
+    let pings: Vec<service::PingResponse> = discover(&client_id, "PING", name, timeout_ms).await?;
+    Ok(pings.into_iter().map(ServicePing::from).collect())
+}
+
+/// Discovers all micro services, or only those with the given name, and returns their info.
+#[flutter_rust_bridge::frb]
+pub async fn discover_services(
+    client_id: String,
+    name: Option<String>,
+    timeout_ms: u64,
+) -> Result<Vec<ServiceInfo>, String> {
+    let infos: Vec<service::Info> = discover(&client_id, "INFO", name, timeout_ms).await?;
+    Ok(infos.into_iter().map(ServiceInfo::from).collect())
+}
+
+/// Collects the stats of all micro services, or only those with the given name.
+#[flutter_rust_bridge::frb]
+pub async fn discover_service_stats(
+    client_id: String,
+    name: Option<String>,
+    timeout_ms: u64,
+) -> Result<Vec<ServiceStats>, String> {
+    let stats: Vec<service::Stats> = discover(&client_id, "STATS", name, timeout_ms).await?;
+    Ok(stats.into_iter().map(ServiceStats::from).collect())
+}
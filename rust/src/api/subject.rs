@@ -0,0 +1,191 @@
+use crate::api::error::{NatsError, NatsErrorKind};
+
+/// Helper function to build an invalid subject error
+fn invalid(subject: &str, reason: &str) -> NatsError {
+    NatsError::new(
+        NatsErrorKind::InvalidSubject,
+        format!("Invalid subject '{}': {}", subject, reason),
+    )
+}
+
+/// Checks that a subject is well formed.
+///
+/// Subjects must be non-empty, contain no whitespace and no empty tokens. The `*` and `>`
+/// wildcards are only accepted when `allow_wildcards` is set, as whole tokens, with `>` last.
+#[flutter_rust_bridge::frb]
+pub fn validate_subject(subject: String, allow_wildcards: bool) -> Result<(), NatsError> {
+    check_subject(&subject, allow_wildcards)
+}
+
+/// Returns whether a subject matches a pattern using the `*` and `>` wildcards.
+///
+/// `*` matches exactly one token and `>` matches one or more trailing tokens.
+#[flutter_rust_bridge::frb]
+pub fn subject_matches(pattern: String, subject: String) -> bool {
+    matches_pattern(&pattern, &subject)
+}
+
+/// Helper function to match a subject against a pattern without taking ownership
+pub(crate) fn matches_pattern(pattern: &str, subject: &str) -> bool {
+    let mut subject_tokens = subject.split('.');
+    for token in pattern.split('.') {
+        match (token, subject_tokens.next()) {
+            (">", Some(_)) => return true,
+            ("*", Some(_)) => {}
+            (token, Some(subject_token)) if token == subject_token => {}
+            _ => return false,
+        }
+    }
+    subject_tokens.next().is_none()
+}
+
+/// Builds a subject from its tokens, checking that each one is a valid literal token.
+#[flutter_rust_bridge::frb]
+pub fn subject_join(tokens: Vec<String>) -> Result<String, NatsError> {
+    let subject = tokens.join(".");
+    if tokens.is_empty() {
+        return Err(invalid(&subject, "no tokens"));
+    }
+    for token in &tokens {
+        if token.is_empty() || token.contains('.') {
+            return Err(invalid(&subject, &format!("invalid token '{}'", token)));
+        }
+    }
+    check_subject(&subject, false)?;
+    Ok(subject)
+}
+
+/// Checks a subject, rejecting wildcards unless `allow_wildcards` is set.
+pub(crate) fn check_subject(subject: &str, allow_wildcards: bool) -> Result<(), NatsError> {
+    if subject.is_empty() {
+        return Err(invalid(subject, "empty subject"));
+    }
+    if subject.chars().any(char::is_whitespace) {
+        return Err(invalid(subject, "contains whitespace"));
+    }
+
+    let tokens: Vec<&str> = subject.split('.').collect();
+    for (index, token) in tokens.iter().enumerate() {
+        if token.is_empty() {
+            return Err(invalid(subject, "empty token"));
+        }
+        let is_wildcard = *token == "*" || *token == ">";
+        if is_wildcard && !allow_wildcards {
+            return Err(invalid(subject, "wildcards are not allowed here"));
+        }
+        if *token == ">" && index != tokens.len() - 1 {
+            return Err(invalid(subject, "'>' must be the last token"));
+        }
+        if !is_wildcard && (token.contains('*') || token.contains('>')) {
+            return Err(invalid(subject, "wildcards must be whole tokens"));
+        }
+    }
+    Ok(())
+}
+
+/// Checks a KV key, which may only contain letters, digits and `-/_=.`, not at its ends.
+pub(crate) fn check_kv_key(key: &str) -> Result<(), NatsError> {
+    let valid_chars = key
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || "-/_=.".contains(c));
+    if key.is_empty() || !valid_chars || key.starts_with('.') || key.ends_with('.') {
+        return Err(NatsError::new(
+            NatsErrorKind::InvalidSubject,
+            format!("Invalid key '{}'", key),
+        ));
+    }
+    check_subject(key, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn literal_patterns_match_exactly() {
+        assert!(matches_pattern("orders.new", "orders.new"));
+        assert!(!matches_pattern("orders.new", "orders.old"));
+        assert!(!matches_pattern("orders.new", "orders.new.eu"));
+        assert!(!matches_pattern("orders.new.eu", "orders.new"));
+    }
+
+    #[test]
+    fn star_matches_one_token() {
+        assert!(matches_pattern("orders.*", "orders.new"));
+        assert!(matches_pattern("*.new", "orders.new"));
+        assert!(matches_pattern("orders.*.eu", "orders.new.eu"));
+        assert!(!matches_pattern("orders.*", "orders"));
+        assert!(!matches_pattern("orders.*", "orders.new.eu"));
+    }
+
+    #[test]
+    fn greater_than_matches_trailing_tokens() {
+        assert!(matches_pattern("orders.>", "orders.new"));
+        assert!(matches_pattern("orders.>", "orders.new.eu"));
+        assert!(matches_pattern(">", "orders"));
+        assert!(!matches_pattern("orders.>", "orders"));
+        assert!(!matches_pattern("orders.>", "payments.new"));
+    }
+
+    #[test]
+    fn valid_subjects_pass() {
+        assert!(check_subject("orders", false).is_ok());
+        assert!(check_subject("orders.new.eu-1", false).is_ok());
+        assert!(check_subject("orders.*.eu", true).is_ok());
+        assert!(check_subject("orders.>", true).is_ok());
+    }
+
+    #[test]
+    fn malformed_subjects_are_rejected() {
+        for subject in [
+            "",
+            "orders new",
+            "orders\t",
+            "orders..new",
+            ".orders",
+            "orders.",
+        ] {
+            let error = check_subject(subject, true).unwrap_err();
+            assert_eq!(error.kind, NatsErrorKind::InvalidSubject, "{:?}", subject);
+        }
+    }
+
+    #[test]
+    fn wildcards_are_checked() {
+        assert!(check_subject("orders.*", false).is_err());
+        assert!(check_subject("orders.>", false).is_err());
+        assert!(check_subject("orders.>.eu", true).is_err());
+        assert!(check_subject("orders.new*", true).is_err());
+        assert!(check_subject("orders.>new", true).is_err());
+    }
+
+    #[test]
+    fn kv_keys_are_checked() {
+        assert!(check_kv_key("settings").is_ok());
+        assert!(check_kv_key("user/42_theme=dark.v-1").is_ok());
+        for key in [
+            "",
+            ".settings",
+            "settings.",
+            "a..b",
+            "a b",
+            "a*",
+            "a>",
+            "caf\u{e9}",
+        ] {
+            let error = check_kv_key(key).unwrap_err();
+            assert_eq!(error.kind, NatsErrorKind::InvalidSubject, "{:?}", key);
+        }
+    }
+
+    #[test]
+    fn subjects_are_joined_from_literal_tokens() {
+        assert_eq!(
+            subject_join(vec!["orders".to_string(), "new".to_string()]).unwrap(),
+            "orders.new"
+        );
+        assert!(subject_join(Vec::new()).is_err());
+        assert!(subject_join(vec!["orders".to_string(), "a.b".to_string()]).is_err());
+        assert!(subject_join(vec!["orders".to_string(), "*".to_string()]).is_err());
+    }
+}